@@ -1,15 +1,35 @@
 // automatically generated by the FlatBuffers compiler, do not modify
 
+export { ChatChannel } from './gameplay-fbdata/chat-channel.js';
+export { ChatMessage } from './gameplay-fbdata/chat-message.js';
+export { ChatSend } from './gameplay-fbdata/chat-send.js';
+export { FlagEvent } from './gameplay-fbdata/flag-event.js';
+export { FlagUpdate } from './gameplay-fbdata/flag-update.js';
 export { GameReponseEvent } from './gameplay-fbdata/game-reponse-event.js';
 export { GameRequestEvent } from './gameplay-fbdata/game-request-event.js';
 export { GameWorldUpdate } from './gameplay-fbdata/game-world-update.js';
+export { LobbyMember } from './gameplay-fbdata/lobby-member.js';
+export { LobbyReady } from './gameplay-fbdata/lobby-ready.js';
+export { LobbySetup } from './gameplay-fbdata/lobby-setup.js';
+export { LobbyUpdate } from './gameplay-fbdata/lobby-update.js';
+export { MatchPhase } from './gameplay-fbdata/match-phase.js';
+export { MatchPhaseChanged } from './gameplay-fbdata/match-phase-changed.js';
+export { MatchSummary } from './gameplay-fbdata/match-summary.js';
+export { PartyInvitation } from './gameplay-fbdata/party-invitation.js';
+export { PartyInvite } from './gameplay-fbdata/party-invite.js';
+export { PartyLeave } from './gameplay-fbdata/party-leave.js';
+export { PartyRespond } from './gameplay-fbdata/party-respond.js';
+export { PartyUpdate } from './gameplay-fbdata/party-update.js';
 export { PlayerControl } from './gameplay-fbdata/player-control.js';
 export { PlayerData } from './gameplay-fbdata/player-data.js';
+export { PlayerKilled } from './gameplay-fbdata/player-killed.js';
 export { PlayerMoved } from './gameplay-fbdata/player-moved.js';
+export { PlayerScore } from './gameplay-fbdata/player-score.js';
 export { RemotePeerJoined } from './gameplay-fbdata/remote-peer-joined.js';
 export { RemotePeerLeft } from './gameplay-fbdata/remote-peer-left.js';
 export { RemotePeerPositionUpdate } from './gameplay-fbdata/remote-peer-position-update.js';
 export { RequestMessages } from './gameplay-fbdata/request-messages.js';
 export { ResponseMessage } from './gameplay-fbdata/response-message.js';
+export { ScoreboardUpdate } from './gameplay-fbdata/scoreboard-update.js';
 export { Vec2 } from './gameplay-fbdata/vec2.js';
 export { WeaponFired } from './gameplay-fbdata/weapon-fired.js';
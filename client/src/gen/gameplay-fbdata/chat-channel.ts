@@ -0,0 +1,7 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+export enum ChatChannel {
+  Room = 0,
+  Direct = 1,
+  Team = 2
+}
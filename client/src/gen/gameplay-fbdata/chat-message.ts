@@ -0,0 +1,71 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ChatChannel } from '../gameplay-fbdata/chat-channel.js';
+
+
+export class ChatMessage {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ChatMessage {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsChatMessage(bb:flatbuffers.ByteBuffer, obj?:ChatMessage):ChatMessage {
+  return (obj || new ChatMessage()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsChatMessage(bb:flatbuffers.ByteBuffer, obj?:ChatMessage):ChatMessage {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ChatMessage()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+channel():ChatChannel {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readInt8(this.bb_pos + offset) : ChatChannel.Room;
+}
+
+senderPlayerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+text():string|null
+text(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+text(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+static startChatMessage(builder:flatbuffers.Builder) {
+  builder.startObject(3);
+}
+
+static addChannel(builder:flatbuffers.Builder, channel:ChatChannel) {
+  builder.addFieldInt8(0, channel, ChatChannel.Room);
+}
+
+static addSenderPlayerId(builder:flatbuffers.Builder, senderPlayerId:bigint) {
+  builder.addFieldInt64(1, senderPlayerId, BigInt('0'));
+}
+
+static addText(builder:flatbuffers.Builder, textOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, textOffset, 0);
+}
+
+static endChatMessage(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createChatMessage(builder:flatbuffers.Builder, channel:ChatChannel, senderPlayerId:bigint, textOffset:flatbuffers.Offset):flatbuffers.Offset {
+  ChatMessage.startChatMessage(builder);
+  ChatMessage.addChannel(builder, channel);
+  ChatMessage.addSenderPlayerId(builder, senderPlayerId);
+  ChatMessage.addText(builder, textOffset);
+  return ChatMessage.endChatMessage(builder);
+}
+}
@@ -0,0 +1,71 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { ChatChannel } from '../gameplay-fbdata/chat-channel.js';
+
+
+export class ChatSend {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ChatSend {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsChatSend(bb:flatbuffers.ByteBuffer, obj?:ChatSend):ChatSend {
+  return (obj || new ChatSend()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsChatSend(bb:flatbuffers.ByteBuffer, obj?:ChatSend):ChatSend {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ChatSend()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+channel():ChatChannel {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readInt8(this.bb_pos + offset) : ChatChannel.Room;
+}
+
+targetPlayerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+text():string|null
+text(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+text(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+static startChatSend(builder:flatbuffers.Builder) {
+  builder.startObject(3);
+}
+
+static addChannel(builder:flatbuffers.Builder, channel:ChatChannel) {
+  builder.addFieldInt8(0, channel, ChatChannel.Room);
+}
+
+static addTargetPlayerId(builder:flatbuffers.Builder, targetPlayerId:bigint) {
+  builder.addFieldInt64(1, targetPlayerId, BigInt('0'));
+}
+
+static addText(builder:flatbuffers.Builder, textOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, textOffset, 0);
+}
+
+static endChatSend(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createChatSend(builder:flatbuffers.Builder, channel:ChatChannel, targetPlayerId:bigint, textOffset:flatbuffers.Offset):flatbuffers.Offset {
+  ChatSend.startChatSend(builder);
+  ChatSend.addChannel(builder, channel);
+  ChatSend.addTargetPlayerId(builder, targetPlayerId);
+  ChatSend.addText(builder, textOffset);
+  return ChatSend.endChatSend(builder);
+}
+}
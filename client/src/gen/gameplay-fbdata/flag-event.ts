@@ -0,0 +1,8 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+export enum FlagEvent {
+  Taken = 0,
+  Dropped = 1,
+  Returned = 2,
+  Captured = 3
+}
@@ -0,0 +1,72 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { FlagEvent } from '../gameplay-fbdata/flag-event.js';
+import { Vec2 } from '../gameplay-fbdata/vec2.js';
+
+
+export class FlagUpdate {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):FlagUpdate {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsFlagUpdate(bb:flatbuffers.ByteBuffer, obj?:FlagUpdate):FlagUpdate {
+  return (obj || new FlagUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsFlagUpdate(bb:flatbuffers.ByteBuffer, obj?:FlagUpdate):FlagUpdate {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new FlagUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+team():number {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : 0;
+}
+
+event():FlagEvent {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readInt8(this.bb_pos + offset) : FlagEvent.Taken;
+}
+
+playerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+position(obj?:Vec2):Vec2|null {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? (obj || new Vec2()).__init(this.bb_pos + offset, this.bb!) : null;
+}
+
+static startFlagUpdate(builder:flatbuffers.Builder) {
+  builder.startObject(4);
+}
+
+static addTeam(builder:flatbuffers.Builder, team:number) {
+  builder.addFieldInt8(0, team, 0);
+}
+
+static addEvent(builder:flatbuffers.Builder, event:FlagEvent) {
+  builder.addFieldInt8(1, event, FlagEvent.Taken);
+}
+
+static addPlayerId(builder:flatbuffers.Builder, playerId:bigint) {
+  builder.addFieldInt64(2, playerId, BigInt('0'));
+}
+
+static addPosition(builder:flatbuffers.Builder, positionOffset:flatbuffers.Offset) {
+  builder.addFieldStruct(3, positionOffset, 0);
+}
+
+static endFlagUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+}
@@ -25,7 +25,7 @@ static getSizePrefixedRootAsGameWorldUpdate(bb:flatbuffers.ByteBuffer, obj?:Game
 
 playerDataList(index: number, obj?:PlayerData):PlayerData|null {
   const offset = this.bb!.__offset(this.bb_pos, 4);
-  return offset ? (obj || new PlayerData()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 16, this.bb!) : null;
+  return offset ? (obj || new PlayerData()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 24, this.bb!) : null;
 }
 
 playerDataListLength():number {
@@ -33,8 +33,22 @@ playerDataListLength():number {
   return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
 }
 
+mapId():string|null
+mapId(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+mapId(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+gameMode():string|null
+gameMode(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+gameMode(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
 static startGameWorldUpdate(builder:flatbuffers.Builder) {
-  builder.startObject(1);
+  builder.startObject(3);
 }
 
 static addPlayerDataList(builder:flatbuffers.Builder, playerDataListOffset:flatbuffers.Offset) {
@@ -42,7 +56,15 @@ static addPlayerDataList(builder:flatbuffers.Builder, playerDataListOffset:flatb
 }
 
 static startPlayerDataListVector(builder:flatbuffers.Builder, numElems:number) {
-  builder.startVector(16, numElems, 8);
+  builder.startVector(24, numElems, 8);
+}
+
+static addMapId(builder:flatbuffers.Builder, mapIdOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, mapIdOffset, 0);
+}
+
+static addGameMode(builder:flatbuffers.Builder, gameModeOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, gameModeOffset, 0);
 }
 
 static endGameWorldUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
@@ -50,9 +72,11 @@ static endGameWorldUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
   return offset;
 }
 
-static createGameWorldUpdate(builder:flatbuffers.Builder, playerDataListOffset:flatbuffers.Offset):flatbuffers.Offset {
+static createGameWorldUpdate(builder:flatbuffers.Builder, playerDataListOffset:flatbuffers.Offset, mapIdOffset:flatbuffers.Offset, gameModeOffset:flatbuffers.Offset):flatbuffers.Offset {
   GameWorldUpdate.startGameWorldUpdate(builder);
   GameWorldUpdate.addPlayerDataList(builder, playerDataListOffset);
+  GameWorldUpdate.addMapId(builder, mapIdOffset);
+  GameWorldUpdate.addGameMode(builder, gameModeOffset);
   return GameWorldUpdate.endGameWorldUpdate(builder);
 }
 }
@@ -0,0 +1,100 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class LobbyMember {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):LobbyMember {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsLobbyMember(bb:flatbuffers.ByteBuffer, obj?:LobbyMember):LobbyMember {
+  return (obj || new LobbyMember()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsLobbyMember(bb:flatbuffers.ByteBuffer, obj?:LobbyMember):LobbyMember {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new LobbyMember()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+playerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+name():string|null
+name(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+name(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+skin():string|null
+skin(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+skin(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+team():number {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : 0;
+}
+
+hasTeam():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+ready():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 14);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startLobbyMember(builder:flatbuffers.Builder) {
+  builder.startObject(6);
+}
+
+static addPlayerId(builder:flatbuffers.Builder, playerId:bigint) {
+  builder.addFieldInt64(0, playerId, BigInt('0'));
+}
+
+static addName(builder:flatbuffers.Builder, nameOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, nameOffset, 0);
+}
+
+static addSkin(builder:flatbuffers.Builder, skinOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(2, skinOffset, 0);
+}
+
+static addTeam(builder:flatbuffers.Builder, team:number) {
+  builder.addFieldInt8(3, team, 0);
+}
+
+static addHasTeam(builder:flatbuffers.Builder, hasTeam:boolean) {
+  builder.addFieldInt8(4, +hasTeam, +false);
+}
+
+static addReady(builder:flatbuffers.Builder, ready:boolean) {
+  builder.addFieldInt8(5, +ready, +false);
+}
+
+static endLobbyMember(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createLobbyMember(builder:flatbuffers.Builder, playerId:bigint, nameOffset:flatbuffers.Offset, skinOffset:flatbuffers.Offset, team:number, hasTeam:boolean, ready:boolean):flatbuffers.Offset {
+  LobbyMember.startLobbyMember(builder);
+  LobbyMember.addPlayerId(builder, playerId);
+  LobbyMember.addName(builder, nameOffset);
+  LobbyMember.addSkin(builder, skinOffset);
+  LobbyMember.addTeam(builder, team);
+  LobbyMember.addHasTeam(builder, hasTeam);
+  LobbyMember.addReady(builder, ready);
+  return LobbyMember.endLobbyMember(builder);
+}
+}
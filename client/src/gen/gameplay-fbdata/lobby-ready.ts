@@ -0,0 +1,46 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class LobbyReady {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):LobbyReady {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsLobbyReady(bb:flatbuffers.ByteBuffer, obj?:LobbyReady):LobbyReady {
+  return (obj || new LobbyReady()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsLobbyReady(bb:flatbuffers.ByteBuffer, obj?:LobbyReady):LobbyReady {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new LobbyReady()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+ready():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startLobbyReady(builder:flatbuffers.Builder) {
+  builder.startObject(1);
+}
+
+static addReady(builder:flatbuffers.Builder, ready:boolean) {
+  builder.addFieldInt8(0, +ready, +false);
+}
+
+static endLobbyReady(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createLobbyReady(builder:flatbuffers.Builder, ready:boolean):flatbuffers.Offset {
+  LobbyReady.startLobbyReady(builder);
+  LobbyReady.addReady(builder, ready);
+  return LobbyReady.endLobbyReady(builder);
+}
+}
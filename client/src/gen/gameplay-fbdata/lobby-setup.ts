@@ -0,0 +1,80 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class LobbySetup {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):LobbySetup {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsLobbySetup(bb:flatbuffers.ByteBuffer, obj?:LobbySetup):LobbySetup {
+  return (obj || new LobbySetup()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsLobbySetup(bb:flatbuffers.ByteBuffer, obj?:LobbySetup):LobbySetup {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new LobbySetup()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+name():string|null
+name(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+name(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+skin():string|null
+skin(optionalEncoding:flatbuffers.Encoding):string|Uint8Array|null
+skin(optionalEncoding?:any):string|Uint8Array|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__string(this.bb_pos + offset, optionalEncoding) : null;
+}
+
+team():number {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : 0;
+}
+
+hasTeam():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startLobbySetup(builder:flatbuffers.Builder) {
+  builder.startObject(4);
+}
+
+static addName(builder:flatbuffers.Builder, nameOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, nameOffset, 0);
+}
+
+static addSkin(builder:flatbuffers.Builder, skinOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, skinOffset, 0);
+}
+
+static addTeam(builder:flatbuffers.Builder, team:number) {
+  builder.addFieldInt8(2, team, 0);
+}
+
+static addHasTeam(builder:flatbuffers.Builder, hasTeam:boolean) {
+  builder.addFieldInt8(3, +hasTeam, +false);
+}
+
+static endLobbySetup(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createLobbySetup(builder:flatbuffers.Builder, nameOffset:flatbuffers.Offset, skinOffset:flatbuffers.Offset, team:number, hasTeam:boolean):flatbuffers.Offset {
+  LobbySetup.startLobbySetup(builder);
+  LobbySetup.addName(builder, nameOffset);
+  LobbySetup.addSkin(builder, skinOffset);
+  LobbySetup.addTeam(builder, team);
+  LobbySetup.addHasTeam(builder, hasTeam);
+  return LobbySetup.endLobbySetup(builder);
+}
+}
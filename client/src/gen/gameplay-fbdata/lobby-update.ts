@@ -0,0 +1,86 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { LobbyMember } from '../gameplay-fbdata/lobby-member.js';
+
+
+export class LobbyUpdate {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):LobbyUpdate {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsLobbyUpdate(bb:flatbuffers.ByteBuffer, obj?:LobbyUpdate):LobbyUpdate {
+  return (obj || new LobbyUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsLobbyUpdate(bb:flatbuffers.ByteBuffer, obj?:LobbyUpdate):LobbyUpdate {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new LobbyUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+playerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+members(index: number, obj?:LobbyMember):LobbyMember|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? (obj || new LobbyMember()).__init(this.bb!.__indirect(this.bb!.__vector(this.bb_pos + offset) + index * 4), this.bb!) : null;
+}
+
+membersLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+countdownMs():number {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? this.bb!.readUint32(this.bb_pos + offset) : 0;
+}
+
+static startLobbyUpdate(builder:flatbuffers.Builder) {
+  builder.startObject(3);
+}
+
+static addPlayerId(builder:flatbuffers.Builder, playerId:bigint) {
+  builder.addFieldInt64(0, playerId, BigInt('0'));
+}
+
+static addMembers(builder:flatbuffers.Builder, membersOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, membersOffset, 0);
+}
+
+static createMembersVector(builder:flatbuffers.Builder, data:flatbuffers.Offset[]):flatbuffers.Offset {
+  builder.startVector(4, data.length, 4);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addOffset(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startMembersVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(4, numElems, 4);
+}
+
+static addCountdownMs(builder:flatbuffers.Builder, countdownMs:number) {
+  builder.addFieldInt32(2, countdownMs, 0);
+}
+
+static endLobbyUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createLobbyUpdate(builder:flatbuffers.Builder, playerId:bigint, membersOffset:flatbuffers.Offset, countdownMs:number):flatbuffers.Offset {
+  LobbyUpdate.startLobbyUpdate(builder);
+  LobbyUpdate.addPlayerId(builder, playerId);
+  LobbyUpdate.addMembers(builder, membersOffset);
+  LobbyUpdate.addCountdownMs(builder, countdownMs);
+  return LobbyUpdate.endLobbyUpdate(builder);
+}
+}
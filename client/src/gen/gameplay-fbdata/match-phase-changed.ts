@@ -0,0 +1,59 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { MatchPhase } from '../gameplay-fbdata/match-phase.js';
+
+
+export class MatchPhaseChanged {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):MatchPhaseChanged {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsMatchPhaseChanged(bb:flatbuffers.ByteBuffer, obj?:MatchPhaseChanged):MatchPhaseChanged {
+  return (obj || new MatchPhaseChanged()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsMatchPhaseChanged(bb:flatbuffers.ByteBuffer, obj?:MatchPhaseChanged):MatchPhaseChanged {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new MatchPhaseChanged()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+phase():MatchPhase {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readInt8(this.bb_pos + offset) : MatchPhase.Warmup;
+}
+
+durationMs():number {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint32(this.bb_pos + offset) : 0;
+}
+
+static startMatchPhaseChanged(builder:flatbuffers.Builder) {
+  builder.startObject(2);
+}
+
+static addPhase(builder:flatbuffers.Builder, phase:MatchPhase) {
+  builder.addFieldInt8(0, phase, MatchPhase.Warmup);
+}
+
+static addDurationMs(builder:flatbuffers.Builder, durationMs:number) {
+  builder.addFieldInt32(1, durationMs, 0);
+}
+
+static endMatchPhaseChanged(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createMatchPhaseChanged(builder:flatbuffers.Builder, phase:MatchPhase, durationMs:number):flatbuffers.Offset {
+  MatchPhaseChanged.startMatchPhaseChanged(builder);
+  MatchPhaseChanged.addPhase(builder, phase);
+  MatchPhaseChanged.addDurationMs(builder, durationMs);
+  return MatchPhaseChanged.endMatchPhaseChanged(builder);
+}
+}
@@ -0,0 +1,7 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+export enum MatchPhase {
+  Warmup = 0,
+  Live = 1,
+  Ended = 2
+}
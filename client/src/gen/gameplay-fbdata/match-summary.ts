@@ -0,0 +1,98 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { PlayerScore } from '../gameplay-fbdata/player-score.js';
+
+
+export class MatchSummary {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):MatchSummary {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsMatchSummary(bb:flatbuffers.ByteBuffer, obj?:MatchSummary):MatchSummary {
+  return (obj || new MatchSummary()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsMatchSummary(bb:flatbuffers.ByteBuffer, obj?:MatchSummary):MatchSummary {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new MatchSummary()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+winnerPlayerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+winningTeam():number {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint8(this.bb_pos + offset) : 0;
+}
+
+isTeamDraw():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+durationMs():number {
+  const offset = this.bb!.__offset(this.bb_pos, 10);
+  return offset ? this.bb!.readUint32(this.bb_pos + offset) : 0;
+}
+
+scores(index: number, obj?:PlayerScore):PlayerScore|null {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? (obj || new PlayerScore()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 24, this.bb!) : null;
+}
+
+scoresLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 12);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+static startMatchSummary(builder:flatbuffers.Builder) {
+  builder.startObject(5);
+}
+
+static addWinnerPlayerId(builder:flatbuffers.Builder, winnerPlayerId:bigint) {
+  builder.addFieldInt64(0, winnerPlayerId, BigInt('0'));
+}
+
+static addWinningTeam(builder:flatbuffers.Builder, winningTeam:number) {
+  builder.addFieldInt8(1, winningTeam, 0);
+}
+
+static addIsTeamDraw(builder:flatbuffers.Builder, isTeamDraw:boolean) {
+  builder.addFieldInt8(2, +isTeamDraw, +false);
+}
+
+static addDurationMs(builder:flatbuffers.Builder, durationMs:number) {
+  builder.addFieldInt32(3, durationMs, 0);
+}
+
+static addScores(builder:flatbuffers.Builder, scoresOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(4, scoresOffset, 0);
+}
+
+static startScoresVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(24, numElems, 8);
+}
+
+static endMatchSummary(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createMatchSummary(builder:flatbuffers.Builder, winnerPlayerId:bigint, winningTeam:number, isTeamDraw:boolean, durationMs:number, scoresOffset:flatbuffers.Offset):flatbuffers.Offset {
+  MatchSummary.startMatchSummary(builder);
+  MatchSummary.addWinnerPlayerId(builder, winnerPlayerId);
+  MatchSummary.addWinningTeam(builder, winningTeam);
+  MatchSummary.addIsTeamDraw(builder, isTeamDraw);
+  MatchSummary.addDurationMs(builder, durationMs);
+  MatchSummary.addScores(builder, scoresOffset);
+  return MatchSummary.endMatchSummary(builder);
+}
+}
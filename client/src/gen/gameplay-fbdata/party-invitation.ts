@@ -0,0 +1,46 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PartyInvitation {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PartyInvitation {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPartyInvitation(bb:flatbuffers.ByteBuffer, obj?:PartyInvitation):PartyInvitation {
+  return (obj || new PartyInvitation()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPartyInvitation(bb:flatbuffers.ByteBuffer, obj?:PartyInvitation):PartyInvitation {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PartyInvitation()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+leaderId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+static startPartyInvitation(builder:flatbuffers.Builder) {
+  builder.startObject(1);
+}
+
+static addLeaderId(builder:flatbuffers.Builder, leaderId:bigint) {
+  builder.addFieldInt64(0, leaderId, BigInt('0'));
+}
+
+static endPartyInvitation(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createPartyInvitation(builder:flatbuffers.Builder, leaderId:bigint):flatbuffers.Offset {
+  PartyInvitation.startPartyInvitation(builder);
+  PartyInvitation.addLeaderId(builder, leaderId);
+  return PartyInvitation.endPartyInvitation(builder);
+}
+}
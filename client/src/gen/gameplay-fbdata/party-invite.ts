@@ -0,0 +1,46 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PartyInvite {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PartyInvite {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPartyInvite(bb:flatbuffers.ByteBuffer, obj?:PartyInvite):PartyInvite {
+  return (obj || new PartyInvite()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPartyInvite(bb:flatbuffers.ByteBuffer, obj?:PartyInvite):PartyInvite {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PartyInvite()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+playerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+static startPartyInvite(builder:flatbuffers.Builder) {
+  builder.startObject(1);
+}
+
+static addPlayerId(builder:flatbuffers.Builder, playerId:bigint) {
+  builder.addFieldInt64(0, playerId, BigInt('0'));
+}
+
+static endPartyInvite(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createPartyInvite(builder:flatbuffers.Builder, playerId:bigint):flatbuffers.Offset {
+  PartyInvite.startPartyInvite(builder);
+  PartyInvite.addPlayerId(builder, playerId);
+  return PartyInvite.endPartyInvite(builder);
+}
+}
@@ -0,0 +1,36 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PartyLeave {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PartyLeave {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPartyLeave(bb:flatbuffers.ByteBuffer, obj?:PartyLeave):PartyLeave {
+  return (obj || new PartyLeave()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPartyLeave(bb:flatbuffers.ByteBuffer, obj?:PartyLeave):PartyLeave {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PartyLeave()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static startPartyLeave(builder:flatbuffers.Builder) {
+  builder.startObject(0);
+}
+
+static endPartyLeave(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createPartyLeave(builder:flatbuffers.Builder):flatbuffers.Offset {
+  PartyLeave.startPartyLeave(builder);
+  return PartyLeave.endPartyLeave(builder);
+}
+}
@@ -0,0 +1,56 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PartyRespond {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PartyRespond {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPartyRespond(bb:flatbuffers.ByteBuffer, obj?:PartyRespond):PartyRespond {
+  return (obj || new PartyRespond()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPartyRespond(bb:flatbuffers.ByteBuffer, obj?:PartyRespond):PartyRespond {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PartyRespond()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+leaderId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+accept():boolean {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? !!this.bb!.readInt8(this.bb_pos + offset) : false;
+}
+
+static startPartyRespond(builder:flatbuffers.Builder) {
+  builder.startObject(2);
+}
+
+static addLeaderId(builder:flatbuffers.Builder, leaderId:bigint) {
+  builder.addFieldInt64(0, leaderId, BigInt('0'));
+}
+
+static addAccept(builder:flatbuffers.Builder, accept:boolean) {
+  builder.addFieldInt8(1, +accept, +false);
+}
+
+static endPartyRespond(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createPartyRespond(builder:flatbuffers.Builder, leaderId:bigint, accept:boolean):flatbuffers.Offset {
+  PartyRespond.startPartyRespond(builder);
+  PartyRespond.addLeaderId(builder, leaderId);
+  PartyRespond.addAccept(builder, accept);
+  return PartyRespond.endPartyRespond(builder);
+}
+}
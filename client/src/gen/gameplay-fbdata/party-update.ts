@@ -0,0 +1,73 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PartyUpdate {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PartyUpdate {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPartyUpdate(bb:flatbuffers.ByteBuffer, obj?:PartyUpdate):PartyUpdate {
+  return (obj || new PartyUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPartyUpdate(bb:flatbuffers.ByteBuffer, obj?:PartyUpdate):PartyUpdate {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PartyUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+leaderId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+members(index: number):bigint|null {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint64(this.bb!.__vector(this.bb_pos + offset) + index * 8) : BigInt(0);
+}
+
+membersLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+static startPartyUpdate(builder:flatbuffers.Builder) {
+  builder.startObject(2);
+}
+
+static addLeaderId(builder:flatbuffers.Builder, leaderId:bigint) {
+  builder.addFieldInt64(0, leaderId, BigInt('0'));
+}
+
+static addMembers(builder:flatbuffers.Builder, membersOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(1, membersOffset, 0);
+}
+
+static createMembersVector(builder:flatbuffers.Builder, data:bigint[]):flatbuffers.Offset {
+  builder.startVector(8, data.length, 8);
+  for (let i = data.length - 1; i >= 0; i--) {
+    builder.addInt64(data[i]!);
+  }
+  return builder.endVector();
+}
+
+static startMembersVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(8, numElems, 8);
+}
+
+static endPartyUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createPartyUpdate(builder:flatbuffers.Builder, leaderId:bigint, membersOffset:flatbuffers.Offset):flatbuffers.Offset {
+  PartyUpdate.startPartyUpdate(builder);
+  PartyUpdate.addLeaderId(builder, leaderId);
+  PartyUpdate.addMembers(builder, membersOffset);
+  return PartyUpdate.endPartyUpdate(builder);
+}
+}
@@ -22,12 +22,18 @@ playerPosition(obj?:Vec2):Vec2|null {
   return (obj || new Vec2()).__init(this.bb_pos + 8, this.bb!);
 }
 
+team():number {
+  return this.bb!.readUint8(this.bb_pos + 16);
+}
+
 static sizeOf():number {
-  return 16;
+  return 24;
 }
 
-static createPlayerData(builder:flatbuffers.Builder, player_id: bigint, player_position_x: number, player_position_y: number):flatbuffers.Offset {
-  builder.prep(8, 16);
+static createPlayerData(builder:flatbuffers.Builder, player_id: bigint, player_position_x: number, player_position_y: number, team: number):flatbuffers.Offset {
+  builder.prep(8, 24);
+  builder.pad(7);
+  builder.writeInt8(team);
   builder.prep(4, 8);
   builder.writeFloat32(player_position_y);
   builder.writeFloat32(player_position_x);
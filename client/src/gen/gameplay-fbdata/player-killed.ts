@@ -0,0 +1,62 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { Vec2 } from '../gameplay-fbdata/vec2.js';
+
+
+export class PlayerKilled {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PlayerKilled {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsPlayerKilled(bb:flatbuffers.ByteBuffer, obj?:PlayerKilled):PlayerKilled {
+  return (obj || new PlayerKilled()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsPlayerKilled(bb:flatbuffers.ByteBuffer, obj?:PlayerKilled):PlayerKilled {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new PlayerKilled()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+killerPlayerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+victimPlayerId():bigint {
+  const offset = this.bb!.__offset(this.bb_pos, 6);
+  return offset ? this.bb!.readUint64(this.bb_pos + offset) : BigInt('0');
+}
+
+respawnPosition(obj?:Vec2):Vec2|null {
+  const offset = this.bb!.__offset(this.bb_pos, 8);
+  return offset ? (obj || new Vec2()).__init(this.bb_pos + offset, this.bb!) : null;
+}
+
+static startPlayerKilled(builder:flatbuffers.Builder) {
+  builder.startObject(3);
+}
+
+static addKillerPlayerId(builder:flatbuffers.Builder, killerPlayerId:bigint) {
+  builder.addFieldInt64(0, killerPlayerId, BigInt('0'));
+}
+
+static addVictimPlayerId(builder:flatbuffers.Builder, victimPlayerId:bigint) {
+  builder.addFieldInt64(1, victimPlayerId, BigInt('0'));
+}
+
+static addRespawnPosition(builder:flatbuffers.Builder, respawnPositionOffset:flatbuffers.Offset) {
+  builder.addFieldStruct(2, respawnPositionOffset, 0);
+}
+
+static endPlayerKilled(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+}
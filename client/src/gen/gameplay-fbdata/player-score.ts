@@ -0,0 +1,49 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+export class PlayerScore {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):PlayerScore {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+playerId():bigint {
+  return this.bb!.readUint64(this.bb_pos);
+}
+
+kills():number {
+  return this.bb!.readUint32(this.bb_pos + 8);
+}
+
+deaths():number {
+  return this.bb!.readUint32(this.bb_pos + 12);
+}
+
+points():number {
+  return this.bb!.readInt32(this.bb_pos + 16);
+}
+
+team():number {
+  return this.bb!.readUint8(this.bb_pos + 20);
+}
+
+static sizeOf():number {
+  return 24;
+}
+
+static createPlayerScore(builder:flatbuffers.Builder, player_id: bigint, kills: number, deaths: number, points: number, team: number):flatbuffers.Offset {
+  builder.prep(8, 24);
+  builder.pad(3);
+  builder.writeInt8(team);
+  builder.writeInt32(points);
+  builder.writeInt32(deaths);
+  builder.writeInt32(kills);
+  builder.writeInt64(BigInt(player_id ?? 0));
+  return builder.offset();
+}
+
+}
@@ -1,5 +1,11 @@
 // automatically generated by the FlatBuffers compiler, do not modify
 
+import { ChatSend } from '../gameplay-fbdata/chat-send.js';
+import { LobbyReady } from '../gameplay-fbdata/lobby-ready.js';
+import { LobbySetup } from '../gameplay-fbdata/lobby-setup.js';
+import { PartyInvite } from '../gameplay-fbdata/party-invite.js';
+import { PartyLeave } from '../gameplay-fbdata/party-leave.js';
+import { PartyRespond } from '../gameplay-fbdata/party-respond.js';
 import { PlayerMoved } from '../gameplay-fbdata/player-moved.js';
 import { WeaponFired } from '../gameplay-fbdata/weapon-fired.js';
 
@@ -7,30 +13,48 @@ import { WeaponFired } from '../gameplay-fbdata/weapon-fired.js';
 export enum RequestMessages {
   NONE = 0,
   PlayerMoved = 1,
-  WeaponFired = 2
+  WeaponFired = 2,
+  ChatSend = 3,
+  LobbySetup = 4,
+  LobbyReady = 5,
+  PartyInvite = 6,
+  PartyRespond = 7,
+  PartyLeave = 8
 }
 
 export function unionToRequestMessages(
   type: RequestMessages,
-  accessor: (obj:PlayerMoved|WeaponFired) => PlayerMoved|WeaponFired|null
-): PlayerMoved|WeaponFired|null {
+  accessor: (obj:ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired) => ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired|null
+): ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired|null {
   switch(RequestMessages[type]) {
     case 'NONE': return null; 
     case 'PlayerMoved': return accessor(new PlayerMoved())! as PlayerMoved;
     case 'WeaponFired': return accessor(new WeaponFired())! as WeaponFired;
+    case 'ChatSend': return accessor(new ChatSend())! as ChatSend;
+    case 'LobbySetup': return accessor(new LobbySetup())! as LobbySetup;
+    case 'LobbyReady': return accessor(new LobbyReady())! as LobbyReady;
+    case 'PartyInvite': return accessor(new PartyInvite())! as PartyInvite;
+    case 'PartyRespond': return accessor(new PartyRespond())! as PartyRespond;
+    case 'PartyLeave': return accessor(new PartyLeave())! as PartyLeave;
     default: return null;
   }
 }
 
 export function unionListToRequestMessages(
   type: RequestMessages, 
-  accessor: (index: number, obj:PlayerMoved|WeaponFired) => PlayerMoved|WeaponFired|null, 
+  accessor: (index: number, obj:ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired) => ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired|null, 
   index: number
-): PlayerMoved|WeaponFired|null {
+): ChatSend|LobbyReady|LobbySetup|PartyInvite|PartyLeave|PartyRespond|PlayerMoved|WeaponFired|null {
   switch(RequestMessages[type]) {
     case 'NONE': return null; 
     case 'PlayerMoved': return accessor(index, new PlayerMoved())! as PlayerMoved;
     case 'WeaponFired': return accessor(index, new WeaponFired())! as WeaponFired;
+    case 'ChatSend': return accessor(index, new ChatSend())! as ChatSend;
+    case 'LobbySetup': return accessor(index, new LobbySetup())! as LobbySetup;
+    case 'LobbyReady': return accessor(index, new LobbyReady())! as LobbyReady;
+    case 'PartyInvite': return accessor(index, new PartyInvite())! as PartyInvite;
+    case 'PartyRespond': return accessor(index, new PartyRespond())! as PartyRespond;
+    case 'PartyLeave': return accessor(index, new PartyLeave())! as PartyLeave;
     default: return null;
   }
 }
@@ -1,9 +1,18 @@
 // automatically generated by the FlatBuffers compiler, do not modify
 
+import { ChatMessage } from '../gameplay-fbdata/chat-message.js';
+import { FlagUpdate } from '../gameplay-fbdata/flag-update.js';
 import { GameWorldUpdate } from '../gameplay-fbdata/game-world-update.js';
+import { LobbyUpdate } from '../gameplay-fbdata/lobby-update.js';
+import { MatchPhaseChanged } from '../gameplay-fbdata/match-phase-changed.js';
+import { MatchSummary } from '../gameplay-fbdata/match-summary.js';
+import { PartyInvitation } from '../gameplay-fbdata/party-invitation.js';
+import { PartyUpdate } from '../gameplay-fbdata/party-update.js';
+import { PlayerKilled } from '../gameplay-fbdata/player-killed.js';
 import { RemotePeerJoined } from '../gameplay-fbdata/remote-peer-joined.js';
 import { RemotePeerLeft } from '../gameplay-fbdata/remote-peer-left.js';
 import { RemotePeerPositionUpdate } from '../gameplay-fbdata/remote-peer-position-update.js';
+import { ScoreboardUpdate } from '../gameplay-fbdata/scoreboard-update.js';
 
 
 export enum ResponseMessage {
@@ -11,34 +20,61 @@ export enum ResponseMessage {
   RemotePeerJoined = 1,
   RemotePeerLeft = 2,
   RemotePeerPositionUpdate = 3,
-  GameWorldUpdate = 4
+  GameWorldUpdate = 4,
+  ChatMessage = 5,
+  MatchPhaseChanged = 6,
+  PlayerKilled = 7,
+  ScoreboardUpdate = 8,
+  MatchSummary = 9,
+  FlagUpdate = 10,
+  LobbyUpdate = 11,
+  PartyInvitation = 12,
+  PartyUpdate = 13
 }
 
 export function unionToResponseMessage(
   type: ResponseMessage,
-  accessor: (obj:GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate) => GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|null
-): GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|null {
+  accessor: (obj:ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate) => ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate|null
+): ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate|null {
   switch(ResponseMessage[type]) {
     case 'NONE': return null; 
     case 'RemotePeerJoined': return accessor(new RemotePeerJoined())! as RemotePeerJoined;
     case 'RemotePeerLeft': return accessor(new RemotePeerLeft())! as RemotePeerLeft;
     case 'RemotePeerPositionUpdate': return accessor(new RemotePeerPositionUpdate())! as RemotePeerPositionUpdate;
     case 'GameWorldUpdate': return accessor(new GameWorldUpdate())! as GameWorldUpdate;
+    case 'ChatMessage': return accessor(new ChatMessage())! as ChatMessage;
+    case 'MatchPhaseChanged': return accessor(new MatchPhaseChanged())! as MatchPhaseChanged;
+    case 'PlayerKilled': return accessor(new PlayerKilled())! as PlayerKilled;
+    case 'ScoreboardUpdate': return accessor(new ScoreboardUpdate())! as ScoreboardUpdate;
+    case 'MatchSummary': return accessor(new MatchSummary())! as MatchSummary;
+    case 'FlagUpdate': return accessor(new FlagUpdate())! as FlagUpdate;
+    case 'LobbyUpdate': return accessor(new LobbyUpdate())! as LobbyUpdate;
+    case 'PartyInvitation': return accessor(new PartyInvitation())! as PartyInvitation;
+    case 'PartyUpdate': return accessor(new PartyUpdate())! as PartyUpdate;
     default: return null;
   }
 }
 
 export function unionListToResponseMessage(
   type: ResponseMessage, 
-  accessor: (index: number, obj:GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate) => GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|null, 
+  accessor: (index: number, obj:ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate) => ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate|null, 
   index: number
-): GameWorldUpdate|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|null {
+): ChatMessage|FlagUpdate|GameWorldUpdate|LobbyUpdate|MatchPhaseChanged|MatchSummary|PartyInvitation|PartyUpdate|PlayerKilled|RemotePeerJoined|RemotePeerLeft|RemotePeerPositionUpdate|ScoreboardUpdate|null {
   switch(ResponseMessage[type]) {
     case 'NONE': return null; 
     case 'RemotePeerJoined': return accessor(index, new RemotePeerJoined())! as RemotePeerJoined;
     case 'RemotePeerLeft': return accessor(index, new RemotePeerLeft())! as RemotePeerLeft;
     case 'RemotePeerPositionUpdate': return accessor(index, new RemotePeerPositionUpdate())! as RemotePeerPositionUpdate;
     case 'GameWorldUpdate': return accessor(index, new GameWorldUpdate())! as GameWorldUpdate;
+    case 'ChatMessage': return accessor(index, new ChatMessage())! as ChatMessage;
+    case 'MatchPhaseChanged': return accessor(index, new MatchPhaseChanged())! as MatchPhaseChanged;
+    case 'PlayerKilled': return accessor(index, new PlayerKilled())! as PlayerKilled;
+    case 'ScoreboardUpdate': return accessor(index, new ScoreboardUpdate())! as ScoreboardUpdate;
+    case 'MatchSummary': return accessor(index, new MatchSummary())! as MatchSummary;
+    case 'FlagUpdate': return accessor(index, new FlagUpdate())! as FlagUpdate;
+    case 'LobbyUpdate': return accessor(index, new LobbyUpdate())! as LobbyUpdate;
+    case 'PartyInvitation': return accessor(index, new PartyInvitation())! as PartyInvitation;
+    case 'PartyUpdate': return accessor(index, new PartyUpdate())! as PartyUpdate;
     default: return null;
   }
 }
@@ -0,0 +1,58 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+import * as flatbuffers from 'flatbuffers';
+
+import { PlayerScore } from '../gameplay-fbdata/player-score.js';
+
+
+export class ScoreboardUpdate {
+  bb: flatbuffers.ByteBuffer|null = null;
+  bb_pos = 0;
+  __init(i:number, bb:flatbuffers.ByteBuffer):ScoreboardUpdate {
+  this.bb_pos = i;
+  this.bb = bb;
+  return this;
+}
+
+static getRootAsScoreboardUpdate(bb:flatbuffers.ByteBuffer, obj?:ScoreboardUpdate):ScoreboardUpdate {
+  return (obj || new ScoreboardUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+static getSizePrefixedRootAsScoreboardUpdate(bb:flatbuffers.ByteBuffer, obj?:ScoreboardUpdate):ScoreboardUpdate {
+  bb.setPosition(bb.position() + flatbuffers.SIZE_PREFIX_LENGTH);
+  return (obj || new ScoreboardUpdate()).__init(bb.readInt32(bb.position()) + bb.position(), bb);
+}
+
+scores(index: number, obj?:PlayerScore):PlayerScore|null {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? (obj || new PlayerScore()).__init(this.bb!.__vector(this.bb_pos + offset) + index * 24, this.bb!) : null;
+}
+
+scoresLength():number {
+  const offset = this.bb!.__offset(this.bb_pos, 4);
+  return offset ? this.bb!.__vector_len(this.bb_pos + offset) : 0;
+}
+
+static startScoreboardUpdate(builder:flatbuffers.Builder) {
+  builder.startObject(1);
+}
+
+static addScores(builder:flatbuffers.Builder, scoresOffset:flatbuffers.Offset) {
+  builder.addFieldOffset(0, scoresOffset, 0);
+}
+
+static startScoresVector(builder:flatbuffers.Builder, numElems:number) {
+  builder.startVector(24, numElems, 8);
+}
+
+static endScoreboardUpdate(builder:flatbuffers.Builder):flatbuffers.Offset {
+  const offset = builder.endObject();
+  return offset;
+}
+
+static createScoreboardUpdate(builder:flatbuffers.Builder, scoresOffset:flatbuffers.Offset):flatbuffers.Offset {
+  ScoreboardUpdate.startScoreboardUpdate(builder);
+  ScoreboardUpdate.addScores(builder, scoresOffset);
+  return ScoreboardUpdate.endScoreboardUpdate(builder);
+}
+}
@@ -2,22 +2,103 @@ import { Disposable, DisposeBag } from '../utils/dispose-bag';
 import { fromEvent, Observable, ReplaySubject, Subject } from 'rxjs';
 import { Builder, ByteBuffer } from 'flatbuffers';
 import {
+	ChatChannel,
+	ChatMessage,
+	ChatSend,
+	FlagEvent,
+	FlagUpdate,
 	GameReponseEvent,
 	GameRequestEvent,
 	GameWorldUpdate,
+	LobbyMember,
+	LobbyReady,
+	LobbySetup,
+	LobbyUpdate,
+	MatchPhase,
+	MatchPhaseChanged,
+	MatchSummary,
+	PartyInvitation,
+	PartyInvite,
+	PartyLeave,
+	PartyRespond,
+	PartyUpdate,
 	PlayerControl,
+	PlayerKilled,
 	PlayerMoved,
+	PlayerScore,
 	RemotePeerJoined,
 	RemotePeerLeft,
 	RemotePeerPositionUpdate,
 	RequestMessages,
 	ResponseMessage,
+	ScoreboardUpdate,
 	Vec2,
 	WeaponFired,
 } from '../gen/gameplay-fbdata';
 
+interface ChatMessageUpdate {
+	readonly senderId: string;
+	readonly isDirect: boolean;
+	readonly isTeam: boolean;
+	readonly text: string;
+}
+
+interface MatchPhaseUpdate {
+	readonly phase: MatchPhase;
+	/** time left in the phase, 0 when it has no time limit */
+	readonly durationMs: number;
+}
+
+interface PlayerKilledUpdate {
+	readonly killerId: string;
+	readonly victimId: string;
+	readonly respawnX: number;
+	readonly respawnY: number;
+}
+
+interface ScoreUpdate {
+	readonly playerId: string;
+	readonly team: number;
+	readonly kills: number;
+	readonly deaths: number;
+	readonly points: number;
+}
+
+interface LobbyMemberUpdate {
+	readonly playerId: string;
+	readonly name: string;
+	readonly skin: string;
+	/** -1 when the server picks the team */
+	readonly team: number;
+	readonly ready: boolean;
+}
+
+interface LobbyStateUpdate {
+	/** the receiving player's own id */
+	readonly playerId: string;
+	readonly members: ReadonlyArray<LobbyMemberUpdate>;
+	/** time until the match starts anyway, 0 when it waits for everyone to be ready */
+	readonly countdownMs: number;
+}
+
+interface PartyStateUpdate {
+	readonly leaderId: string;
+	/** empty once this player is no longer in a party */
+	readonly members: ReadonlyArray<string>;
+}
+
+interface FlagStateUpdate {
+	readonly team: number;
+	readonly event: FlagEvent;
+	/** empty when the flag returned by itself */
+	readonly playerId: string;
+	readonly x: number;
+	readonly y: number;
+}
+
 interface PeerPlayerUpdate {
 	readonly playerId: string;
+	readonly team: number;
 	readonly x: number;
 	readonly y: number;
 }
@@ -27,6 +108,14 @@ export class CommsManager implements Disposable {
 	private readonly _peerPlayerUpdateSubject$ = new ReplaySubject<ReadonlyArray<PeerPlayerUpdate>>(1);
 	private readonly _peerPlayerLeftSubject$ = new Subject<{ readonly playerId: string }>();
 	private readonly _peerPlayerJoinedSubject$ = new Subject<{ readonly playerId: string }>();
+	private readonly _chatMessageSubject$ = new Subject<ChatMessageUpdate>();
+	private readonly _matchPhaseSubject$ = new ReplaySubject<MatchPhaseUpdate>(1);
+	private readonly _playerKilledSubject$ = new Subject<PlayerKilledUpdate>();
+	private readonly _scoreboardSubject$ = new ReplaySubject<ReadonlyArray<ScoreUpdate>>(1);
+	private readonly _flagSubject$ = new Subject<FlagStateUpdate>();
+	private readonly _lobbySubject$ = new ReplaySubject<LobbyStateUpdate>(1);
+	private readonly _partyInvitationSubject$ = new Subject<{ readonly leaderId: string }>();
+	private readonly _partySubject$ = new ReplaySubject<PartyStateUpdate>(1);
 	private readonly _disposeBag = new DisposeBag();
 	private readonly _socket: WebSocket;
 
@@ -67,7 +156,7 @@ export class CommsManager implements Disposable {
 				const playerPosition = msg.playerData().playerPosition();
 				const playerId = BigInt(playerData.playerId()).toString();
 
-				console.log('Remote player joined: ', playerId, { x: playerPosition.x(), y: playerPosition.y() });
+				console.log('Remote player joined: ', playerId, { team: playerData.team(), x: playerPosition.x(), y: playerPosition.y() });
 				this._peerPlayerJoinedSubject$.next({
 					playerId: playerId,
 				});
@@ -93,6 +182,7 @@ export class CommsManager implements Disposable {
 				this._peerPlayerUpdateSubject$.next([
 					<PeerPlayerUpdate>{
 						playerId: playerId,
+						team: playerData.team(),
 						x: playerPosition.x(),
 						y: playerPosition.y(),
 					},
@@ -100,18 +190,104 @@ export class CommsManager implements Disposable {
 			} else if (eventType === ResponseMessage.GameWorldUpdate) {
 				const updateDate = GameWorldUpdate.getRootAsGameWorldUpdate(buffer);
 				const msg: GameWorldUpdate = gameResponseEvent.msg(updateDate);
+				console.log('Joined map: ', msg.mapId(), msg.gameMode());
 
 				const playerUpdateList = Array.from({ length: msg.playerDataListLength() }).map((_, index) => {
 					const playerData = msg.playerDataList(index);
 					const pos = playerData.playerPosition();
 					const update: PeerPlayerUpdate = {
 						playerId: playerData.playerId().toString(),
+						team: playerData.team(),
 						x: pos.x(),
 						y: pos.y(),
 					};
 					return update;
 				});
 				this._peerPlayerUpdateSubject$.next(playerUpdateList);
+			} else if (eventType === ResponseMessage.ChatMessage) {
+				const chatData = ChatMessage.getRootAsChatMessage(buffer);
+				const msg: ChatMessage = gameResponseEvent.msg(chatData);
+
+				this._chatMessageSubject$.next({
+					senderId: BigInt(msg.senderPlayerId()).toString(),
+					isDirect: msg.channel() === ChatChannel.Direct,
+					isTeam: msg.channel() === ChatChannel.Team,
+					text: msg.text() ?? '',
+				});
+			} else if (eventType === ResponseMessage.MatchPhaseChanged) {
+				const phaseData = MatchPhaseChanged.getRootAsMatchPhaseChanged(buffer);
+				const msg: MatchPhaseChanged = gameResponseEvent.msg(phaseData);
+
+				console.log('Match phase: ', MatchPhase[msg.phase()], msg.durationMs());
+				this._matchPhaseSubject$.next({ phase: msg.phase(), durationMs: msg.durationMs() });
+			} else if (eventType === ResponseMessage.PlayerKilled) {
+				const killData = PlayerKilled.getRootAsPlayerKilled(buffer);
+				const msg: PlayerKilled = gameResponseEvent.msg(killData);
+				const respawn = msg.respawnPosition();
+
+				this._playerKilledSubject$.next({
+					killerId: BigInt(msg.killerPlayerId()).toString(),
+					victimId: BigInt(msg.victimPlayerId()).toString(),
+					respawnX: respawn?.x() ?? 0,
+					respawnY: respawn?.y() ?? 0,
+				});
+			} else if (eventType === ResponseMessage.ScoreboardUpdate) {
+				const scoreData = ScoreboardUpdate.getRootAsScoreboardUpdate(buffer);
+				const msg: ScoreboardUpdate = gameResponseEvent.msg(scoreData);
+
+				this._scoreboardSubject$.next(
+					Array.from({ length: msg.scoresLength() }).map((_, index) => toScoreUpdate(msg.scores(index)!)),
+				);
+			} else if (eventType === ResponseMessage.MatchSummary) {
+				const summaryData = MatchSummary.getRootAsMatchSummary(buffer);
+				const msg: MatchSummary = gameResponseEvent.msg(summaryData);
+
+				console.log('Match ended, winner: ', BigInt(msg.winnerPlayerId()).toString(), {
+					winningTeam: msg.isTeamDraw() ? null : msg.winningTeam(),
+					durationMs: msg.durationMs(),
+				});
+				this._scoreboardSubject$.next(
+					Array.from({ length: msg.scoresLength() }).map((_, index) => toScoreUpdate(msg.scores(index)!)),
+				);
+			} else if (eventType === ResponseMessage.LobbyUpdate) {
+				const lobbyData = LobbyUpdate.getRootAsLobbyUpdate(buffer);
+				const msg: LobbyUpdate = gameResponseEvent.msg(lobbyData);
+
+				this._lobbySubject$.next({
+					playerId: BigInt(msg.playerId()).toString(),
+					members: Array.from({ length: msg.membersLength() }).map((_, index) =>
+						toLobbyMemberUpdate(msg.members(index)!),
+					),
+					countdownMs: msg.countdownMs(),
+				});
+			} else if (eventType === ResponseMessage.PartyInvitation) {
+				const invitationData = PartyInvitation.getRootAsPartyInvitation(buffer);
+				const msg: PartyInvitation = gameResponseEvent.msg(invitationData);
+
+				this._partyInvitationSubject$.next({ leaderId: BigInt(msg.leaderId()).toString() });
+			} else if (eventType === ResponseMessage.PartyUpdate) {
+				const partyData = PartyUpdate.getRootAsPartyUpdate(buffer);
+				const msg: PartyUpdate = gameResponseEvent.msg(partyData);
+
+				this._partySubject$.next({
+					leaderId: BigInt(msg.leaderId()).toString(),
+					members: Array.from({ length: msg.membersLength() }).map((_, index) =>
+						BigInt(msg.members(index)!).toString(),
+					),
+				});
+			} else if (eventType === ResponseMessage.FlagUpdate) {
+				const flagData = FlagUpdate.getRootAsFlagUpdate(buffer);
+				const msg: FlagUpdate = gameResponseEvent.msg(flagData);
+				const playerId = BigInt(msg.playerId());
+				const position = msg.position();
+
+				this._flagSubject$.next({
+					team: msg.team(),
+					event: msg.event(),
+					playerId: playerId === BigInt(0) ? '' : playerId.toString(),
+					x: position?.x() ?? 0,
+					y: position?.y() ?? 0,
+				});
 			}
 		});
 
@@ -164,6 +340,38 @@ export class CommsManager implements Disposable {
 		return this._peerPlayerLeftSubject$.asObservable();
 	}
 
+	get chatMessage$(): Observable<ChatMessageUpdate> {
+		return this._chatMessageSubject$.asObservable();
+	}
+
+	get matchPhase$(): Observable<MatchPhaseUpdate> {
+		return this._matchPhaseSubject$.asObservable();
+	}
+
+	get playerKilled$(): Observable<PlayerKilledUpdate> {
+		return this._playerKilledSubject$.asObservable();
+	}
+
+	get scoreboard$(): Observable<ReadonlyArray<ScoreUpdate>> {
+		return this._scoreboardSubject$.asObservable();
+	}
+
+	get flag$(): Observable<FlagStateUpdate> {
+		return this._flagSubject$.asObservable();
+	}
+
+	get lobby$(): Observable<LobbyStateUpdate> {
+		return this._lobbySubject$.asObservable();
+	}
+
+	get partyInvitation$(): Observable<{ readonly leaderId: string }> {
+		return this._partyInvitationSubject$.asObservable();
+	}
+
+	get party$(): Observable<PartyStateUpdate> {
+		return this._partySubject$.asObservable();
+	}
+
 	sendPlayerMoved(): void {
 		// console.log('sendUpdates ', playerPosition);
 		if (this._isSocketClosed) {
@@ -208,4 +416,138 @@ export class CommsManager implements Disposable {
 		const bytes = builder.asUint8Array();
 		this._socket.send(bytes);
 	}
+
+	sendChatMessage(text: string, targetPlayerId?: string): void {
+		this.sendChat(targetPlayerId === undefined ? ChatChannel.Room : ChatChannel.Direct, text, targetPlayerId);
+	}
+
+	sendTeamChatMessage(text: string): void {
+		this.sendChat(ChatChannel.Team, text);
+	}
+
+	/** team of -1 leaves the team up to the server */
+	sendLobbySetup(name: string, skin: string, team: number): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const nameOffset = builder.createString(name);
+		const skinOffset = builder.createString(skin);
+		const msgOffset = LobbySetup.createLobbySetup(builder, nameOffset, skinOffset, Math.max(team, 0), team >= 0);
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.LobbySetup, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+
+	sendLobbyReady(ready: boolean): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const msgOffset = LobbyReady.createLobbyReady(builder, ready);
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.LobbyReady, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+
+	sendPartyInvite(playerId: string): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const msgOffset = PartyInvite.createPartyInvite(builder, BigInt(playerId));
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.PartyInvite, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+
+	sendPartyResponse(leaderId: string, accept: boolean): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const msgOffset = PartyRespond.createPartyRespond(builder, BigInt(leaderId), accept);
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.PartyRespond, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+
+	sendPartyLeave(): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const msgOffset = PartyLeave.createPartyLeave(builder);
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.PartyLeave, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+
+	private sendChat(channel: ChatChannel, text: string, targetPlayerId?: string): void {
+		if (this._isSocketClosed) {
+			return;
+		}
+
+		const builder = new Builder(0);
+		builder.clear();
+
+		const textOffset = builder.createString(text);
+		const msgOffset = ChatSend.createChatSend(builder, channel, BigInt(targetPlayerId ?? '0'), textOffset);
+
+		const offset = GameRequestEvent.createGameRequestEvent(builder, RequestMessages.ChatSend, msgOffset);
+		builder.finish(offset);
+
+		const bytes = builder.asUint8Array();
+		this._socket.send(bytes);
+	}
+}
+
+function toScoreUpdate(score: PlayerScore): ScoreUpdate {
+	return {
+		playerId: BigInt(score.playerId()).toString(),
+		team: score.team(),
+		kills: score.kills(),
+		deaths: score.deaths(),
+		points: score.points(),
+	};
+}
+
+function toLobbyMemberUpdate(member: LobbyMember): LobbyMemberUpdate {
+	return {
+		playerId: BigInt(member.playerId()).toString(),
+		name: member.name() ?? '',
+		skin: member.skin() ?? '',
+		team: member.hasTeam() ? member.team() : -1,
+		ready: member.ready(),
+	};
 }
@@ -0,0 +1,377 @@
+//! Simulated players against a running server.
+//!
+//! `cargo run --release --bin loadtest -- --clients 2000 --duration 30 --server-pid $(pgrep demo-game-server)`
+
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+use serde::Serialize;
+use tokio::time::{interval, sleep, MissedTickBehavior};
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::peer::{ClientControls, ClientPosition};
+
+#[derive(Debug)]
+struct Options {
+    url: String,
+    clients: usize,
+    /// connections are spread evenly over this period
+    ramp_up: Duration,
+    /// how long each client stays connected
+    duration: Duration,
+    move_hz: f64,
+    fire_hz: f64,
+    /// pid of the server process, used to sample its CPU time from /proc
+    server_pid: Option<u32>,
+    json: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            url: "ws://127.0.0.1:8090/ws".to_string(),
+            clients: 100,
+            ramp_up: Duration::from_secs(5),
+            duration: Duration::from_secs(30),
+            move_hz: 20.0,
+            fire_hz: 2.0,
+            server_pid: None,
+            json: false,
+        }
+    }
+}
+
+const USAGE: &str = "usage: loadtest [--url URL] [--clients N] [--ramp-up SECS] [--duration SECS] \
+[--move-hz HZ] [--fire-hz HZ] [--server-pid PID] [--json]";
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--json" {
+                options.json = true;
+                continue;
+            }
+            let value = args.next().ok_or_else(|| format!("missing value for {}", arg))?;
+            match arg.as_str() {
+                "--url" => options.url = value.clone(),
+                "--clients" => options.clients = parse_value(&arg, &value)?,
+                "--ramp-up" => options.ramp_up = Duration::from_secs_f64(parse_value(&arg, &value)?),
+                "--duration" => options.duration = Duration::from_secs_f64(parse_value(&arg, &value)?),
+                "--move-hz" => options.move_hz = parse_value(&arg, &value)?,
+                "--fire-hz" => options.fire_hz = parse_value(&arg, &value)?,
+                "--server-pid" => options.server_pid = Some(parse_value(&arg, &value)?),
+                _ => return Err(format!("unknown argument {}", arg)),
+            }
+        }
+        if options.move_hz <= 0.0 || options.fire_hz <= 0.0 {
+            return Err("rates must be positive".to_string());
+        }
+        Ok(options)
+    }
+}
+
+fn parse_value<T: FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid value for {}: {}", arg, value))
+}
+
+/// Scripted movement, the position is a function of the time since connecting
+#[derive(Debug, Clone, Copy)]
+enum Pattern {
+    Circle,
+    Zigzag,
+    /// stands on its spawn and only fires
+    Idle,
+}
+
+impl Pattern {
+    fn of(client: usize) -> Self {
+        match client % 3 {
+            0 => Pattern::Circle,
+            1 => Pattern::Zigzag,
+            _ => Pattern::Idle,
+        }
+    }
+
+    /// speeds stay below the server's default `maxSpeed`, so moves aren't flagged
+    fn position(self, t: f32) -> (ClientPosition, ClientControls) {
+        let mut controls = ClientControls { up: false, down: false, left: false, right: false };
+        let position = match self {
+            Pattern::Circle => {
+                let (radius, angular_speed) = (80.0, 3.0);
+                let angle = t * angular_speed % TAU;
+                controls.right = angle.sin() > 0.0;
+                controls.left = angle.sin() < 0.0;
+                controls.down = angle.cos() > 0.0;
+                controls.up = angle.cos() < 0.0;
+                // starts on the spawn point at 0,0 and stays inside the default bounds
+                ClientPosition { x: radius * (1.0 - angle.cos()), y: radius * angle.sin() }
+            }
+            Pattern::Zigzag => {
+                let (extent, speed) = (150.0, 300.0);
+                let period = 4.0 * extent / speed;
+                // goes back and forth between x = 0 and 2 * extent
+                let phase = t / period % 1.0;
+                let forward = phase < 0.5;
+                controls.right = forward;
+                controls.left = !forward;
+                let x = if forward { 4.0 * extent * phase } else { 4.0 * extent * (1.0 - phase) };
+                ClientPosition { x, y: 0.0 }
+            }
+            Pattern::Idle => ClientPosition { x: 0.0, y: 0.0 },
+        };
+        (position, controls)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ClientReport {
+    connected: bool,
+    connect_time: Option<Duration>,
+    /// the server closed the connection before the run ended
+    dropped: bool,
+    sent: u64,
+    received: u64,
+    latencies: Vec<Duration>,
+}
+
+/// Connects one client and plays its pattern until `deadline`.
+///
+/// Latency is the round trip of a direct chat message: the server echoes direct messages to the sender, so a
+/// probe addressed to any known player comes back through the `GameServer` actor.
+async fn run_client(url: String, client: usize, deadline: Instant, options: &Options) -> ClientReport {
+    let mut report = ClientReport::default();
+    let connect_started = Instant::now();
+    let mut game_client = match GameClient::connect(&url).await {
+        Ok(game_client) => game_client,
+        Err(err) => {
+            eprintln!("client {} failed to connect: {}", client, err);
+            return report;
+        }
+    };
+    report.connected = true;
+    report.connect_time = Some(connect_started.elapsed());
+
+    let pattern = Pattern::of(client);
+    let started = Instant::now();
+    let mut move_tick = interval(Duration::from_secs_f64(1.0 / options.move_hz));
+    let mut fire_tick = interval(Duration::from_secs_f64(1.0 / options.fire_hz));
+    // stays within the default chat limit of one message per second
+    let mut probe_tick = interval(Duration::from_secs(1));
+    for tick in [&mut move_tick, &mut fire_tick, &mut probe_tick] {
+        tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    }
+
+    let mut probe_target = None;
+    let mut probes: HashMap<String, Instant> = HashMap::new();
+    let mut probe_seq = 0u64;
+    let end = sleep(deadline.saturating_duration_since(Instant::now()));
+    tokio::pin!(end);
+
+    loop {
+        let sent = tokio::select! {
+            _ = &mut end => break,
+            msg = game_client.recv() => {
+                match msg {
+                    Ok(Some(data)) => {
+                        report.received += 1;
+                        match data {
+                            PeerPlayerData::WorldUpdate { world_data, .. } => {
+                                probe_target = probe_target.or(world_data.first().map(|info| info.player_id));
+                            }
+                            PeerPlayerData::RemotePeerJoined { player_id, .. } => {
+                                probe_target = probe_target.or(Some(player_id));
+                            }
+                            PeerPlayerData::RemotePeerLeft { player_id } if probe_target == Some(player_id) => {
+                                probe_target = None;
+                            }
+                            PeerPlayerData::ChatMessage { text, .. } => {
+                                if let Some(sent_at) = probes.remove(&text) {
+                                    report.latencies.push(sent_at.elapsed());
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    Ok(None) | Err(_) => {
+                        report.dropped = true;
+                        break;
+                    }
+                }
+                Ok(())
+            }
+            _ = move_tick.tick(), if !matches!(pattern, Pattern::Idle) => {
+                let (position, controls) = pattern.position(started.elapsed().as_secs_f32());
+                report.sent += 1;
+                game_client.send_player_moved(controls, position).await
+            }
+            _ = fire_tick.tick() => {
+                let angle = (client as f32 + started.elapsed().as_secs_f32()) % TAU;
+                report.sent += 1;
+                game_client.send_weapon_fired(angle, 1.0).await
+            }
+            _ = probe_tick.tick(), if probe_target.is_some() => {
+                probe_seq += 1;
+                let text = format!("probe {} {}", client, probe_seq);
+                probes.insert(text.clone(), Instant::now());
+                report.sent += 1;
+                game_client.send_chat(ChatChannel::Direct, probe_target.unwrap_or_default(), &text).await
+            }
+        };
+        if sent.is_err() {
+            report.dropped = true;
+            break;
+        }
+    }
+
+    if !report.dropped {
+        let _ = game_client.close().await;
+    }
+    report
+}
+
+/// utime + stime of `pid` in clock ticks
+fn cpu_ticks(pid: u32) -> Option<u64> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // the command name can contain spaces, fields are counted from after its closing parenthesis
+    let fields = stat.rsplit_once(')')?.1.split_whitespace().collect::<Vec<&str>>();
+    let utime = fields.get(11)?.parse::<u64>().ok()?;
+    let stime = fields.get(12)?.parse::<u64>().ok()?;
+    Some(utime + stime)
+}
+
+// USER_HZ, 100 on every mainstream Linux build
+const CLOCK_TICKS_PER_SECOND: f64 = 100.0;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary {
+    clients: usize,
+    connected: usize,
+    failed: usize,
+    dropped: usize,
+    connect_p50_ms: f64,
+    connect_p99_ms: f64,
+    messages_sent: u64,
+    messages_received: u64,
+    sent_per_second: f64,
+    received_per_second: f64,
+    latency_samples: usize,
+    latency_p50_ms: f64,
+    latency_p90_ms: f64,
+    latency_p99_ms: f64,
+    latency_max_ms: f64,
+    /// average over the run, 100 is one core
+    server_cpu_percent: Option<f64>,
+    elapsed_seconds: f64,
+}
+
+/// nearest-rank percentile of a sorted slice, in milliseconds
+fn percentile(sorted: &[Duration], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1].as_secs_f64() * 1000.0
+}
+
+fn summarize(options: &Options, reports: Vec<ClientReport>, elapsed: Duration, server_cpu_percent: Option<f64>) -> Summary {
+    let mut connect_times = reports.iter().filter_map(|report| report.connect_time).collect::<Vec<Duration>>();
+    connect_times.sort();
+    let mut latencies = reports.iter().flat_map(|report| report.latencies.iter().copied()).collect::<Vec<Duration>>();
+    latencies.sort();
+
+    let connected = reports.iter().filter(|report| report.connected).count();
+    let messages_sent = reports.iter().map(|report| report.sent).sum::<u64>();
+    let messages_received = reports.iter().map(|report| report.received).sum::<u64>();
+    let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+
+    Summary {
+        clients: options.clients,
+        connected,
+        failed: options.clients - connected,
+        dropped: reports.iter().filter(|report| report.dropped).count(),
+        connect_p50_ms: percentile(&connect_times, 50.0),
+        connect_p99_ms: percentile(&connect_times, 99.0),
+        messages_sent,
+        messages_received,
+        sent_per_second: messages_sent as f64 / seconds,
+        received_per_second: messages_received as f64 / seconds,
+        latency_samples: latencies.len(),
+        latency_p50_ms: percentile(&latencies, 50.0),
+        latency_p90_ms: percentile(&latencies, 90.0),
+        latency_p99_ms: percentile(&latencies, 99.0),
+        latency_max_ms: percentile(&latencies, 100.0),
+        server_cpu_percent,
+        elapsed_seconds: seconds,
+    }
+}
+
+fn print_summary(summary: &Summary) {
+    println!("connections   {} ok, {} failed, {} dropped by the server", summary.connected, summary.failed, summary.dropped);
+    println!("connect time  p50 {:.1} ms, p99 {:.1} ms", summary.connect_p50_ms, summary.connect_p99_ms);
+    println!(
+        "throughput    {} sent ({:.0}/s), {} received ({:.0}/s)",
+        summary.messages_sent, summary.sent_per_second, summary.messages_received, summary.received_per_second
+    );
+    println!(
+        "latency       {} samples, p50 {:.1} ms, p90 {:.1} ms, p99 {:.1} ms, max {:.1} ms",
+        summary.latency_samples, summary.latency_p50_ms, summary.latency_p90_ms, summary.latency_p99_ms, summary.latency_max_ms
+    );
+    match summary.server_cpu_percent {
+        Some(cpu) => println!("server cpu    {:.1}%", cpu),
+        None => println!("server cpu    not measured, pass --server-pid"),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    let options: &'static Options = Box::leak(Box::new(options));
+
+    let started = Instant::now();
+    let cpu_before = options.server_pid.and_then(cpu_ticks);
+    let deadline = started + options.ramp_up + options.duration;
+
+    let handles = (0..options.clients)
+        .map(|client| {
+            let delay = options.ramp_up.mul_f64(client as f64 / options.clients as f64);
+            let url = options.url.clone();
+            tokio::spawn(async move {
+                sleep(delay).await;
+                run_client(url, client, deadline, options).await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut reports = Vec::with_capacity(handles.len());
+    for handle in handles {
+        reports.push(handle.await.unwrap_or_default());
+    }
+
+    let elapsed = started.elapsed();
+    let server_cpu_percent = match (cpu_before, options.server_pid.and_then(cpu_ticks)) {
+        (Some(before), Some(after)) => Some((after - before) as f64 / CLOCK_TICKS_PER_SECOND / elapsed.as_secs_f64() * 100.0),
+        _ => None,
+    };
+
+    let summary = summarize(options, reports, elapsed, server_cpu_percent);
+    if options.json {
+        println!("{}", serde_json::to_string(&summary).expect("summary serializes"));
+    } else {
+        print_summary(&summary);
+    }
+    if summary.failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,66 @@
+use std::{env, fs, io};
+use serde::Deserialize;
+use crate::game_server::bot::BotConfig;
+use crate::game_server::chat::ChatConfig;
+use crate::game_server::compression::CompressionConfig;
+use crate::game_server::lobby::LobbyConfig;
+use crate::game_server::outbox::OutboundConfig;
+use crate::game_server::party::PartyConfig;
+use crate::game_server::profile::ProfileConfig;
+use crate::game_server::rating::RatingConfig;
+use crate::game_server::match_state::MatchConfig;
+use crate::game_server::movement_validator::MovementConfig;
+use crate::game_server::rate_limiter::RateLimitConfig;
+use crate::game_server::replay::ReplayConfig;
+use crate::game_server::room_registry::{MatchmakingConfig, PrivateRoomConfig};
+use crate::game_server::simulation::SimulationConfig;
+use crate::game_server::spawn::SpawnStrategy;
+use crate::game_server::team::TeamConfig;
+use crate::game_server::udp_server::UdpConfig;
+
+/// Server settings, read from the JSON file named by `GAME_SERVER_CONFIG`.
+/// Missing fields fall back to their defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServerConfig {
+    pub rate_limit: RateLimitConfig,
+    pub movement: MovementConfig,
+    /// path of the map JSON file, an empty map is used when not set
+    pub map_file: Option<String>,
+    pub spawn_strategy: SpawnStrategy,
+    pub teams: TeamConfig,
+    #[serde(rename = "match")]
+    pub match_config: MatchConfig,
+    pub chat: ChatConfig,
+    pub spectator: SpectatorConfig,
+    pub replay: ReplayConfig,
+    pub simulation: SimulationConfig,
+    pub bots: BotConfig,
+    pub lobby: LobbyConfig,
+    pub party: PartyConfig,
+    pub profiles: ProfileConfig,
+    pub ratings: RatingConfig,
+    pub private_rooms: PrivateRoomConfig,
+    pub matchmaking: MatchmakingConfig,
+    pub udp: UdpConfig,
+    pub outbound: OutboundConfig,
+    pub compression: CompressionConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpectatorConfig {
+    /// how long spectators lag behind the live game, 0 streams without delay
+    pub broadcast_delay_ms: u64,
+}
+
+impl ServerConfig {
+    pub fn load() -> io::Result<Self> {
+        let Ok(path) = env::var("GAME_SERVER_CONFIG") else {
+            return Ok(Self::default());
+        };
+
+        let contents = fs::read_to_string(&path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fmt, io};
+use std::time::Duration;
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use crate::game_server::chat::ChatChannel;
+use crate::game_server::compression::decode_frame;
+use crate::game_server::flatbuffers_utils::{create_chat_send_bytes, create_lobby_ready_bytes, create_lobby_setup_bytes, create_party_invite_bytes, create_party_leave_bytes, create_party_respond_bytes, create_player_moved_bytes, create_weapon_fired_bytes, read_player_data};
+use crate::game_server::message_types::PeerPlayerData;
+use crate::game_server::peer::{ClientControls, ClientPosition};
+
+#[derive(Debug)]
+pub enum ClientError {
+    WebSocket(tungstenite::Error),
+    /// a socket error of the UDP transport
+    Io(io::Error),
+    /// the server refused the UDP connection, with its reason
+    Rejected(String),
+    /// the server sent something that isn't a `GameReponseEvent`
+    InvalidMessage(flatbuffers::InvalidFlatbuffer),
+    Timeout,
+    /// the connection closed before the awaited message arrived
+    Closed,
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::WebSocket(err) => write!(f, "websocket error: {}", err),
+            ClientError::Io(err) => write!(f, "socket error: {}", err),
+            ClientError::Rejected(reason) => write!(f, "connection refused: {}", reason),
+            ClientError::InvalidMessage(err) => write!(f, "invalid message: {}", err),
+            ClientError::Timeout => write!(f, "timed out"),
+            ClientError::Closed => write!(f, "connection closed"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        ClientError::Io(err)
+    }
+}
+
+impl From<tungstenite::Error> for ClientError {
+    fn from(err: tungstenite::Error) -> Self {
+        ClientError::WebSocket(err)
+    }
+}
+
+/// Headless client speaking the game protocol, for tests and tooling
+pub struct GameClient {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    close_reason: Option<(CloseCode, String)>,
+    // connected with `compress=true`, frames carry a flag byte
+    compressed: bool,
+}
+
+impl GameClient {
+    /// `url` is the full websocket url, e.g. `ws://127.0.0.1:8090/ws?spectate=true`, frames are decompressed when it
+    /// asks for `compress=true`
+    pub async fn connect(url: &str) -> Result<Self, ClientError> {
+        let compressed = url
+            .split_once('?')
+            .is_some_and(|(_, query)| query.split('&').any(|param| param == "compress=true"));
+        let (stream, _) = connect_async(url).await?;
+        Ok(Self {
+            stream,
+            close_reason: None,
+            compressed,
+        })
+    }
+
+    pub async fn send_player_moved(&mut self, player_controls: ClientControls, player_position: ClientPosition) -> Result<(), ClientError> {
+        self.send(create_player_moved_bytes(player_controls, player_position)).await
+    }
+
+    pub async fn send_weapon_fired(&mut self, angle: f32, power: f32) -> Result<(), ClientError> {
+        self.send(create_weapon_fired_bytes(angle, power)).await
+    }
+
+    /// `target_player_id` is only used for `ChatChannel::Direct`
+    pub async fn send_chat(&mut self, channel: ChatChannel, target_player_id: usize, text: &str) -> Result<(), ClientError> {
+        self.send(create_chat_send_bytes(channel, target_player_id, text)).await
+    }
+
+    /// `team` of `None` leaves the team up to the server
+    pub async fn send_lobby_setup(&mut self, name: &str, skin: &str, team: Option<u8>) -> Result<(), ClientError> {
+        self.send(create_lobby_setup_bytes(name, skin, team)).await
+    }
+
+    pub async fn send_lobby_ready(&mut self, ready: bool) -> Result<(), ClientError> {
+        self.send(create_lobby_ready_bytes(ready)).await
+    }
+
+    pub async fn send_party_invite(&mut self, player_id: usize) -> Result<(), ClientError> {
+        self.send(create_party_invite_bytes(player_id)).await
+    }
+
+    pub async fn send_party_respond(&mut self, leader_id: usize, accept: bool) -> Result<(), ClientError> {
+        self.send(create_party_respond_bytes(leader_id, accept)).await
+    }
+
+    pub async fn send_party_leave(&mut self) -> Result<(), ClientError> {
+        self.send(create_party_leave_bytes()).await
+    }
+
+    pub async fn send_raw(&mut self, bytes: Vec<u8>) -> Result<(), ClientError> {
+        self.send(bytes).await
+    }
+
+    async fn send(&mut self, bytes: Vec<u8>) -> Result<(), ClientError> {
+        self.stream.send(Message::Binary(bytes)).await?;
+        Ok(())
+    }
+
+    /// next message from the server, `None` once the connection is closed
+    pub async fn recv(&mut self) -> Result<Option<PeerPlayerData>, ClientError> {
+        while let Some(msg) = self.stream.next().await {
+            match msg {
+                Ok(Message::Binary(bytes)) => {
+                    let bytes = if self.compressed { decode_frame(&bytes)? } else { bytes };
+                    if let Some(data) = read_player_data(&bytes).map_err(ClientError::InvalidMessage)? {
+                        return Ok(Some(data));
+                    }
+                }
+                Ok(Message::Close(frame)) => {
+                    self.close_reason = frame.map(|frame| (frame.code, frame.reason.into_owned()));
+                    return Ok(None);
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::ConnectionClosed) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(None)
+    }
+
+    /// skips messages until one matches `predicate`
+    pub async fn wait_for<F>(&mut self, timeout: Duration, mut predicate: F) -> Result<PeerPlayerData, ClientError>
+    where
+        F: FnMut(&PeerPlayerData) -> bool,
+    {
+        let wait = async {
+            loop {
+                match self.recv().await? {
+                    Some(data) if predicate(&data) => return Ok(data),
+                    Some(_) => {}
+                    None => return Err(ClientError::Closed),
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.unwrap_or(Err(ClientError::Timeout))
+    }
+
+    /// close code and reason sent by the server, once `recv` has seen the connection close
+    pub fn close_reason(&self) -> Option<&(CloseCode, String)> {
+        self.close_reason.as_ref()
+    }
+
+    pub async fn close(mut self) -> Result<(), ClientError> {
+        self.stream.close(None).await?;
+        // drain until the server acknowledges the close
+        while let Some(Ok(_)) = self.stream.next().await {}
+        Ok(())
+    }
+}
@@ -18,16 +18,296 @@ pub mod gameplay_fbdata {
   extern crate flatbuffers;
   use self::flatbuffers::{EndianScalar, Follow};
 
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CHAT_CHANNEL: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CHAT_CHANNEL: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CHAT_CHANNEL: [ChatChannel; 3] = [
+  ChatChannel::Room,
+  ChatChannel::Direct,
+  ChatChannel::Team,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ChatChannel(pub i8);
+#[allow(non_upper_case_globals)]
+impl ChatChannel {
+  pub const Room: Self = Self(0);
+  pub const Direct: Self = Self(1);
+  pub const Team: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Room,
+    Self::Direct,
+    Self::Team,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Room => Some("Room"),
+      Self::Direct => Some("Direct"),
+      Self::Team => Some("Team"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ChatChannel {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ChatChannel {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ChatChannel {
+    type Output = ChatChannel;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ChatChannel {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ChatChannel {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ChatChannel {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MATCH_PHASE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MATCH_PHASE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MATCH_PHASE: [MatchPhase; 3] = [
+  MatchPhase::Warmup,
+  MatchPhase::Live,
+  MatchPhase::Ended,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct MatchPhase(pub i8);
+#[allow(non_upper_case_globals)]
+impl MatchPhase {
+  pub const Warmup: Self = Self(0);
+  pub const Live: Self = Self(1);
+  pub const Ended: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Warmup,
+    Self::Live,
+    Self::Ended,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Warmup => Some("Warmup"),
+      Self::Live => Some("Live"),
+      Self::Ended => Some("Ended"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for MatchPhase {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for MatchPhase {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for MatchPhase {
+    type Output = MatchPhase;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for MatchPhase {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for MatchPhase {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MatchPhase {}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_FLAG_EVENT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_FLAG_EVENT: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_FLAG_EVENT: [FlagEvent; 4] = [
+  FlagEvent::Taken,
+  FlagEvent::Dropped,
+  FlagEvent::Returned,
+  FlagEvent::Captured,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct FlagEvent(pub i8);
+#[allow(non_upper_case_globals)]
+impl FlagEvent {
+  pub const Taken: Self = Self(0);
+  pub const Dropped: Self = Self(1);
+  pub const Returned: Self = Self(2);
+  pub const Captured: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Taken,
+    Self::Dropped,
+    Self::Returned,
+    Self::Captured,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Taken => Some("Taken"),
+      Self::Dropped => Some("Dropped"),
+      Self::Returned => Some("Returned"),
+      Self::Captured => Some("Captured"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for FlagEvent {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for FlagEvent {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for FlagEvent {
+    type Output = FlagEvent;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for FlagEvent {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for FlagEvent {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for FlagEvent {}
+
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_REQUEST_MESSAGES: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_REQUEST_MESSAGES: u8 = 2;
+pub const ENUM_MAX_REQUEST_MESSAGES: u8 = 8;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_REQUEST_MESSAGES: [RequestMessages; 3] = [
+pub const ENUM_VALUES_REQUEST_MESSAGES: [RequestMessages; 9] = [
   RequestMessages::NONE,
   RequestMessages::PlayerMoved,
   RequestMessages::WeaponFired,
+  RequestMessages::ChatSend,
+  RequestMessages::LobbySetup,
+  RequestMessages::LobbyReady,
+  RequestMessages::PartyInvite,
+  RequestMessages::PartyRespond,
+  RequestMessages::PartyLeave,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -38,13 +318,25 @@ impl RequestMessages {
   pub const NONE: Self = Self(0);
   pub const PlayerMoved: Self = Self(1);
   pub const WeaponFired: Self = Self(2);
+  pub const ChatSend: Self = Self(3);
+  pub const LobbySetup: Self = Self(4);
+  pub const LobbyReady: Self = Self(5);
+  pub const PartyInvite: Self = Self(6);
+  pub const PartyRespond: Self = Self(7);
+  pub const PartyLeave: Self = Self(8);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_MAX: u8 = 8;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::PlayerMoved,
     Self::WeaponFired,
+    Self::ChatSend,
+    Self::LobbySetup,
+    Self::LobbyReady,
+    Self::PartyInvite,
+    Self::PartyRespond,
+    Self::PartyLeave,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -52,6 +344,12 @@ impl RequestMessages {
       Self::NONE => Some("NONE"),
       Self::PlayerMoved => Some("PlayerMoved"),
       Self::WeaponFired => Some("WeaponFired"),
+      Self::ChatSend => Some("ChatSend"),
+      Self::LobbySetup => Some("LobbySetup"),
+      Self::LobbyReady => Some("LobbyReady"),
+      Self::PartyInvite => Some("PartyInvite"),
+      Self::PartyRespond => Some("PartyRespond"),
+      Self::PartyLeave => Some("PartyLeave"),
       _ => None,
     }
   }
@@ -112,15 +410,24 @@ pub struct RequestMessagesUnionTableOffset {}
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_RESPONSE_MESSAGE: u8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_RESPONSE_MESSAGE: u8 = 4;
+pub const ENUM_MAX_RESPONSE_MESSAGE: u8 = 13;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_RESPONSE_MESSAGE: [ResponseMessage; 5] = [
+pub const ENUM_VALUES_RESPONSE_MESSAGE: [ResponseMessage; 14] = [
   ResponseMessage::NONE,
   ResponseMessage::RemotePeerJoined,
   ResponseMessage::RemotePeerLeft,
   ResponseMessage::RemotePeerPositionUpdate,
   ResponseMessage::GameWorldUpdate,
+  ResponseMessage::ChatMessage,
+  ResponseMessage::MatchPhaseChanged,
+  ResponseMessage::PlayerKilled,
+  ResponseMessage::ScoreboardUpdate,
+  ResponseMessage::MatchSummary,
+  ResponseMessage::FlagUpdate,
+  ResponseMessage::LobbyUpdate,
+  ResponseMessage::PartyInvitation,
+  ResponseMessage::PartyUpdate,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -133,15 +440,33 @@ impl ResponseMessage {
   pub const RemotePeerLeft: Self = Self(2);
   pub const RemotePeerPositionUpdate: Self = Self(3);
   pub const GameWorldUpdate: Self = Self(4);
+  pub const ChatMessage: Self = Self(5);
+  pub const MatchPhaseChanged: Self = Self(6);
+  pub const PlayerKilled: Self = Self(7);
+  pub const ScoreboardUpdate: Self = Self(8);
+  pub const MatchSummary: Self = Self(9);
+  pub const FlagUpdate: Self = Self(10);
+  pub const LobbyUpdate: Self = Self(11);
+  pub const PartyInvitation: Self = Self(12);
+  pub const PartyUpdate: Self = Self(13);
 
   pub const ENUM_MIN: u8 = 0;
-  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_MAX: u8 = 13;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::NONE,
     Self::RemotePeerJoined,
     Self::RemotePeerLeft,
     Self::RemotePeerPositionUpdate,
     Self::GameWorldUpdate,
+    Self::ChatMessage,
+    Self::MatchPhaseChanged,
+    Self::PlayerKilled,
+    Self::ScoreboardUpdate,
+    Self::MatchSummary,
+    Self::FlagUpdate,
+    Self::LobbyUpdate,
+    Self::PartyInvitation,
+    Self::PartyUpdate,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -151,6 +476,15 @@ impl ResponseMessage {
       Self::RemotePeerLeft => Some("RemotePeerLeft"),
       Self::RemotePeerPositionUpdate => Some("RemotePeerPositionUpdate"),
       Self::GameWorldUpdate => Some("GameWorldUpdate"),
+      Self::ChatMessage => Some("ChatMessage"),
+      Self::MatchPhaseChanged => Some("MatchPhaseChanged"),
+      Self::PlayerKilled => Some("PlayerKilled"),
+      Self::ScoreboardUpdate => Some("ScoreboardUpdate"),
+      Self::MatchSummary => Some("MatchSummary"),
+      Self::FlagUpdate => Some("FlagUpdate"),
+      Self::LobbyUpdate => Some("LobbyUpdate"),
+      Self::PartyInvitation => Some("PartyInvitation"),
+      Self::PartyUpdate => Some("PartyUpdate"),
       _ => None,
     }
   }
@@ -523,10 +857,10 @@ impl<'a> Vec2 {
 // struct PlayerData, aligned to 8
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct PlayerData(pub [u8; 16]);
+pub struct PlayerData(pub [u8; 24]);
 impl Default for PlayerData { 
   fn default() -> Self { 
-    Self([0; 16])
+    Self([0; 24])
   }
 }
 impl core::fmt::Debug for PlayerData {
@@ -534,6 +868,7 @@ impl core::fmt::Debug for PlayerData {
     f.debug_struct("PlayerData")
       .field("player_id", &self.player_id())
       .field("player_position", &self.player_position())
+      .field("team", &self.team())
       .finish()
   }
 }
@@ -577,10 +912,12 @@ impl<'a> PlayerData {
   pub fn new(
     player_id: u64,
     player_position: &Vec2,
+    team: u8,
   ) -> Self {
-    let mut s = Self([0; 16]);
+    let mut s = Self([0; 24]);
     s.set_player_id(player_id);
     s.set_player_position(player_position);
+    s.set_team(team);
     s
   }
 
@@ -625,77 +962,326 @@ impl<'a> PlayerData {
     self.0[8..8 + 8].copy_from_slice(&x.0)
   }
 
-}
+  pub fn team(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
 
-pub enum PlayerMovedOffset {}
-#[derive(Copy, Clone, PartialEq)]
+  pub fn set_team(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
 
-pub struct PlayerMoved<'a> {
-  pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PlayerMoved<'a> {
-  type Inner = PlayerMoved<'a>;
-  #[inline]
-  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-    Self { _tab: flatbuffers::Table::new(buf, loc) }
+// struct PlayerScore, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PlayerScore(pub [u8; 24]);
+impl Default for PlayerScore { 
+  fn default() -> Self { 
+    Self([0; 24])
   }
 }
-
-impl<'a> PlayerMoved<'a> {
-  pub const VT_PLAYER_CONTROLS: flatbuffers::VOffsetT = 4;
-  pub const VT_PLAYER_POSITION: flatbuffers::VOffsetT = 6;
-
-  #[inline]
-  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    PlayerMoved { _tab: table }
-  }
-  #[allow(unused_mut)]
-  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args PlayerMovedArgs<'args>
-  ) -> flatbuffers::WIPOffset<PlayerMoved<'bldr>> {
-    let mut builder = PlayerMovedBuilder::new(_fbb);
-    if let Some(x) = args.player_position { builder.add_player_position(x); }
-    if let Some(x) = args.player_controls { builder.add_player_controls(x); }
-    builder.finish()
+impl core::fmt::Debug for PlayerScore {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("PlayerScore")
+      .field("player_id", &self.player_id())
+      .field("kills", &self.kills())
+      .field("deaths", &self.deaths())
+      .field("points", &self.points())
+      .field("team", &self.team())
+      .finish()
   }
+}
 
-
+impl flatbuffers::SimpleToVerifyInSlice for PlayerScore {}
+impl<'a> flatbuffers::Follow<'a> for PlayerScore {
+  type Inner = &'a PlayerScore;
   #[inline]
-  pub fn player_controls(&self) -> Option<&'a PlayerControl> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<PlayerControl>(PlayerMoved::VT_PLAYER_CONTROLS, None)}
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a PlayerScore>::follow(buf, loc)
   }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a PlayerScore {
+  type Inner = &'a PlayerScore;
   #[inline]
-  pub fn player_position(&self) -> Option<&'a Vec2> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<Vec2>(PlayerMoved::VT_PLAYER_POSITION, None)}
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<PlayerScore>(buf, loc)
   }
 }
+impl<'b> flatbuffers::Push for PlayerScore {
+    type Output = PlayerScore;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const PlayerScore as *const u8, Self::size());
+        dst.copy_from_slice(src);
+    }
+}
 
-impl flatbuffers::Verifiable for PlayerMoved<'_> {
+impl<'a> flatbuffers::Verifiable for PlayerScore {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
-    v.visit_table(pos)?
-     .visit_field::<PlayerControl>("player_controls", Self::VT_PLAYER_CONTROLS, false)?
-     .visit_field::<Vec2>("player_position", Self::VT_PLAYER_POSITION, false)?
-     .finish();
-    Ok(())
+    v.in_buffer::<Self>(pos)
   }
 }
-pub struct PlayerMovedArgs<'a> {
-    pub player_controls: Option<&'a PlayerControl>,
-    pub player_position: Option<&'a Vec2>,
-}
-impl<'a> Default for PlayerMovedArgs<'a> {
+
+impl<'a> PlayerScore {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    player_id: u64,
+    kills: u32,
+    deaths: u32,
+    points: i32,
+    team: u8,
+  ) -> Self {
+    let mut s = Self([0; 24]);
+    s.set_player_id(player_id);
+    s.set_kills(kills);
+    s.set_deaths(deaths);
+    s.set_points(points);
+    s.set_team(team);
+    s
+  }
+
+  pub fn player_id(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_player_id(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn kills(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_kills(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn deaths(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[12..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_deaths(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[12..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn points(&self) -> i32 {
+    let mut mem = core::mem::MaybeUninit::<<i32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_points(&mut self, x: i32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<i32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn team(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[20..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_team(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[20..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum PlayerMovedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerMoved<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerMoved<'a> {
+  type Inner = PlayerMoved<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayerMoved<'a> {
+  pub const VT_PLAYER_CONTROLS: flatbuffers::VOffsetT = 4;
+  pub const VT_PLAYER_POSITION: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerMoved { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayerMovedArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayerMoved<'bldr>> {
+    let mut builder = PlayerMovedBuilder::new(_fbb);
+    if let Some(x) = args.player_position { builder.add_player_position(x); }
+    if let Some(x) = args.player_controls { builder.add_player_controls(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_controls(&self) -> Option<&'a PlayerControl> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerControl>(PlayerMoved::VT_PLAYER_CONTROLS, None)}
+  }
+  #[inline]
+  pub fn player_position(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(PlayerMoved::VT_PLAYER_POSITION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerMoved<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<PlayerControl>("player_controls", Self::VT_PLAYER_CONTROLS, false)?
+     .visit_field::<Vec2>("player_position", Self::VT_PLAYER_POSITION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerMovedArgs<'a> {
+    pub player_controls: Option<&'a PlayerControl>,
+    pub player_position: Option<&'a Vec2>,
+}
+impl<'a> Default for PlayerMovedArgs<'a> {
   #[inline]
   fn default() -> Self {
     PlayerMovedArgs {
@@ -705,450 +1291,2552 @@ impl<'a> Default for PlayerMovedArgs<'a> {
   }
 }
 
-pub struct PlayerMovedBuilder<'a: 'b, 'b> {
+pub struct PlayerMovedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayerMovedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_controls(&mut self, player_controls: &PlayerControl) {
+    self.fbb_.push_slot_always::<&PlayerControl>(PlayerMoved::VT_PLAYER_CONTROLS, player_controls);
+  }
+  #[inline]
+  pub fn add_player_position(&mut self, player_position: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(PlayerMoved::VT_PLAYER_POSITION, player_position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayerMovedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayerMovedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerMoved<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerMoved<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerMoved");
+      ds.field("player_controls", &self.player_controls());
+      ds.field("player_position", &self.player_position());
+      ds.finish()
+  }
+}
+pub enum WeaponFiredOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WeaponFired<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WeaponFired<'a> {
+  type Inner = WeaponFired<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> WeaponFired<'a> {
+  pub const VT_ANGLE: flatbuffers::VOffsetT = 4;
+  pub const VT_POWER: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    WeaponFired { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args WeaponFiredArgs
+  ) -> flatbuffers::WIPOffset<WeaponFired<'bldr>> {
+    let mut builder = WeaponFiredBuilder::new(_fbb);
+    builder.add_power(args.power);
+    builder.add_angle(args.angle);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn angle(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(WeaponFired::VT_ANGLE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn power(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(WeaponFired::VT_POWER, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for WeaponFired<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<f32>("angle", Self::VT_ANGLE, false)?
+     .visit_field::<f32>("power", Self::VT_POWER, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct WeaponFiredArgs {
+    pub angle: f32,
+    pub power: f32,
+}
+impl<'a> Default for WeaponFiredArgs {
+  #[inline]
+  fn default() -> Self {
+    WeaponFiredArgs {
+      angle: 0.0,
+      power: 0.0,
+    }
+  }
+}
+
+pub struct WeaponFiredBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> WeaponFiredBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_angle(&mut self, angle: f32) {
+    self.fbb_.push_slot::<f32>(WeaponFired::VT_ANGLE, angle, 0.0);
+  }
+  #[inline]
+  pub fn add_power(&mut self, power: f32) {
+    self.fbb_.push_slot::<f32>(WeaponFired::VT_POWER, power, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> WeaponFiredBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    WeaponFiredBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<WeaponFired<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for WeaponFired<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("WeaponFired");
+      ds.field("angle", &self.angle());
+      ds.field("power", &self.power());
+      ds.finish()
+  }
+}
+pub enum ChatSendOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ChatSend<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ChatSend<'a> {
+  type Inner = ChatSend<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ChatSend<'a> {
+  pub const VT_CHANNEL: flatbuffers::VOffsetT = 4;
+  pub const VT_TARGET_PLAYER_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ChatSend { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ChatSendArgs<'args>
+  ) -> flatbuffers::WIPOffset<ChatSend<'bldr>> {
+    let mut builder = ChatSendBuilder::new(_fbb);
+    builder.add_target_player_id(args.target_player_id);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_channel(args.channel);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn channel(&self) -> ChatChannel {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ChatChannel>(ChatSend::VT_CHANNEL, Some(ChatChannel::Room)).unwrap()}
+  }
+  #[inline]
+  pub fn target_player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ChatSend::VT_TARGET_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ChatSend::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ChatSend<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<ChatChannel>("channel", Self::VT_CHANNEL, false)?
+     .visit_field::<u64>("target_player_id", Self::VT_TARGET_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChatSendArgs<'a> {
+    pub channel: ChatChannel,
+    pub target_player_id: u64,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ChatSendArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChatSendArgs {
+      channel: ChatChannel::Room,
+      target_player_id: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct ChatSendBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ChatSendBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_channel(&mut self, channel: ChatChannel) {
+    self.fbb_.push_slot::<ChatChannel>(ChatSend::VT_CHANNEL, channel, ChatChannel::Room);
+  }
+  #[inline]
+  pub fn add_target_player_id(&mut self, target_player_id: u64) {
+    self.fbb_.push_slot::<u64>(ChatSend::VT_TARGET_PLAYER_ID, target_player_id, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ChatSend::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ChatSendBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ChatSendBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ChatSend<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ChatSend<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ChatSend");
+      ds.field("channel", &self.channel());
+      ds.field("target_player_id", &self.target_player_id());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum LobbySetupOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LobbySetup<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LobbySetup<'a> {
+  type Inner = LobbySetup<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LobbySetup<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_SKIN: flatbuffers::VOffsetT = 6;
+  pub const VT_TEAM: flatbuffers::VOffsetT = 8;
+  pub const VT_HAS_TEAM: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LobbySetup { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LobbySetupArgs<'args>
+  ) -> flatbuffers::WIPOffset<LobbySetup<'bldr>> {
+    let mut builder = LobbySetupBuilder::new(_fbb);
+    if let Some(x) = args.skin { builder.add_skin(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_has_team(args.has_team);
+    builder.add_team(args.team);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LobbySetup::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn skin(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LobbySetup::VT_SKIN, None)}
+  }
+  #[inline]
+  pub fn team(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(LobbySetup::VT_TEAM, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn has_team(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(LobbySetup::VT_HAS_TEAM, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for LobbySetup<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("skin", Self::VT_SKIN, false)?
+     .visit_field::<u8>("team", Self::VT_TEAM, false)?
+     .visit_field::<bool>("has_team", Self::VT_HAS_TEAM, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LobbySetupArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub skin: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub team: u8,
+    pub has_team: bool,
+}
+impl<'a> Default for LobbySetupArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LobbySetupArgs {
+      name: None,
+      skin: None,
+      team: 0,
+      has_team: false,
+    }
+  }
+}
+
+pub struct LobbySetupBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LobbySetupBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LobbySetup::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_skin(&mut self, skin: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LobbySetup::VT_SKIN, skin);
+  }
+  #[inline]
+  pub fn add_team(&mut self, team: u8) {
+    self.fbb_.push_slot::<u8>(LobbySetup::VT_TEAM, team, 0);
+  }
+  #[inline]
+  pub fn add_has_team(&mut self, has_team: bool) {
+    self.fbb_.push_slot::<bool>(LobbySetup::VT_HAS_TEAM, has_team, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LobbySetupBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LobbySetupBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LobbySetup<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LobbySetup<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LobbySetup");
+      ds.field("name", &self.name());
+      ds.field("skin", &self.skin());
+      ds.field("team", &self.team());
+      ds.field("has_team", &self.has_team());
+      ds.finish()
+  }
+}
+pub enum LobbyReadyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LobbyReady<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LobbyReady<'a> {
+  type Inner = LobbyReady<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LobbyReady<'a> {
+  pub const VT_READY: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LobbyReady { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args LobbyReadyArgs
+  ) -> flatbuffers::WIPOffset<LobbyReady<'bldr>> {
+    let mut builder = LobbyReadyBuilder::new(_fbb);
+    builder.add_ready(args.ready);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn ready(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(LobbyReady::VT_READY, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for LobbyReady<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("ready", Self::VT_READY, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LobbyReadyArgs {
+    pub ready: bool,
+}
+impl<'a> Default for LobbyReadyArgs {
+  #[inline]
+  fn default() -> Self {
+    LobbyReadyArgs {
+      ready: false,
+    }
+  }
+}
+
+pub struct LobbyReadyBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> LobbyReadyBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_ready(&mut self, ready: bool) {
+    self.fbb_.push_slot::<bool>(LobbyReady::VT_READY, ready, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LobbyReadyBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    LobbyReadyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LobbyReady<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LobbyReady<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LobbyReady");
+      ds.field("ready", &self.ready());
+      ds.finish()
+  }
+}
+pub enum PartyInviteOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PartyInvite<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PartyInvite<'a> {
+  type Inner = PartyInvite<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PartyInvite<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PartyInvite { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PartyInviteArgs
+  ) -> flatbuffers::WIPOffset<PartyInvite<'bldr>> {
+    let mut builder = PartyInviteBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PartyInvite::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PartyInvite<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("player_id", Self::VT_PLAYER_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PartyInviteArgs {
+    pub player_id: u64,
+}
+impl<'a> Default for PartyInviteArgs {
+  #[inline]
+  fn default() -> Self {
+    PartyInviteArgs {
+      player_id: 0,
+    }
+  }
+}
+
+pub struct PartyInviteBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PartyInviteBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u64) {
+    self.fbb_.push_slot::<u64>(PartyInvite::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyInviteBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PartyInviteBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PartyInvite<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PartyInvite<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PartyInvite");
+      ds.field("player_id", &self.player_id());
+      ds.finish()
+  }
+}
+pub enum PartyRespondOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PartyRespond<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PartyRespond<'a> {
+  type Inner = PartyRespond<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PartyRespond<'a> {
+  pub const VT_LEADER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ACCEPT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PartyRespond { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PartyRespondArgs
+  ) -> flatbuffers::WIPOffset<PartyRespond<'bldr>> {
+    let mut builder = PartyRespondBuilder::new(_fbb);
+    builder.add_leader_id(args.leader_id);
+    builder.add_accept(args.accept);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn leader_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PartyRespond::VT_LEADER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn accept(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(PartyRespond::VT_ACCEPT, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PartyRespond<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("leader_id", Self::VT_LEADER_ID, false)?
+     .visit_field::<bool>("accept", Self::VT_ACCEPT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PartyRespondArgs {
+    pub leader_id: u64,
+    pub accept: bool,
+}
+impl<'a> Default for PartyRespondArgs {
+  #[inline]
+  fn default() -> Self {
+    PartyRespondArgs {
+      leader_id: 0,
+      accept: false,
+    }
+  }
+}
+
+pub struct PartyRespondBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PartyRespondBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_leader_id(&mut self, leader_id: u64) {
+    self.fbb_.push_slot::<u64>(PartyRespond::VT_LEADER_ID, leader_id, 0);
+  }
+  #[inline]
+  pub fn add_accept(&mut self, accept: bool) {
+    self.fbb_.push_slot::<bool>(PartyRespond::VT_ACCEPT, accept, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyRespondBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PartyRespondBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PartyRespond<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PartyRespond<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PartyRespond");
+      ds.field("leader_id", &self.leader_id());
+      ds.field("accept", &self.accept());
+      ds.finish()
+  }
+}
+pub enum PartyLeaveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PartyLeave<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PartyLeave<'a> {
+  type Inner = PartyLeave<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PartyLeave<'a> {
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PartyLeave { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    _args: &'args PartyLeaveArgs
+  ) -> flatbuffers::WIPOffset<PartyLeave<'bldr>> {
+    let mut builder = PartyLeaveBuilder::new(_fbb);
+    builder.finish()
+  }
+
+
+}
+
+impl flatbuffers::Verifiable for PartyLeave<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PartyLeaveArgs {
+}
+impl<'a> Default for PartyLeaveArgs {
+  #[inline]
+  fn default() -> Self {
+    PartyLeaveArgs {
+    }
+  }
+}
+
+pub struct PartyLeaveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PartyLeaveBuilder<'a, 'b> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyLeaveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PartyLeaveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PartyLeave<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PartyLeave<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PartyLeave");
+      ds.finish()
+  }
+}
+pub enum GameRequestEventOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GameRequestEvent<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GameRequestEvent<'a> {
+  type Inner = GameRequestEvent<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GameRequestEvent<'a> {
+  pub const VT_MSG_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_MSG: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GameRequestEvent { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GameRequestEventArgs
+  ) -> flatbuffers::WIPOffset<GameRequestEvent<'bldr>> {
+    let mut builder = GameRequestEventBuilder::new(_fbb);
+    if let Some(x) = args.msg { builder.add_msg(x); }
+    builder.add_msg_type(args.msg_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn msg_type(&self) -> RequestMessages {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<RequestMessages>(GameRequestEvent::VT_MSG_TYPE, Some(RequestMessages::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn msg(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(GameRequestEvent::VT_MSG, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_player_moved(&self) -> Option<PlayerMoved<'a>> {
+    if self.msg_type() == RequestMessages::PlayerMoved {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerMoved::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_weapon_fired(&self) -> Option<WeaponFired<'a>> {
+    if self.msg_type() == RequestMessages::WeaponFired {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { WeaponFired::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_chat_send(&self) -> Option<ChatSend<'a>> {
+    if self.msg_type() == RequestMessages::ChatSend {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ChatSend::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_lobby_setup(&self) -> Option<LobbySetup<'a>> {
+    if self.msg_type() == RequestMessages::LobbySetup {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { LobbySetup::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_lobby_ready(&self) -> Option<LobbyReady<'a>> {
+    if self.msg_type() == RequestMessages::LobbyReady {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { LobbyReady::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_party_invite(&self) -> Option<PartyInvite<'a>> {
+    if self.msg_type() == RequestMessages::PartyInvite {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PartyInvite::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_party_respond(&self) -> Option<PartyRespond<'a>> {
+    if self.msg_type() == RequestMessages::PartyRespond {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PartyRespond::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_party_leave(&self) -> Option<PartyLeave<'a>> {
+    if self.msg_type() == RequestMessages::PartyLeave {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PartyLeave::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+}
+
+impl flatbuffers::Verifiable for GameRequestEvent<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<RequestMessages, _>("msg_type", Self::VT_MSG_TYPE, "msg", Self::VT_MSG, false, |key, v, pos| {
+        match key {
+          RequestMessages::PlayerMoved => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerMoved>>("RequestMessages::PlayerMoved", pos),
+          RequestMessages::WeaponFired => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WeaponFired>>("RequestMessages::WeaponFired", pos),
+          RequestMessages::ChatSend => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ChatSend>>("RequestMessages::ChatSend", pos),
+          RequestMessages::LobbySetup => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LobbySetup>>("RequestMessages::LobbySetup", pos),
+          RequestMessages::LobbyReady => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LobbyReady>>("RequestMessages::LobbyReady", pos),
+          RequestMessages::PartyInvite => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PartyInvite>>("RequestMessages::PartyInvite", pos),
+          RequestMessages::PartyRespond => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PartyRespond>>("RequestMessages::PartyRespond", pos),
+          RequestMessages::PartyLeave => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PartyLeave>>("RequestMessages::PartyLeave", pos),
+          _ => Ok(()),
+        }
+     })?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GameRequestEventArgs {
+    pub msg_type: RequestMessages,
+    pub msg: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for GameRequestEventArgs {
+  #[inline]
+  fn default() -> Self {
+    GameRequestEventArgs {
+      msg_type: RequestMessages::NONE,
+      msg: None,
+    }
+  }
+}
+
+pub struct GameRequestEventBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GameRequestEventBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_msg_type(&mut self, msg_type: RequestMessages) {
+    self.fbb_.push_slot::<RequestMessages>(GameRequestEvent::VT_MSG_TYPE, msg_type, RequestMessages::NONE);
+  }
+  #[inline]
+  pub fn add_msg(&mut self, msg: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameRequestEvent::VT_MSG, msg);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GameRequestEventBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GameRequestEventBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GameRequestEvent<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GameRequestEvent<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GameRequestEvent");
+      ds.field("msg_type", &self.msg_type());
+      match self.msg_type() {
+        RequestMessages::PlayerMoved => {
+          if let Some(x) = self.msg_as_player_moved() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::WeaponFired => {
+          if let Some(x) = self.msg_as_weapon_fired() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::ChatSend => {
+          if let Some(x) = self.msg_as_chat_send() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::LobbySetup => {
+          if let Some(x) = self.msg_as_lobby_setup() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::LobbyReady => {
+          if let Some(x) = self.msg_as_lobby_ready() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::PartyInvite => {
+          if let Some(x) = self.msg_as_party_invite() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::PartyRespond => {
+          if let Some(x) = self.msg_as_party_respond() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        RequestMessages::PartyLeave => {
+          if let Some(x) = self.msg_as_party_leave() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("msg", &x)
+        },
+      };
+      ds.finish()
+  }
+}
+pub enum RemotePeerJoinedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RemotePeerJoined<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RemotePeerJoined<'a> {
+  type Inner = RemotePeerJoined<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RemotePeerJoined<'a> {
+  pub const VT_PLAYER_DATA: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RemotePeerJoined { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RemotePeerJoinedArgs<'args>
+  ) -> flatbuffers::WIPOffset<RemotePeerJoined<'bldr>> {
+    let mut builder = RemotePeerJoinedBuilder::new(_fbb);
+    if let Some(x) = args.player_data { builder.add_player_data(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_data(&self) -> Option<&'a PlayerData> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerData>(RemotePeerJoined::VT_PLAYER_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RemotePeerJoined<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<PlayerData>("player_data", Self::VT_PLAYER_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RemotePeerJoinedArgs<'a> {
+    pub player_data: Option<&'a PlayerData>,
+}
+impl<'a> Default for RemotePeerJoinedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RemotePeerJoinedArgs {
+      player_data: None,
+    }
+  }
+}
+
+pub struct RemotePeerJoinedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RemotePeerJoinedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_data(&mut self, player_data: &PlayerData) {
+    self.fbb_.push_slot_always::<&PlayerData>(RemotePeerJoined::VT_PLAYER_DATA, player_data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerJoinedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RemotePeerJoinedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerJoined<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RemotePeerJoined<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RemotePeerJoined");
+      ds.field("player_data", &self.player_data());
+      ds.finish()
+  }
+}
+pub enum RemotePeerLeftOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RemotePeerLeft<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RemotePeerLeft<'a> {
+  type Inner = RemotePeerLeft<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RemotePeerLeft<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RemotePeerLeft { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RemotePeerLeftArgs
+  ) -> flatbuffers::WIPOffset<RemotePeerLeft<'bldr>> {
+    let mut builder = RemotePeerLeftBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(RemotePeerLeft::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for RemotePeerLeft<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("player_id", Self::VT_PLAYER_ID, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RemotePeerLeftArgs {
+    pub player_id: u64,
+}
+impl<'a> Default for RemotePeerLeftArgs {
+  #[inline]
+  fn default() -> Self {
+    RemotePeerLeftArgs {
+      player_id: 0,
+    }
+  }
+}
+
+pub struct RemotePeerLeftBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RemotePeerLeftBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_id(&mut self, player_id: u64) {
+    self.fbb_.push_slot::<u64>(RemotePeerLeft::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerLeftBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RemotePeerLeftBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerLeft<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RemotePeerLeft<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RemotePeerLeft");
+      ds.field("player_id", &self.player_id());
+      ds.finish()
+  }
+}
+pub enum RemotePeerPositionUpdateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RemotePeerPositionUpdate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RemotePeerPositionUpdate<'a> {
+  type Inner = RemotePeerPositionUpdate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> RemotePeerPositionUpdate<'a> {
+  pub const VT_PLAYER_DATA: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    RemotePeerPositionUpdate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args RemotePeerPositionUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<RemotePeerPositionUpdate<'bldr>> {
+    let mut builder = RemotePeerPositionUpdateBuilder::new(_fbb);
+    if let Some(x) = args.player_data { builder.add_player_data(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_data(&self) -> Option<&'a PlayerData> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<PlayerData>(RemotePeerPositionUpdate::VT_PLAYER_DATA, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for RemotePeerPositionUpdate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<PlayerData>("player_data", Self::VT_PLAYER_DATA, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct RemotePeerPositionUpdateArgs<'a> {
+    pub player_data: Option<&'a PlayerData>,
+}
+impl<'a> Default for RemotePeerPositionUpdateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    RemotePeerPositionUpdateArgs {
+      player_data: None,
+    }
+  }
+}
+
+pub struct RemotePeerPositionUpdateBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RemotePeerPositionUpdateBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_data(&mut self, player_data: &PlayerData) {
+    self.fbb_.push_slot_always::<&PlayerData>(RemotePeerPositionUpdate::VT_PLAYER_DATA, player_data);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerPositionUpdateBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RemotePeerPositionUpdateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerPositionUpdate<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for RemotePeerPositionUpdate<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("RemotePeerPositionUpdate");
+      ds.field("player_data", &self.player_data());
+      ds.finish()
+  }
+}
+pub enum GameWorldUpdateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GameWorldUpdate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GameWorldUpdate<'a> {
+  type Inner = GameWorldUpdate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GameWorldUpdate<'a> {
+  pub const VT_PLAYER_DATA_LIST: flatbuffers::VOffsetT = 4;
+  pub const VT_MAP_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_GAME_MODE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GameWorldUpdate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args GameWorldUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<GameWorldUpdate<'bldr>> {
+    let mut builder = GameWorldUpdateBuilder::new(_fbb);
+    if let Some(x) = args.game_mode { builder.add_game_mode(x); }
+    if let Some(x) = args.map_id { builder.add_map_id(x); }
+    if let Some(x) = args.player_data_list { builder.add_player_data_list(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn player_data_list(&self) -> Option<flatbuffers::Vector<'a, PlayerData>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PlayerData>>>(GameWorldUpdate::VT_PLAYER_DATA_LIST, None)}
+  }
+  #[inline]
+  pub fn map_id(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(GameWorldUpdate::VT_MAP_ID, None)}
+  }
+  #[inline]
+  pub fn game_mode(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(GameWorldUpdate::VT_GAME_MODE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for GameWorldUpdate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PlayerData>>>("player_data_list", Self::VT_PLAYER_DATA_LIST, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("map_id", Self::VT_MAP_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("game_mode", Self::VT_GAME_MODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GameWorldUpdateArgs<'a> {
+    pub player_data_list: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PlayerData>>>,
+    pub map_id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub game_mode: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for GameWorldUpdateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    GameWorldUpdateArgs {
+      player_data_list: None,
+      map_id: None,
+      game_mode: None,
+    }
+  }
+}
+
+pub struct GameWorldUpdateBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GameWorldUpdateBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_player_data_list(&mut self, player_data_list: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PlayerData>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameWorldUpdate::VT_PLAYER_DATA_LIST, player_data_list);
+  }
+  #[inline]
+  pub fn add_map_id(&mut self, map_id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameWorldUpdate::VT_MAP_ID, map_id);
+  }
+  #[inline]
+  pub fn add_game_mode(&mut self, game_mode: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameWorldUpdate::VT_GAME_MODE, game_mode);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GameWorldUpdateBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GameWorldUpdateBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GameWorldUpdate<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GameWorldUpdate<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GameWorldUpdate");
+      ds.field("player_data_list", &self.player_data_list());
+      ds.field("map_id", &self.map_id());
+      ds.field("game_mode", &self.game_mode());
+      ds.finish()
+  }
+}
+pub enum ChatMessageOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ChatMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ChatMessage<'a> {
+  type Inner = ChatMessage<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ChatMessage<'a> {
+  pub const VT_CHANNEL: flatbuffers::VOffsetT = 4;
+  pub const VT_SENDER_PLAYER_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_TEXT: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ChatMessage { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ChatMessageArgs<'args>
+  ) -> flatbuffers::WIPOffset<ChatMessage<'bldr>> {
+    let mut builder = ChatMessageBuilder::new(_fbb);
+    builder.add_sender_player_id(args.sender_player_id);
+    if let Some(x) = args.text { builder.add_text(x); }
+    builder.add_channel(args.channel);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn channel(&self) -> ChatChannel {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ChatChannel>(ChatMessage::VT_CHANNEL, Some(ChatChannel::Room)).unwrap()}
+  }
+  #[inline]
+  pub fn sender_player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ChatMessage::VT_SENDER_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn text(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ChatMessage::VT_TEXT, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ChatMessage<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<ChatChannel>("channel", Self::VT_CHANNEL, false)?
+     .visit_field::<u64>("sender_player_id", Self::VT_SENDER_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("text", Self::VT_TEXT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ChatMessageArgs<'a> {
+    pub channel: ChatChannel,
+    pub sender_player_id: u64,
+    pub text: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ChatMessageArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ChatMessageArgs {
+      channel: ChatChannel::Room,
+      sender_player_id: 0,
+      text: None,
+    }
+  }
+}
+
+pub struct ChatMessageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ChatMessageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_channel(&mut self, channel: ChatChannel) {
+    self.fbb_.push_slot::<ChatChannel>(ChatMessage::VT_CHANNEL, channel, ChatChannel::Room);
+  }
+  #[inline]
+  pub fn add_sender_player_id(&mut self, sender_player_id: u64) {
+    self.fbb_.push_slot::<u64>(ChatMessage::VT_SENDER_PLAYER_ID, sender_player_id, 0);
+  }
+  #[inline]
+  pub fn add_text(&mut self, text: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ChatMessage::VT_TEXT, text);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ChatMessageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ChatMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ChatMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ChatMessage<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ChatMessage");
+      ds.field("channel", &self.channel());
+      ds.field("sender_player_id", &self.sender_player_id());
+      ds.field("text", &self.text());
+      ds.finish()
+  }
+}
+pub enum MatchPhaseChangedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MatchPhaseChanged<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MatchPhaseChanged<'a> {
+  type Inner = MatchPhaseChanged<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> MatchPhaseChanged<'a> {
+  pub const VT_PHASE: flatbuffers::VOffsetT = 4;
+  pub const VT_DURATION_MS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MatchPhaseChanged { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args MatchPhaseChangedArgs
+  ) -> flatbuffers::WIPOffset<MatchPhaseChanged<'bldr>> {
+    let mut builder = MatchPhaseChangedBuilder::new(_fbb);
+    builder.add_duration_ms(args.duration_ms);
+    builder.add_phase(args.phase);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn phase(&self) -> MatchPhase {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MatchPhase>(MatchPhaseChanged::VT_PHASE, Some(MatchPhase::Warmup)).unwrap()}
+  }
+  #[inline]
+  pub fn duration_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MatchPhaseChanged::VT_DURATION_MS, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MatchPhaseChanged<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<MatchPhase>("phase", Self::VT_PHASE, false)?
+     .visit_field::<u32>("duration_ms", Self::VT_DURATION_MS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MatchPhaseChangedArgs {
+    pub phase: MatchPhase,
+    pub duration_ms: u32,
+}
+impl<'a> Default for MatchPhaseChangedArgs {
+  #[inline]
+  fn default() -> Self {
+    MatchPhaseChangedArgs {
+      phase: MatchPhase::Warmup,
+      duration_ms: 0,
+    }
+  }
+}
+
+pub struct MatchPhaseChangedBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MatchPhaseChangedBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_phase(&mut self, phase: MatchPhase) {
+    self.fbb_.push_slot::<MatchPhase>(MatchPhaseChanged::VT_PHASE, phase, MatchPhase::Warmup);
+  }
+  #[inline]
+  pub fn add_duration_ms(&mut self, duration_ms: u32) {
+    self.fbb_.push_slot::<u32>(MatchPhaseChanged::VT_DURATION_MS, duration_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MatchPhaseChangedBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    MatchPhaseChangedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MatchPhaseChanged<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MatchPhaseChanged<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MatchPhaseChanged");
+      ds.field("phase", &self.phase());
+      ds.field("duration_ms", &self.duration_ms());
+      ds.finish()
+  }
+}
+pub enum PlayerKilledOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PlayerKilled<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PlayerKilled<'a> {
+  type Inner = PlayerKilled<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PlayerKilled<'a> {
+  pub const VT_KILLER_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_VICTIM_PLAYER_ID: flatbuffers::VOffsetT = 6;
+  pub const VT_RESPAWN_POSITION: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PlayerKilled { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args PlayerKilledArgs<'args>
+  ) -> flatbuffers::WIPOffset<PlayerKilled<'bldr>> {
+    let mut builder = PlayerKilledBuilder::new(_fbb);
+    builder.add_victim_player_id(args.victim_player_id);
+    builder.add_killer_player_id(args.killer_player_id);
+    if let Some(x) = args.respawn_position { builder.add_respawn_position(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn killer_player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PlayerKilled::VT_KILLER_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn victim_player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(PlayerKilled::VT_VICTIM_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn respawn_position(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(PlayerKilled::VT_RESPAWN_POSITION, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PlayerKilled<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("killer_player_id", Self::VT_KILLER_PLAYER_ID, false)?
+     .visit_field::<u64>("victim_player_id", Self::VT_VICTIM_PLAYER_ID, false)?
+     .visit_field::<Vec2>("respawn_position", Self::VT_RESPAWN_POSITION, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PlayerKilledArgs<'a> {
+    pub killer_player_id: u64,
+    pub victim_player_id: u64,
+    pub respawn_position: Option<&'a Vec2>,
+}
+impl<'a> Default for PlayerKilledArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PlayerKilledArgs {
+      killer_player_id: 0,
+      victim_player_id: 0,
+      respawn_position: None,
+    }
+  }
+}
+
+pub struct PlayerKilledBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PlayerKilledBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_killer_player_id(&mut self, killer_player_id: u64) {
+    self.fbb_.push_slot::<u64>(PlayerKilled::VT_KILLER_PLAYER_ID, killer_player_id, 0);
+  }
+  #[inline]
+  pub fn add_victim_player_id(&mut self, victim_player_id: u64) {
+    self.fbb_.push_slot::<u64>(PlayerKilled::VT_VICTIM_PLAYER_ID, victim_player_id, 0);
+  }
+  #[inline]
+  pub fn add_respawn_position(&mut self, respawn_position: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(PlayerKilled::VT_RESPAWN_POSITION, respawn_position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayerKilledBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PlayerKilledBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerKilled<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PlayerKilled<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PlayerKilled");
+      ds.field("killer_player_id", &self.killer_player_id());
+      ds.field("victim_player_id", &self.victim_player_id());
+      ds.field("respawn_position", &self.respawn_position());
+      ds.finish()
+  }
+}
+pub enum ScoreboardUpdateOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScoreboardUpdate<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScoreboardUpdate<'a> {
+  type Inner = ScoreboardUpdate<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScoreboardUpdate<'a> {
+  pub const VT_SCORES: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScoreboardUpdate { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+    args: &'args ScoreboardUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScoreboardUpdate<'bldr>> {
+    let mut builder = ScoreboardUpdateBuilder::new(_fbb);
+    if let Some(x) = args.scores { builder.add_scores(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn scores(&self) -> Option<flatbuffers::Vector<'a, PlayerScore>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PlayerScore>>>(ScoreboardUpdate::VT_SCORES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScoreboardUpdate<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PlayerScore>>>("scores", Self::VT_SCORES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScoreboardUpdateArgs<'a> {
+    pub scores: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PlayerScore>>>,
+}
+impl<'a> Default for ScoreboardUpdateArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScoreboardUpdateArgs {
+      scores: None,
+    }
+  }
+}
+
+pub struct ScoreboardUpdateBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> PlayerMovedBuilder<'a, 'b> {
-  #[inline]
-  pub fn add_player_controls(&mut self, player_controls: &PlayerControl) {
-    self.fbb_.push_slot_always::<&PlayerControl>(PlayerMoved::VT_PLAYER_CONTROLS, player_controls);
-  }
+impl<'a: 'b, 'b> ScoreboardUpdateBuilder<'a, 'b> {
   #[inline]
-  pub fn add_player_position(&mut self, player_position: &Vec2) {
-    self.fbb_.push_slot_always::<&Vec2>(PlayerMoved::VT_PLAYER_POSITION, player_position);
+  pub fn add_scores(&mut self, scores: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PlayerScore>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScoreboardUpdate::VT_SCORES, scores);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PlayerMovedBuilder<'a, 'b> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ScoreboardUpdateBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    PlayerMovedBuilder {
+    ScoreboardUpdateBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<PlayerMoved<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScoreboardUpdate<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for PlayerMoved<'_> {
+impl core::fmt::Debug for ScoreboardUpdate<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("PlayerMoved");
-      ds.field("player_controls", &self.player_controls());
-      ds.field("player_position", &self.player_position());
+    let mut ds = f.debug_struct("ScoreboardUpdate");
+      ds.field("scores", &self.scores());
       ds.finish()
   }
 }
-pub enum WeaponFiredOffset {}
+pub enum MatchSummaryOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct WeaponFired<'a> {
+pub struct MatchSummary<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for WeaponFired<'a> {
-  type Inner = WeaponFired<'a>;
+impl<'a> flatbuffers::Follow<'a> for MatchSummary<'a> {
+  type Inner = MatchSummary<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> WeaponFired<'a> {
-  pub const VT_ANGLE: flatbuffers::VOffsetT = 4;
-  pub const VT_POWER: flatbuffers::VOffsetT = 6;
+impl<'a> MatchSummary<'a> {
+  pub const VT_WINNER_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_WINNING_TEAM: flatbuffers::VOffsetT = 6;
+  pub const VT_IS_TEAM_DRAW: flatbuffers::VOffsetT = 8;
+  pub const VT_DURATION_MS: flatbuffers::VOffsetT = 10;
+  pub const VT_SCORES: flatbuffers::VOffsetT = 12;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    WeaponFired { _tab: table }
+    MatchSummary { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args WeaponFiredArgs
-  ) -> flatbuffers::WIPOffset<WeaponFired<'bldr>> {
-    let mut builder = WeaponFiredBuilder::new(_fbb);
-    builder.add_power(args.power);
-    builder.add_angle(args.angle);
+    args: &'args MatchSummaryArgs<'args>
+  ) -> flatbuffers::WIPOffset<MatchSummary<'bldr>> {
+    let mut builder = MatchSummaryBuilder::new(_fbb);
+    builder.add_winner_player_id(args.winner_player_id);
+    if let Some(x) = args.scores { builder.add_scores(x); }
+    builder.add_duration_ms(args.duration_ms);
+    builder.add_is_team_draw(args.is_team_draw);
+    builder.add_winning_team(args.winning_team);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn angle(&self) -> f32 {
+  pub fn winner_player_id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(WeaponFired::VT_ANGLE, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<u64>(MatchSummary::VT_WINNER_PLAYER_ID, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn power(&self) -> f32 {
+  pub fn winning_team(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<f32>(WeaponFired::VT_POWER, Some(0.0)).unwrap()}
+    unsafe { self._tab.get::<u8>(MatchSummary::VT_WINNING_TEAM, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn is_team_draw(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(MatchSummary::VT_IS_TEAM_DRAW, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn duration_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MatchSummary::VT_DURATION_MS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn scores(&self) -> Option<flatbuffers::Vector<'a, PlayerScore>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PlayerScore>>>(MatchSummary::VT_SCORES, None)}
   }
 }
 
-impl flatbuffers::Verifiable for WeaponFired<'_> {
+impl flatbuffers::Verifiable for MatchSummary<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<f32>("angle", Self::VT_ANGLE, false)?
-     .visit_field::<f32>("power", Self::VT_POWER, false)?
+     .visit_field::<u64>("winner_player_id", Self::VT_WINNER_PLAYER_ID, false)?
+     .visit_field::<u8>("winning_team", Self::VT_WINNING_TEAM, false)?
+     .visit_field::<bool>("is_team_draw", Self::VT_IS_TEAM_DRAW, false)?
+     .visit_field::<u32>("duration_ms", Self::VT_DURATION_MS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PlayerScore>>>("scores", Self::VT_SCORES, false)?
      .finish();
     Ok(())
   }
 }
-pub struct WeaponFiredArgs {
-    pub angle: f32,
-    pub power: f32,
+pub struct MatchSummaryArgs<'a> {
+    pub winner_player_id: u64,
+    pub winning_team: u8,
+    pub is_team_draw: bool,
+    pub duration_ms: u32,
+    pub scores: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PlayerScore>>>,
 }
-impl<'a> Default for WeaponFiredArgs {
+impl<'a> Default for MatchSummaryArgs<'a> {
   #[inline]
   fn default() -> Self {
-    WeaponFiredArgs {
-      angle: 0.0,
-      power: 0.0,
+    MatchSummaryArgs {
+      winner_player_id: 0,
+      winning_team: 0,
+      is_team_draw: false,
+      duration_ms: 0,
+      scores: None,
     }
   }
 }
 
-pub struct WeaponFiredBuilder<'a: 'b, 'b> {
+pub struct MatchSummaryBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> WeaponFiredBuilder<'a, 'b> {
+impl<'a: 'b, 'b> MatchSummaryBuilder<'a, 'b> {
   #[inline]
-  pub fn add_angle(&mut self, angle: f32) {
-    self.fbb_.push_slot::<f32>(WeaponFired::VT_ANGLE, angle, 0.0);
+  pub fn add_winner_player_id(&mut self, winner_player_id: u64) {
+    self.fbb_.push_slot::<u64>(MatchSummary::VT_WINNER_PLAYER_ID, winner_player_id, 0);
   }
   #[inline]
-  pub fn add_power(&mut self, power: f32) {
-    self.fbb_.push_slot::<f32>(WeaponFired::VT_POWER, power, 0.0);
+  pub fn add_winning_team(&mut self, winning_team: u8) {
+    self.fbb_.push_slot::<u8>(MatchSummary::VT_WINNING_TEAM, winning_team, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> WeaponFiredBuilder<'a, 'b> {
+  pub fn add_is_team_draw(&mut self, is_team_draw: bool) {
+    self.fbb_.push_slot::<bool>(MatchSummary::VT_IS_TEAM_DRAW, is_team_draw, false);
+  }
+  #[inline]
+  pub fn add_duration_ms(&mut self, duration_ms: u32) {
+    self.fbb_.push_slot::<u32>(MatchSummary::VT_DURATION_MS, duration_ms, 0);
+  }
+  #[inline]
+  pub fn add_scores(&mut self, scores: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PlayerScore>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MatchSummary::VT_SCORES, scores);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MatchSummaryBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    WeaponFiredBuilder {
+    MatchSummaryBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<WeaponFired<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<MatchSummary<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for WeaponFired<'_> {
+impl core::fmt::Debug for MatchSummary<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("WeaponFired");
-      ds.field("angle", &self.angle());
-      ds.field("power", &self.power());
+    let mut ds = f.debug_struct("MatchSummary");
+      ds.field("winner_player_id", &self.winner_player_id());
+      ds.field("winning_team", &self.winning_team());
+      ds.field("is_team_draw", &self.is_team_draw());
+      ds.field("duration_ms", &self.duration_ms());
+      ds.field("scores", &self.scores());
       ds.finish()
   }
 }
-pub enum GameRequestEventOffset {}
+pub enum FlagUpdateOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GameRequestEvent<'a> {
+pub struct FlagUpdate<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GameRequestEvent<'a> {
-  type Inner = GameRequestEvent<'a>;
+impl<'a> flatbuffers::Follow<'a> for FlagUpdate<'a> {
+  type Inner = FlagUpdate<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> GameRequestEvent<'a> {
-  pub const VT_MSG_TYPE: flatbuffers::VOffsetT = 4;
-  pub const VT_MSG: flatbuffers::VOffsetT = 6;
+impl<'a> FlagUpdate<'a> {
+  pub const VT_TEAM: flatbuffers::VOffsetT = 4;
+  pub const VT_EVENT: flatbuffers::VOffsetT = 6;
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_POSITION: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GameRequestEvent { _tab: table }
+    FlagUpdate { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args GameRequestEventArgs
-  ) -> flatbuffers::WIPOffset<GameRequestEvent<'bldr>> {
-    let mut builder = GameRequestEventBuilder::new(_fbb);
-    if let Some(x) = args.msg { builder.add_msg(x); }
-    builder.add_msg_type(args.msg_type);
+    args: &'args FlagUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<FlagUpdate<'bldr>> {
+    let mut builder = FlagUpdateBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    if let Some(x) = args.position { builder.add_position(x); }
+    builder.add_event(args.event);
+    builder.add_team(args.team);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn msg_type(&self) -> RequestMessages {
+  pub fn team(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<RequestMessages>(GameRequestEvent::VT_MSG_TYPE, Some(RequestMessages::NONE)).unwrap()}
+    unsafe { self._tab.get::<u8>(FlagUpdate::VT_TEAM, Some(0)).unwrap()}
   }
   #[inline]
-  pub fn msg(&self) -> Option<flatbuffers::Table<'a>> {
+  pub fn event(&self) -> FlagEvent {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(GameRequestEvent::VT_MSG, None)}
+    unsafe { self._tab.get::<FlagEvent>(FlagUpdate::VT_EVENT, Some(FlagEvent::Taken)).unwrap()}
   }
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn msg_as_player_moved(&self) -> Option<PlayerMoved<'a>> {
-    if self.msg_type() == RequestMessages::PlayerMoved {
-      self.msg().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { PlayerMoved::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn player_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(FlagUpdate::VT_PLAYER_ID, Some(0)).unwrap()}
   }
-
   #[inline]
-  #[allow(non_snake_case)]
-  pub fn msg_as_weapon_fired(&self) -> Option<WeaponFired<'a>> {
-    if self.msg_type() == RequestMessages::WeaponFired {
-      self.msg().map(|t| {
-       // Safety:
-       // Created from a valid Table for this object
-       // Which contains a valid union in this slot
-       unsafe { WeaponFired::init_from_table(t) }
-     })
-    } else {
-      None
-    }
+  pub fn position(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(FlagUpdate::VT_POSITION, None)}
   }
-
 }
 
-impl flatbuffers::Verifiable for GameRequestEvent<'_> {
+impl flatbuffers::Verifiable for FlagUpdate<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_union::<RequestMessages, _>("msg_type", Self::VT_MSG_TYPE, "msg", Self::VT_MSG, false, |key, v, pos| {
-        match key {
-          RequestMessages::PlayerMoved => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerMoved>>("RequestMessages::PlayerMoved", pos),
-          RequestMessages::WeaponFired => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WeaponFired>>("RequestMessages::WeaponFired", pos),
-          _ => Ok(()),
-        }
-     })?
+     .visit_field::<u8>("team", Self::VT_TEAM, false)?
+     .visit_field::<FlagEvent>("event", Self::VT_EVENT, false)?
+     .visit_field::<u64>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<Vec2>("position", Self::VT_POSITION, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GameRequestEventArgs {
-    pub msg_type: RequestMessages,
-    pub msg: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct FlagUpdateArgs<'a> {
+    pub team: u8,
+    pub event: FlagEvent,
+    pub player_id: u64,
+    pub position: Option<&'a Vec2>,
 }
-impl<'a> Default for GameRequestEventArgs {
+impl<'a> Default for FlagUpdateArgs<'a> {
   #[inline]
   fn default() -> Self {
-    GameRequestEventArgs {
-      msg_type: RequestMessages::NONE,
-      msg: None,
+    FlagUpdateArgs {
+      team: 0,
+      event: FlagEvent::Taken,
+      player_id: 0,
+      position: None,
     }
   }
 }
 
-pub struct GameRequestEventBuilder<'a: 'b, 'b> {
+pub struct FlagUpdateBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GameRequestEventBuilder<'a, 'b> {
+impl<'a: 'b, 'b> FlagUpdateBuilder<'a, 'b> {
   #[inline]
-  pub fn add_msg_type(&mut self, msg_type: RequestMessages) {
-    self.fbb_.push_slot::<RequestMessages>(GameRequestEvent::VT_MSG_TYPE, msg_type, RequestMessages::NONE);
+  pub fn add_team(&mut self, team: u8) {
+    self.fbb_.push_slot::<u8>(FlagUpdate::VT_TEAM, team, 0);
   }
   #[inline]
-  pub fn add_msg(&mut self, msg: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameRequestEvent::VT_MSG, msg);
+  pub fn add_event(&mut self, event: FlagEvent) {
+    self.fbb_.push_slot::<FlagEvent>(FlagUpdate::VT_EVENT, event, FlagEvent::Taken);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GameRequestEventBuilder<'a, 'b> {
+  pub fn add_player_id(&mut self, player_id: u64) {
+    self.fbb_.push_slot::<u64>(FlagUpdate::VT_PLAYER_ID, player_id, 0);
+  }
+  #[inline]
+  pub fn add_position(&mut self, position: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(FlagUpdate::VT_POSITION, position);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FlagUpdateBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    GameRequestEventBuilder {
+    FlagUpdateBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GameRequestEvent<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<FlagUpdate<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for GameRequestEvent<'_> {
+impl core::fmt::Debug for FlagUpdate<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GameRequestEvent");
-      ds.field("msg_type", &self.msg_type());
-      match self.msg_type() {
-        RequestMessages::PlayerMoved => {
-          if let Some(x) = self.msg_as_player_moved() {
-            ds.field("msg", &x)
-          } else {
-            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        RequestMessages::WeaponFired => {
-          if let Some(x) = self.msg_as_weapon_fired() {
-            ds.field("msg", &x)
-          } else {
-            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
-          }
-        },
-        _ => {
-          let x: Option<()> = None;
-          ds.field("msg", &x)
-        },
-      };
+    let mut ds = f.debug_struct("FlagUpdate");
+      ds.field("team", &self.team());
+      ds.field("event", &self.event());
+      ds.field("player_id", &self.player_id());
+      ds.field("position", &self.position());
       ds.finish()
   }
 }
-pub enum RemotePeerJoinedOffset {}
+pub enum LobbyMemberOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RemotePeerJoined<'a> {
+pub struct LobbyMember<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RemotePeerJoined<'a> {
-  type Inner = RemotePeerJoined<'a>;
+impl<'a> flatbuffers::Follow<'a> for LobbyMember<'a> {
+  type Inner = LobbyMember<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> RemotePeerJoined<'a> {
-  pub const VT_PLAYER_DATA: flatbuffers::VOffsetT = 4;
+impl<'a> LobbyMember<'a> {
+  pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_SKIN: flatbuffers::VOffsetT = 8;
+  pub const VT_TEAM: flatbuffers::VOffsetT = 10;
+  pub const VT_HAS_TEAM: flatbuffers::VOffsetT = 12;
+  pub const VT_READY: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    RemotePeerJoined { _tab: table }
+    LobbyMember { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args RemotePeerJoinedArgs<'args>
-  ) -> flatbuffers::WIPOffset<RemotePeerJoined<'bldr>> {
-    let mut builder = RemotePeerJoinedBuilder::new(_fbb);
-    if let Some(x) = args.player_data { builder.add_player_data(x); }
+    args: &'args LobbyMemberArgs<'args>
+  ) -> flatbuffers::WIPOffset<LobbyMember<'bldr>> {
+    let mut builder = LobbyMemberBuilder::new(_fbb);
+    builder.add_player_id(args.player_id);
+    if let Some(x) = args.skin { builder.add_skin(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_ready(args.ready);
+    builder.add_has_team(args.has_team);
+    builder.add_team(args.team);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn player_data(&self) -> Option<&'a PlayerData> {
+  pub fn player_id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<PlayerData>(RemotePeerJoined::VT_PLAYER_DATA, None)}
+    unsafe { self._tab.get::<u64>(LobbyMember::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LobbyMember::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn skin(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LobbyMember::VT_SKIN, None)}
+  }
+  #[inline]
+  pub fn team(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(LobbyMember::VT_TEAM, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn has_team(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(LobbyMember::VT_HAS_TEAM, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn ready(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(LobbyMember::VT_READY, Some(false)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for RemotePeerJoined<'_> {
+impl flatbuffers::Verifiable for LobbyMember<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<PlayerData>("player_data", Self::VT_PLAYER_DATA, false)?
+     .visit_field::<u64>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("skin", Self::VT_SKIN, false)?
+     .visit_field::<u8>("team", Self::VT_TEAM, false)?
+     .visit_field::<bool>("has_team", Self::VT_HAS_TEAM, false)?
+     .visit_field::<bool>("ready", Self::VT_READY, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RemotePeerJoinedArgs<'a> {
-    pub player_data: Option<&'a PlayerData>,
+pub struct LobbyMemberArgs<'a> {
+    pub player_id: u64,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub skin: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub team: u8,
+    pub has_team: bool,
+    pub ready: bool,
 }
-impl<'a> Default for RemotePeerJoinedArgs<'a> {
+impl<'a> Default for LobbyMemberArgs<'a> {
   #[inline]
   fn default() -> Self {
-    RemotePeerJoinedArgs {
-      player_data: None,
+    LobbyMemberArgs {
+      player_id: 0,
+      name: None,
+      skin: None,
+      team: 0,
+      has_team: false,
+      ready: false,
     }
   }
 }
 
-pub struct RemotePeerJoinedBuilder<'a: 'b, 'b> {
+pub struct LobbyMemberBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RemotePeerJoinedBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LobbyMemberBuilder<'a, 'b> {
   #[inline]
-  pub fn add_player_data(&mut self, player_data: &PlayerData) {
-    self.fbb_.push_slot_always::<&PlayerData>(RemotePeerJoined::VT_PLAYER_DATA, player_data);
+  pub fn add_player_id(&mut self, player_id: u64) {
+    self.fbb_.push_slot::<u64>(LobbyMember::VT_PLAYER_ID, player_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerJoinedBuilder<'a, 'b> {
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LobbyMember::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_skin(&mut self, skin: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LobbyMember::VT_SKIN, skin);
+  }
+  #[inline]
+  pub fn add_team(&mut self, team: u8) {
+    self.fbb_.push_slot::<u8>(LobbyMember::VT_TEAM, team, 0);
+  }
+  #[inline]
+  pub fn add_has_team(&mut self, has_team: bool) {
+    self.fbb_.push_slot::<bool>(LobbyMember::VT_HAS_TEAM, has_team, false);
+  }
+  #[inline]
+  pub fn add_ready(&mut self, ready: bool) {
+    self.fbb_.push_slot::<bool>(LobbyMember::VT_READY, ready, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LobbyMemberBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    RemotePeerJoinedBuilder {
+    LobbyMemberBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerJoined<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<LobbyMember<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for RemotePeerJoined<'_> {
+impl core::fmt::Debug for LobbyMember<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("RemotePeerJoined");
-      ds.field("player_data", &self.player_data());
+    let mut ds = f.debug_struct("LobbyMember");
+      ds.field("player_id", &self.player_id());
+      ds.field("name", &self.name());
+      ds.field("skin", &self.skin());
+      ds.field("team", &self.team());
+      ds.field("has_team", &self.has_team());
+      ds.field("ready", &self.ready());
       ds.finish()
   }
 }
-pub enum RemotePeerLeftOffset {}
+pub enum LobbyUpdateOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RemotePeerLeft<'a> {
+pub struct LobbyUpdate<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RemotePeerLeft<'a> {
-  type Inner = RemotePeerLeft<'a>;
+impl<'a> flatbuffers::Follow<'a> for LobbyUpdate<'a> {
+  type Inner = LobbyUpdate<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> RemotePeerLeft<'a> {
+impl<'a> LobbyUpdate<'a> {
   pub const VT_PLAYER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_MEMBERS: flatbuffers::VOffsetT = 6;
+  pub const VT_COUNTDOWN_MS: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    RemotePeerLeft { _tab: table }
+    LobbyUpdate { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args RemotePeerLeftArgs
-  ) -> flatbuffers::WIPOffset<RemotePeerLeft<'bldr>> {
-    let mut builder = RemotePeerLeftBuilder::new(_fbb);
+    args: &'args LobbyUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<LobbyUpdate<'bldr>> {
+    let mut builder = LobbyUpdateBuilder::new(_fbb);
     builder.add_player_id(args.player_id);
+    builder.add_countdown_ms(args.countdown_ms);
+    if let Some(x) = args.members { builder.add_members(x); }
     builder.finish()
   }
 
@@ -1158,11 +3846,25 @@ impl<'a> RemotePeerLeft<'a> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u64>(RemotePeerLeft::VT_PLAYER_ID, Some(0)).unwrap()}
+    unsafe { self._tab.get::<u64>(LobbyUpdate::VT_PLAYER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn members(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LobbyMember<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LobbyMember>>>>(LobbyUpdate::VT_MEMBERS, None)}
+  }
+  #[inline]
+  pub fn countdown_ms(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(LobbyUpdate::VT_COUNTDOWN_MS, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for RemotePeerLeft<'_> {
+impl flatbuffers::Verifiable for LobbyUpdate<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
@@ -1170,244 +3872,277 @@ impl flatbuffers::Verifiable for RemotePeerLeft<'_> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
      .visit_field::<u64>("player_id", Self::VT_PLAYER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<LobbyMember>>>>("members", Self::VT_MEMBERS, false)?
+     .visit_field::<u32>("countdown_ms", Self::VT_COUNTDOWN_MS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RemotePeerLeftArgs {
+pub struct LobbyUpdateArgs<'a> {
     pub player_id: u64,
+    pub members: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<LobbyMember<'a>>>>>,
+    pub countdown_ms: u32,
 }
-impl<'a> Default for RemotePeerLeftArgs {
+impl<'a> Default for LobbyUpdateArgs<'a> {
   #[inline]
   fn default() -> Self {
-    RemotePeerLeftArgs {
+    LobbyUpdateArgs {
       player_id: 0,
+      members: None,
+      countdown_ms: 0,
     }
   }
 }
 
-pub struct RemotePeerLeftBuilder<'a: 'b, 'b> {
+pub struct LobbyUpdateBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RemotePeerLeftBuilder<'a, 'b> {
+impl<'a: 'b, 'b> LobbyUpdateBuilder<'a, 'b> {
   #[inline]
   pub fn add_player_id(&mut self, player_id: u64) {
-    self.fbb_.push_slot::<u64>(RemotePeerLeft::VT_PLAYER_ID, player_id, 0);
+    self.fbb_.push_slot::<u64>(LobbyUpdate::VT_PLAYER_ID, player_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerLeftBuilder<'a, 'b> {
+  pub fn add_members(&mut self, members: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<LobbyMember<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LobbyUpdate::VT_MEMBERS, members);
+  }
+  #[inline]
+  pub fn add_countdown_ms(&mut self, countdown_ms: u32) {
+    self.fbb_.push_slot::<u32>(LobbyUpdate::VT_COUNTDOWN_MS, countdown_ms, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LobbyUpdateBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    RemotePeerLeftBuilder {
+    LobbyUpdateBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerLeft<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<LobbyUpdate<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for RemotePeerLeft<'_> {
+impl core::fmt::Debug for LobbyUpdate<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("RemotePeerLeft");
+    let mut ds = f.debug_struct("LobbyUpdate");
       ds.field("player_id", &self.player_id());
+      ds.field("members", &self.members());
+      ds.field("countdown_ms", &self.countdown_ms());
       ds.finish()
   }
 }
-pub enum RemotePeerPositionUpdateOffset {}
+pub enum PartyInvitationOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RemotePeerPositionUpdate<'a> {
+pub struct PartyInvitation<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RemotePeerPositionUpdate<'a> {
-  type Inner = RemotePeerPositionUpdate<'a>;
+impl<'a> flatbuffers::Follow<'a> for PartyInvitation<'a> {
+  type Inner = PartyInvitation<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> RemotePeerPositionUpdate<'a> {
-  pub const VT_PLAYER_DATA: flatbuffers::VOffsetT = 4;
+impl<'a> PartyInvitation<'a> {
+  pub const VT_LEADER_ID: flatbuffers::VOffsetT = 4;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    RemotePeerPositionUpdate { _tab: table }
+    PartyInvitation { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args RemotePeerPositionUpdateArgs<'args>
-  ) -> flatbuffers::WIPOffset<RemotePeerPositionUpdate<'bldr>> {
-    let mut builder = RemotePeerPositionUpdateBuilder::new(_fbb);
-    if let Some(x) = args.player_data { builder.add_player_data(x); }
+    args: &'args PartyInvitationArgs
+  ) -> flatbuffers::WIPOffset<PartyInvitation<'bldr>> {
+    let mut builder = PartyInvitationBuilder::new(_fbb);
+    builder.add_leader_id(args.leader_id);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn player_data(&self) -> Option<&'a PlayerData> {
+  pub fn leader_id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<PlayerData>(RemotePeerPositionUpdate::VT_PLAYER_DATA, None)}
+    unsafe { self._tab.get::<u64>(PartyInvitation::VT_LEADER_ID, Some(0)).unwrap()}
   }
 }
 
-impl flatbuffers::Verifiable for RemotePeerPositionUpdate<'_> {
+impl flatbuffers::Verifiable for PartyInvitation<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<PlayerData>("player_data", Self::VT_PLAYER_DATA, false)?
+     .visit_field::<u64>("leader_id", Self::VT_LEADER_ID, false)?
      .finish();
     Ok(())
   }
 }
-pub struct RemotePeerPositionUpdateArgs<'a> {
-    pub player_data: Option<&'a PlayerData>,
+pub struct PartyInvitationArgs {
+    pub leader_id: u64,
 }
-impl<'a> Default for RemotePeerPositionUpdateArgs<'a> {
+impl<'a> Default for PartyInvitationArgs {
   #[inline]
   fn default() -> Self {
-    RemotePeerPositionUpdateArgs {
-      player_data: None,
+    PartyInvitationArgs {
+      leader_id: 0,
     }
   }
 }
 
-pub struct RemotePeerPositionUpdateBuilder<'a: 'b, 'b> {
+pub struct PartyInvitationBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> RemotePeerPositionUpdateBuilder<'a, 'b> {
+impl<'a: 'b, 'b> PartyInvitationBuilder<'a, 'b> {
   #[inline]
-  pub fn add_player_data(&mut self, player_data: &PlayerData) {
-    self.fbb_.push_slot_always::<&PlayerData>(RemotePeerPositionUpdate::VT_PLAYER_DATA, player_data);
+  pub fn add_leader_id(&mut self, leader_id: u64) {
+    self.fbb_.push_slot::<u64>(PartyInvitation::VT_LEADER_ID, leader_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RemotePeerPositionUpdateBuilder<'a, 'b> {
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyInvitationBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    RemotePeerPositionUpdateBuilder {
+    PartyInvitationBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<RemotePeerPositionUpdate<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<PartyInvitation<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for RemotePeerPositionUpdate<'_> {
+impl core::fmt::Debug for PartyInvitation<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("RemotePeerPositionUpdate");
-      ds.field("player_data", &self.player_data());
+    let mut ds = f.debug_struct("PartyInvitation");
+      ds.field("leader_id", &self.leader_id());
       ds.finish()
   }
 }
-pub enum GameWorldUpdateOffset {}
+pub enum PartyUpdateOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GameWorldUpdate<'a> {
+pub struct PartyUpdate<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GameWorldUpdate<'a> {
-  type Inner = GameWorldUpdate<'a>;
+impl<'a> flatbuffers::Follow<'a> for PartyUpdate<'a> {
+  type Inner = PartyUpdate<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> GameWorldUpdate<'a> {
-  pub const VT_PLAYER_DATA_LIST: flatbuffers::VOffsetT = 4;
+impl<'a> PartyUpdate<'a> {
+  pub const VT_LEADER_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_MEMBERS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    GameWorldUpdate { _tab: table }
+    PartyUpdate { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-    args: &'args GameWorldUpdateArgs<'args>
-  ) -> flatbuffers::WIPOffset<GameWorldUpdate<'bldr>> {
-    let mut builder = GameWorldUpdateBuilder::new(_fbb);
-    if let Some(x) = args.player_data_list { builder.add_player_data_list(x); }
+    args: &'args PartyUpdateArgs<'args>
+  ) -> flatbuffers::WIPOffset<PartyUpdate<'bldr>> {
+    let mut builder = PartyUpdateBuilder::new(_fbb);
+    builder.add_leader_id(args.leader_id);
+    if let Some(x) = args.members { builder.add_members(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn player_data_list(&self) -> Option<flatbuffers::Vector<'a, PlayerData>> {
+  pub fn leader_id(&self) -> u64 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, PlayerData>>>(GameWorldUpdate::VT_PLAYER_DATA_LIST, None)}
+    unsafe { self._tab.get::<u64>(PartyUpdate::VT_LEADER_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn members(&self) -> Option<flatbuffers::Vector<'a, u64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u64>>>(PartyUpdate::VT_MEMBERS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for GameWorldUpdate<'_> {
+impl flatbuffers::Verifiable for PartyUpdate<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, PlayerData>>>("player_data_list", Self::VT_PLAYER_DATA_LIST, false)?
+     .visit_field::<u64>("leader_id", Self::VT_LEADER_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("members", Self::VT_MEMBERS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct GameWorldUpdateArgs<'a> {
-    pub player_data_list: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, PlayerData>>>,
+pub struct PartyUpdateArgs<'a> {
+    pub leader_id: u64,
+    pub members: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
 }
-impl<'a> Default for GameWorldUpdateArgs<'a> {
+impl<'a> Default for PartyUpdateArgs<'a> {
   #[inline]
   fn default() -> Self {
-    GameWorldUpdateArgs {
-      player_data_list: None,
+    PartyUpdateArgs {
+      leader_id: 0,
+      members: None,
     }
   }
 }
 
-pub struct GameWorldUpdateBuilder<'a: 'b, 'b> {
+pub struct PartyUpdateBuilder<'a: 'b, 'b> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> GameWorldUpdateBuilder<'a, 'b> {
+impl<'a: 'b, 'b> PartyUpdateBuilder<'a, 'b> {
   #[inline]
-  pub fn add_player_data_list(&mut self, player_data_list: flatbuffers::WIPOffset<flatbuffers::Vector<'b , PlayerData>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GameWorldUpdate::VT_PLAYER_DATA_LIST, player_data_list);
+  pub fn add_leader_id(&mut self, leader_id: u64) {
+    self.fbb_.push_slot::<u64>(PartyUpdate::VT_LEADER_ID, leader_id, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GameWorldUpdateBuilder<'a, 'b> {
+  pub fn add_members(&mut self, members: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PartyUpdate::VT_MEMBERS, members);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PartyUpdateBuilder<'a, 'b> {
     let start = _fbb.start_table();
-    GameWorldUpdateBuilder {
+    PartyUpdateBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<GameWorldUpdate<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<PartyUpdate<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for GameWorldUpdate<'_> {
+impl core::fmt::Debug for PartyUpdate<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("GameWorldUpdate");
-      ds.field("player_data_list", &self.player_data_list());
+    let mut ds = f.debug_struct("PartyUpdate");
+      ds.field("leader_id", &self.leader_id());
+      ds.field("members", &self.members());
       ds.finish()
   }
 }
@@ -1520,6 +4255,141 @@ impl<'a> GameReponseEvent<'a> {
     }
   }
 
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_chat_message(&self) -> Option<ChatMessage<'a>> {
+    if self.msg_type() == ResponseMessage::ChatMessage {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ChatMessage::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_match_phase_changed(&self) -> Option<MatchPhaseChanged<'a>> {
+    if self.msg_type() == ResponseMessage::MatchPhaseChanged {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MatchPhaseChanged::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_player_killed(&self) -> Option<PlayerKilled<'a>> {
+    if self.msg_type() == ResponseMessage::PlayerKilled {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PlayerKilled::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_scoreboard_update(&self) -> Option<ScoreboardUpdate<'a>> {
+    if self.msg_type() == ResponseMessage::ScoreboardUpdate {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { ScoreboardUpdate::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_match_summary(&self) -> Option<MatchSummary<'a>> {
+    if self.msg_type() == ResponseMessage::MatchSummary {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { MatchSummary::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_flag_update(&self) -> Option<FlagUpdate<'a>> {
+    if self.msg_type() == ResponseMessage::FlagUpdate {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { FlagUpdate::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_lobby_update(&self) -> Option<LobbyUpdate<'a>> {
+    if self.msg_type() == ResponseMessage::LobbyUpdate {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { LobbyUpdate::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_party_invitation(&self) -> Option<PartyInvitation<'a>> {
+    if self.msg_type() == ResponseMessage::PartyInvitation {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PartyInvitation::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn msg_as_party_update(&self) -> Option<PartyUpdate<'a>> {
+    if self.msg_type() == ResponseMessage::PartyUpdate {
+      self.msg().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { PartyUpdate::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
 }
 
 impl flatbuffers::Verifiable for GameReponseEvent<'_> {
@@ -1535,6 +4405,15 @@ impl flatbuffers::Verifiable for GameReponseEvent<'_> {
           ResponseMessage::RemotePeerLeft => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RemotePeerLeft>>("ResponseMessage::RemotePeerLeft", pos),
           ResponseMessage::RemotePeerPositionUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RemotePeerPositionUpdate>>("ResponseMessage::RemotePeerPositionUpdate", pos),
           ResponseMessage::GameWorldUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GameWorldUpdate>>("ResponseMessage::GameWorldUpdate", pos),
+          ResponseMessage::ChatMessage => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ChatMessage>>("ResponseMessage::ChatMessage", pos),
+          ResponseMessage::MatchPhaseChanged => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MatchPhaseChanged>>("ResponseMessage::MatchPhaseChanged", pos),
+          ResponseMessage::PlayerKilled => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PlayerKilled>>("ResponseMessage::PlayerKilled", pos),
+          ResponseMessage::ScoreboardUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ScoreboardUpdate>>("ResponseMessage::ScoreboardUpdate", pos),
+          ResponseMessage::MatchSummary => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MatchSummary>>("ResponseMessage::MatchSummary", pos),
+          ResponseMessage::FlagUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FlagUpdate>>("ResponseMessage::FlagUpdate", pos),
+          ResponseMessage::LobbyUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LobbyUpdate>>("ResponseMessage::LobbyUpdate", pos),
+          ResponseMessage::PartyInvitation => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PartyInvitation>>("ResponseMessage::PartyInvitation", pos),
+          ResponseMessage::PartyUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<PartyUpdate>>("ResponseMessage::PartyUpdate", pos),
           _ => Ok(()),
         }
      })?
@@ -1617,6 +4496,69 @@ impl core::fmt::Debug for GameReponseEvent<'_> {
             ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
           }
         },
+        ResponseMessage::ChatMessage => {
+          if let Some(x) = self.msg_as_chat_message() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::MatchPhaseChanged => {
+          if let Some(x) = self.msg_as_match_phase_changed() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::PlayerKilled => {
+          if let Some(x) = self.msg_as_player_killed() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::ScoreboardUpdate => {
+          if let Some(x) = self.msg_as_scoreboard_update() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::MatchSummary => {
+          if let Some(x) = self.msg_as_match_summary() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::FlagUpdate => {
+          if let Some(x) = self.msg_as_flag_update() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::LobbyUpdate => {
+          if let Some(x) = self.msg_as_lobby_update() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::PartyInvitation => {
+          if let Some(x) = self.msg_as_party_invitation() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        ResponseMessage::PartyUpdate => {
+          if let Some(x) = self.msg_as_party_update() {
+            ds.field("msg", &x)
+          } else {
+            ds.field("msg", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
         _ => {
           let x: Option<()> = None;
           ds.field("msg", &x)
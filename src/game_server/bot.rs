@@ -0,0 +1,159 @@
+use std::time::Duration;
+use rand::Rng;
+use serde::Deserialize;
+use crate::game_server::game_map::GameMap;
+use crate::game_server::peer::{ClientControls, ClientPosition};
+use crate::game_server::simulation::SimRng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BotBehaviour {
+    /// walks to random points on the map
+    Wander,
+    /// runs at the nearest human
+    Chase,
+    /// runs away from the nearest human
+    Flee,
+    /// keeps its distance from the nearest human and fires at it
+    Shoot,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BotConfig {
+    /// fill empty player slots with bots while at least one human is in the room
+    pub enabled: bool,
+    /// how often bots decide and send a move
+    pub tick_ms: u64,
+    /// units per second, keep it below `movement.maxSpeed`
+    pub speed: f32,
+    /// each bot picks one of these at random
+    pub behaviours: Vec<BotBehaviour>,
+}
+
+impl Default for BotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tick_ms: 50,
+            speed: 300.0,
+            behaviours: vec![BotBehaviour::Wander, BotBehaviour::Chase, BotBehaviour::Flee, BotBehaviour::Shoot],
+        }
+    }
+}
+
+/// What a bot does this tick, applied like a player's input
+#[derive(Debug, Clone, Copy)]
+pub struct BotAction {
+    pub position: ClientPosition,
+    pub controls: ClientControls,
+    /// angle in radians, set when the bot fires this tick
+    pub fire_angle: Option<f32>,
+}
+
+// Shoot keeps the target between these distances
+const SHOOT_MIN_DISTANCE: f32 = 200.0;
+const SHOOT_MAX_DISTANCE: f32 = 320.0;
+const FIRE_INTERVAL: Duration = Duration::from_secs(1);
+/// close enough to a wander target to pick the next one
+const ARRIVE_DISTANCE: f32 = 16.0;
+
+#[derive(Debug)]
+pub struct Bot {
+    pub player_id: usize,
+    pub behaviour: BotBehaviour,
+    wander_target: Option<ClientPosition>,
+    reload: Duration,
+}
+
+impl Bot {
+    pub fn new(player_id: usize, behaviour: BotBehaviour) -> Self {
+        Self {
+            player_id,
+            behaviour,
+            wander_target: None,
+            reload: Duration::ZERO,
+        }
+    }
+
+    /// Decides the next move from the bot's current `position` and the positions of the humans in the room.
+    /// Behaviours that need a human wander while there is none.
+    pub fn think(&mut self, position: ClientPosition, humans: &[ClientPosition], map: &GameMap, config: &BotConfig, dt: Duration, rng: &mut SimRng) -> BotAction {
+        self.reload = self.reload.saturating_sub(dt);
+        let nearest = humans.iter()
+            .copied()
+            .min_by(|a, b| distance(position, *a).total_cmp(&distance(position, *b)));
+
+        let mut fire_angle = None;
+        let direction = match (self.behaviour, nearest) {
+            (BotBehaviour::Chase, Some(target)) => toward(position, target),
+            (BotBehaviour::Flee, Some(target)) => {
+                let (dx, dy) = toward(position, target);
+                (-dx, -dy)
+            }
+            (BotBehaviour::Shoot, Some(target)) => {
+                if self.reload.is_zero() && distance(position, target) <= SHOOT_MAX_DISTANCE * 1.5 {
+                    fire_angle = Some((target.y - position.y).atan2(target.x - position.x));
+                    self.reload = FIRE_INTERVAL;
+                }
+                let (dx, dy) = toward(position, target);
+                match distance(position, target) {
+                    d if d > SHOOT_MAX_DISTANCE => (dx, dy),
+                    d if d < SHOOT_MIN_DISTANCE => (-dx, -dy),
+                    _ => (0.0, 0.0),
+                }
+            }
+            _ => self.wander(position, map, rng),
+        };
+
+        let step = config.speed * dt.as_secs_f32();
+        // stay inside the bounds, crossing an edge would wrap the bot to the other side
+        let next = map.bounds.clamp(ClientPosition {
+            x: position.x + direction.0 * step,
+            y: position.y + direction.1 * step,
+        });
+        let next = map.resolve_collisions(next).unwrap_or(position);
+
+        BotAction {
+            position: next,
+            controls: ClientControls {
+                up: next.y < position.y,
+                down: next.y > position.y,
+                left: next.x < position.x,
+                right: next.x > position.x,
+            },
+            fire_angle,
+        }
+    }
+
+    fn wander(&mut self, position: ClientPosition, map: &GameMap, rng: &mut SimRng) -> (f32, f32) {
+        let target = match self.wander_target {
+            Some(target) if distance(position, target) > ARRIVE_DISTANCE => target,
+            _ => {
+                let bounds = map.bounds;
+                let target = ClientPosition {
+                    x: rng.gen_range(bounds.min_x..=bounds.max_x),
+                    y: rng.gen_range(bounds.min_y..=bounds.max_y),
+                };
+                self.wander_target = Some(target);
+                target
+            }
+        };
+        toward(position, target)
+    }
+}
+
+/// unit vector from `from` to `to`, zero when they are the same point
+fn toward(from: ClientPosition, to: ClientPosition) -> (f32, f32) {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length <= f32::EPSILON {
+        return (0.0, 0.0);
+    }
+    (dx / length, dy / length)
+}
+
+fn distance(a: ClientPosition, b: ClientPosition) -> f32 {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    (dx * dx + dy * dy).sqrt()
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use serde::Deserialize;
+use crate::game_server::game_map::GameMap;
+use crate::game_server::game_mode::{score_kill, GameMode, GameModeKind, Hit, ModeContext};
+use crate::game_server::game_world::{PeerPlayerInfo, WorldEvent};
+use crate::game_server::match_state::{MatchPhase, MatchState};
+use crate::game_server::peer::ClientPosition;
+use crate::game_server::simulation::SimTime;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CaptureTheFlagConfig {
+    /// captures that win the match, 0 plays until the time limit
+    pub capture_limit: u32,
+    /// points for the player who captures
+    pub capture_points: i32,
+    /// how close a player has to be to take, return or capture a flag
+    pub pickup_radius: f32,
+    /// a dropped flag goes home after this long
+    pub return_secs: u64,
+}
+
+impl Default for CaptureTheFlagConfig {
+    fn default() -> Self {
+        Self {
+            capture_limit: 3,
+            capture_points: 5,
+            pickup_radius: 32.0,
+            return_secs: 15,
+        }
+    }
+}
+
+/// What happened to a flag, sent with the flag's new position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagEvent {
+    /// picked up by an enemy
+    Taken,
+    /// its carrier was killed or left
+    Dropped,
+    /// back home, touched by a teammate or timed out
+    Returned,
+    /// carried to the enemy's home flag, scores and goes home
+    Captured,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlagState {
+    Home,
+    Carried { carrier_id: usize },
+    Dropped { position: ClientPosition, since: SimTime },
+}
+
+#[derive(Debug)]
+struct Flag {
+    team: u8,
+    home: ClientPosition,
+    state: FlagState,
+}
+
+impl Flag {
+    fn position(&self, players: &BTreeMap<usize, PeerPlayerInfo>) -> ClientPosition {
+        match self.state {
+            FlagState::Home => self.home,
+            FlagState::Carried { carrier_id } => players.get(&carrier_id)
+                .map(|data| ClientPosition { x: data.x, y: data.y })
+                .unwrap_or(self.home),
+            FlagState::Dropped { position, .. } => position,
+        }
+    }
+
+    fn event(&self, event: FlagEvent, player_id: Option<usize>, position: ClientPosition) -> WorldEvent {
+        WorldEvent::FlagChanged { team: self.team, event, player_id, position }
+    }
+}
+
+/// Every team has a flag on its first team spawn point. Touching the enemy flag takes it, touching your own dropped
+/// flag returns it, and bringing the enemy flag to your own flag while it is home captures it.
+#[derive(Debug)]
+pub struct CaptureTheFlag {
+    config: CaptureTheFlagConfig,
+    flags: Vec<Flag>,
+    captures: BTreeMap<u8, u32>,
+}
+
+impl CaptureTheFlag {
+    pub fn new(config: CaptureTheFlagConfig, map: &GameMap, team_count: u8) -> Self {
+        let flags = (0..team_count)
+            .map(|team| {
+                // maps without team spawn points share theirs out in order
+                let home = map.spawn_points
+                    .iter()
+                    .find(|point| point.team == Some(team))
+                    .or_else(|| map.spawn_points.get(team as usize % map.spawn_points.len().max(1)))
+                    .map(|point| point.position())
+                    .unwrap_or(ClientPosition { x: 0.0, y: 0.0 });
+                Flag { team, home, state: FlagState::Home }
+            })
+            .collect();
+
+        Self {
+            config,
+            flags,
+            captures: BTreeMap::new(),
+        }
+    }
+
+    fn carried_by(&self, player_id: usize) -> Option<usize> {
+        self.flags.iter().position(|flag| flag.state == FlagState::Carried { carrier_id: player_id })
+    }
+
+    /// drops whatever flag the player carries where they are
+    fn drop_flag(&mut self, ctx: &ModeContext, player_id: usize, position: ClientPosition) -> Option<WorldEvent> {
+        let index = self.carried_by(player_id)?;
+        let flag = &mut self.flags[index];
+        flag.state = FlagState::Dropped { position, since: ctx.now };
+        Some(flag.event(FlagEvent::Dropped, Some(player_id), position))
+    }
+
+    fn touches(&self, a: ClientPosition, b: ClientPosition) -> bool {
+        let (dx, dy) = (a.x - b.x, a.y - b.y);
+        (dx * dx + dy * dy).sqrt() <= self.config.pickup_radius
+    }
+}
+
+impl GameMode for CaptureTheFlag {
+    fn kind(&self) -> GameModeKind {
+        GameModeKind::CaptureTheFlag
+    }
+
+    fn on_leave(&mut self, ctx: &mut ModeContext, player_id: usize) -> Vec<WorldEvent> {
+        let Some(position) = ctx.player_position(player_id) else {
+            return Vec::new();
+        };
+        self.drop_flag(ctx, player_id, position).into_iter().collect()
+    }
+
+    fn on_input(&mut self, ctx: &mut ModeContext, player_id: usize, position: ClientPosition) -> Vec<WorldEvent> {
+        let Some(team) = ctx.players.get(&player_id).map(|data| data.team) else {
+            return Vec::new();
+        };
+        if ctx.match_state.phase() == MatchPhase::Ended {
+            return Vec::new();
+        }
+
+        let mut events = Vec::new();
+        for index in 0..self.flags.len() {
+            let flag_position = self.flags[index].position(ctx.players);
+            if !self.touches(position, flag_position) {
+                continue;
+            }
+            let carrying = self.carried_by(player_id);
+            let flag = &mut self.flags[index];
+            match flag.state {
+                FlagState::Home | FlagState::Dropped { .. } if flag.team != team && carrying.is_none() => {
+                    flag.state = FlagState::Carried { carrier_id: player_id };
+                    events.push(flag.event(FlagEvent::Taken, Some(player_id), position));
+                }
+                FlagState::Dropped { .. } if flag.team == team => {
+                    flag.state = FlagState::Home;
+                    events.push(flag.event(FlagEvent::Returned, Some(player_id), flag.home));
+                }
+                FlagState::Home if flag.team == team => {
+                    let Some(carried) = carrying else {
+                        continue;
+                    };
+                    let captured = &mut self.flags[carried];
+                    captured.state = FlagState::Home;
+                    events.push(captured.event(FlagEvent::Captured, Some(player_id), captured.home));
+
+                    // captures in warmup only send the flag home
+                    if ctx.match_state.phase() == MatchPhase::Live {
+                        *self.captures.entry(team).or_insert(0) += 1;
+                        ctx.match_state.scoreboard.add_points(player_id, self.config.capture_points);
+                        events.push(WorldEvent::ScoresChanged { scores: ctx.match_state.scoreboard.ranked() });
+                    }
+                }
+                _ => {}
+            }
+        }
+        events
+    }
+
+    fn on_tick(&mut self, ctx: &mut ModeContext) -> Vec<WorldEvent> {
+        let return_after = Duration::from_secs(self.config.return_secs);
+        let mut events = Vec::new();
+        for flag in &mut self.flags {
+            if let FlagState::Dropped { since, .. } = flag.state {
+                if ctx.now.saturating_sub(since) >= return_after {
+                    flag.state = FlagState::Home;
+                    events.push(flag.event(FlagEvent::Returned, None, flag.home));
+                }
+            }
+        }
+        events
+    }
+
+    fn on_hit(&mut self, ctx: &mut ModeContext, hit: &Hit) -> Vec<WorldEvent> {
+        let mut events = Vec::new();
+        if hit.killed {
+            events.extend(self.drop_flag(ctx, hit.victim_id, hit.position));
+        }
+        events.extend(score_kill(ctx, hit));
+        events
+    }
+
+    fn on_phase(&mut self, _ctx: &mut ModeContext, phase: MatchPhase) -> Vec<WorldEvent> {
+        if phase == MatchPhase::Ended {
+            return Vec::new();
+        }
+
+        self.captures.clear();
+        let mut events = Vec::new();
+        for flag in &mut self.flags {
+            if flag.state != FlagState::Home {
+                flag.state = FlagState::Home;
+                events.push(flag.event(FlagEvent::Returned, None, flag.home));
+            }
+        }
+        events
+    }
+
+    fn is_finished(&self, _match_state: &MatchState) -> bool {
+        self.config.capture_limit > 0 && self.captures.values().any(|captures| *captures >= self.config.capture_limit)
+    }
+
+    fn state(&self, players: &BTreeMap<usize, PeerPlayerInfo>) -> Vec<WorldEvent> {
+        self.flags
+            .iter()
+            .map(|flag| {
+                let (event, player_id) = match flag.state {
+                    FlagState::Home => (FlagEvent::Returned, None),
+                    FlagState::Carried { carrier_id } => (FlagEvent::Taken, Some(carrier_id)),
+                    FlagState::Dropped { .. } => (FlagEvent::Dropped, None),
+                };
+                flag.event(event, player_id, flag.position(players))
+            })
+            .collect()
+    }
+}
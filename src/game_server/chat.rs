@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatChannel {
+    /// everyone in the sender's room
+    Room,
+    /// a single player
+    Direct,
+    /// players on the sender's team
+    Team,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockedWordAction {
+    /// replace the word with `*`
+    #[default]
+    Mask,
+    /// drop the whole message
+    Drop,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChatConfig {
+    /// in characters, longer messages are rejected
+    pub max_length: usize,
+    /// words caught by the default filter, matched case insensitively
+    pub blocked_words: Vec<String>,
+    pub blocked_word_action: BlockedWordAction,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            max_length: 200,
+            blocked_words: Vec::new(),
+            blocked_word_action: BlockedWordAction::Mask,
+        }
+    }
+}
+
+pub enum ChatFilterResult {
+    /// deliver the (possibly rewritten) text
+    Deliver(String),
+    /// drop the message, with a reason for the logs
+    Block(&'static str),
+}
+
+/// Moderation hook every chat message passes through before it is delivered
+pub trait ChatFilter: Debug + Send {
+    fn filter(&self, sender_id: usize, text: &str) -> ChatFilterResult;
+}
+
+/// Masks or drops messages containing blocked words
+#[derive(Debug, Default)]
+pub struct WordListFilter {
+    blocked_words: HashSet<String>,
+    action: BlockedWordAction,
+}
+
+impl WordListFilter {
+    pub fn new(blocked_words: &[String], action: BlockedWordAction) -> Self {
+        Self {
+            blocked_words: blocked_words.iter().map(|word| word.to_lowercase()).collect(),
+            action,
+        }
+    }
+}
+
+impl ChatFilter for WordListFilter {
+    fn filter(&self, _sender_id: usize, text: &str) -> ChatFilterResult {
+        if self.blocked_words.is_empty() {
+            return ChatFilterResult::Deliver(text.to_string());
+        }
+
+        let mut filtered = String::with_capacity(text.len());
+        let mut word = String::new();
+        for c in text.chars().chain(std::iter::once(' ')) {
+            if c.is_alphanumeric() {
+                word.push(c);
+                continue;
+            }
+            if self.blocked_words.contains(&word.to_lowercase()) {
+                if self.action == BlockedWordAction::Drop {
+                    return ChatFilterResult::Block("blocked_word");
+                }
+                filtered.extend(word.chars().map(|_| '*'));
+            } else {
+                filtered.push_str(&word);
+            }
+            word.clear();
+            filtered.push(c);
+        }
+        // drop the separator appended above
+        filtered.pop();
+
+        ChatFilterResult::Deliver(filtered)
+    }
+}
+
+/// Length checks followed by the configured filter
+#[derive(Debug)]
+pub struct ChatModerator {
+    config: ChatConfig,
+    filter: Box<dyn ChatFilter>,
+}
+
+impl ChatModerator {
+    pub fn new(config: ChatConfig, filter: Box<dyn ChatFilter>) -> Self {
+        Self {
+            config,
+            filter,
+        }
+    }
+
+    /// returns the text to deliver, or why the message was rejected
+    pub fn moderate(&self, sender_id: usize, text: &str) -> Result<String, &'static str> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("empty");
+        }
+        if text.chars().count() > self.config.max_length {
+            return Err("too_long");
+        }
+
+        match self.filter.filter(sender_id, text) {
+            ChatFilterResult::Deliver(text) => Ok(text),
+            ChatFilterResult::Block(reason) => Err(reason),
+        }
+    }
+}
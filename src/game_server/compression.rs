@@ -0,0 +1,133 @@
+use std::io::{self, Read, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use actix_web::web::Bytes;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+/// set in the first byte of a frame whose payload is deflated
+pub const COMPRESSED_FLAG: u8 = 1;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CompressionConfig {
+    /// without it clients asking for compression still get the flag byte, but never a compressed payload
+    pub enabled: bool,
+    /// smaller payloads aren't worth compressing
+    pub threshold_bytes: usize,
+    /// deflate level from 0 (none) to 9 (smallest)
+    pub level: u32,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold_bytes: 512,
+            level: 6,
+        }
+    }
+}
+
+/// Server wide counters of the frames sent to clients that asked for compression
+#[derive(Debug, Default)]
+pub struct CompressionMetrics {
+    pub frames: AtomicUsize,
+    pub compressed_frames: AtomicUsize,
+    /// FlatBuffers payloads as they were built
+    pub raw_bytes: AtomicUsize,
+    /// what went out instead, flag bytes included
+    pub sent_bytes: AtomicUsize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompressionMetricsSnapshot {
+    pub frames: usize,
+    pub compressed_frames: usize,
+    pub raw_bytes: usize,
+    pub sent_bytes: usize,
+}
+
+impl CompressionMetrics {
+    pub fn snapshot(&self) -> CompressionMetricsSnapshot {
+        CompressionMetricsSnapshot {
+            frames: self.frames.load(Ordering::Relaxed),
+            compressed_frames: self.compressed_frames.load(Ordering::Relaxed),
+            raw_bytes: self.raw_bytes.load(Ordering::Relaxed),
+            sent_bytes: self.sent_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Frames the payloads for peers that asked for compression: a flag byte, then the payload, deflated if it is at
+/// least `threshold_bytes` long and got smaller
+#[derive(Debug, Clone)]
+pub struct FrameEncoder {
+    config: Arc<CompressionConfig>,
+    metrics: Arc<CompressionMetrics>,
+}
+
+impl FrameEncoder {
+    pub fn new(config: Arc<CompressionConfig>, metrics: Arc<CompressionMetrics>) -> Self {
+        Self { config, metrics }
+    }
+
+    pub fn encode(&self, payload: Vec<u8>) -> Bytes {
+        let frame = self.frame(&payload);
+        self.count(payload.len(), &frame);
+        frame
+    }
+
+    /// frames a payload without counting it, the peers count a shared frame as they send it
+    pub fn frame(&self, payload: &[u8]) -> Bytes {
+        if self.config.enabled && payload.len() >= self.config.threshold_bytes {
+            let compressed = deflate(payload, self.config.level);
+            if compressed.len() < payload.len() {
+                return Bytes::from([&[COMPRESSED_FLAG][..], &compressed].concat());
+            }
+        }
+        Bytes::from([&[0][..], payload].concat())
+    }
+
+    /// counts a frame built from a `payload_len` bytes payload as sent
+    pub fn count(&self, payload_len: usize, frame: &[u8]) {
+        if frame[0] & COMPRESSED_FLAG != 0 {
+            self.metrics.compressed_frames.fetch_add(1, Ordering::Relaxed);
+        }
+        self.metrics.frames.fetch_add(1, Ordering::Relaxed);
+        self.metrics.raw_bytes.fetch_add(payload_len, Ordering::Relaxed);
+        self.metrics.sent_bytes.fetch_add(frame.len(), Ordering::Relaxed);
+    }
+}
+
+/// A broadcast encoded once by the game server, every peer it goes to sends the same bytes
+#[derive(Debug, Clone)]
+pub struct SharedFrames {
+    /// the FlatBuffers payload, for peers that didn't ask for compression
+    pub raw: Bytes,
+    /// the payload framed by `FrameEncoder`, built when one of the peers asked for compression
+    pub compressed: Option<Bytes>,
+}
+
+/// the payload of a frame built by `FrameEncoder`
+pub fn decode_frame(frame: &[u8]) -> io::Result<Vec<u8>> {
+    let Some((flags, payload)) = frame.split_first() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "empty frame"));
+    };
+    if flags & COMPRESSED_FLAG == 0 {
+        return Ok(payload.to_vec());
+    }
+    let mut inflated = Vec::with_capacity(payload.len() * 4);
+    DeflateDecoder::new(payload).read_to_end(&mut inflated)?;
+    Ok(inflated)
+}
+
+fn deflate(payload: &[u8], level: u32) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::with_capacity(payload.len() / 2), Compression::new(level.min(9)));
+    // writing into a Vec can't fail
+    encoder.write_all(payload).expect("deflate into memory");
+    encoder.finish().expect("deflate into memory")
+}
@@ -0,0 +1,16 @@
+use crate::game_server::game_mode::{GameMode, GameModeKind};
+use crate::game_server::match_state::MatchState;
+
+/// Every kill is worth `killPoints`, the match ends when a player reaches the score limit
+#[derive(Debug, Default)]
+pub struct Deathmatch;
+
+impl GameMode for Deathmatch {
+    fn kind(&self) -> GameModeKind {
+        GameModeKind::Deathmatch
+    }
+
+    fn is_finished(&self, match_state: &MatchState) -> bool {
+        match_state.score_limit_reached()
+    }
+}
@@ -1,18 +1,24 @@
-use flatbuffers::{FlatBufferBuilder};
-use crate::game_schema_generated::gameplay_fbdata::{GameReponseEvent, GameReponseEventArgs, GameWorldUpdate, GameWorldUpdateArgs, PlayerControl, PlayerData, PlayerMoved, RemotePeerJoined, RemotePeerJoinedArgs, RemotePeerLeft, RemotePeerLeftArgs, RemotePeerPositionUpdate, RemotePeerPositionUpdateArgs, RequestMessages, ResponseMessage, root_as_game_request_event, Vec2};
+use std::time::Duration;
+use flatbuffers::{FlatBufferBuilder, InvalidFlatbuffer, UnionWIPOffset, WIPOffset};
+use crate::game_schema_generated::gameplay_fbdata::{ChatChannel as FBChatChannel, ChatMessage, ChatMessageArgs, ChatSend, ChatSendArgs, FlagEvent as FBFlagEvent, FlagUpdate, FlagUpdateArgs, GameReponseEvent, GameReponseEventArgs, GameRequestEvent, GameRequestEventArgs, GameWorldUpdate, GameWorldUpdateArgs, LobbyMember as FBLobbyMember, LobbyMemberArgs, LobbyReady, LobbyReadyArgs, LobbySetup, LobbySetupArgs, LobbyUpdate, LobbyUpdateArgs, MatchPhase as FBMatchPhase, MatchPhaseChanged, MatchPhaseChangedArgs, MatchSummary as FBMatchSummary, MatchSummaryArgs, PartyInvitation, PartyInvitationArgs, PartyInvite, PartyInviteArgs, PartyLeave, PartyLeaveArgs, PartyRespond, PartyRespondArgs, PartyUpdate, PartyUpdateArgs, PlayerKilled, PlayerKilledArgs, PlayerScore as FBPlayerScore, PlayerControl, PlayerData, PlayerMoved, PlayerMovedArgs, RemotePeerJoined, RemotePeerJoinedArgs, RemotePeerLeft, RemotePeerLeftArgs, RemotePeerPositionUpdate, RemotePeerPositionUpdateArgs, RequestMessages, ResponseMessage, root_as_game_request_event, ScoreboardUpdate, ScoreboardUpdateArgs, Vec2, WeaponFired, WeaponFiredArgs};
+use crate::game_server::chat::ChatChannel;
 use crate::game_server::game_world::PeerPlayerInfo;
+use crate::game_server::capture_the_flag::FlagEvent;
+use crate::game_server::lobby::LobbyMember;
+use crate::game_server::match_state::{MatchPhase, MatchSummary, PlayerScore};
+use crate::game_server::message_types::PeerPlayerData;
 use crate::game_server::peer::{ClientControls, ClientData, ClientPosition};
 
-pub fn read_gameplay_data(buf: &[u8]) -> ClientData {
-    let gameplay = root_as_game_request_event(buf).unwrap();
+pub fn read_gameplay_data(buf: &[u8]) -> Result<ClientData, InvalidFlatbuffer> {
+    let gameplay = root_as_game_request_event(buf)?;
     let event_type = gameplay.msg_type();
 
     if event_type == RequestMessages::PlayerMoved {
         if let Some(player_moved) =  gameplay.msg_as_player_moved() {
-            let player_controls = player_moved.player_controls().unwrap_or_else(|| &PlayerControl([0; 4]));
-            let player_position = player_moved.player_position().unwrap_or_else(|| &Vec2([0; 8]));
+            let player_controls = player_moved.player_controls().unwrap_or(&PlayerControl([0; 4]));
+            let player_position = player_moved.player_position().unwrap_or(&Vec2([0; 8]));
 
-            return ClientData::PlayerMoved {
+            return Ok(ClientData::PlayerMoved {
                 player_position: ClientPosition {
                     x: player_position.x(),
                     y: player_position.y(),
@@ -23,16 +29,79 @@ pub fn read_gameplay_data(buf: &[u8]) -> ClientData {
                     left: player_controls.left(),
                     right: player_controls.right(),
                 }
-            };
+            });
         }
     } else if event_type == RequestMessages::WeaponFired {
-        //
+        if let Some(weapon_fired) = gameplay.msg_as_weapon_fired() {
+            return Ok(ClientData::WeaponFired {
+                angle: weapon_fired.angle(),
+                power: weapon_fired.power(),
+            });
+        }
+    } else if event_type == RequestMessages::ChatSend {
+        if let Some(chat_send) = gameplay.msg_as_chat_send() {
+            return Ok(ClientData::ChatSend {
+                channel: from_fb_channel(chat_send.channel()),
+                target_player_id: chat_send.target_player_id() as usize,
+                text: chat_send.text().unwrap_or_default().to_string(),
+            });
+        }
+    } else if event_type == RequestMessages::LobbySetup {
+        if let Some(lobby_setup) = gameplay.msg_as_lobby_setup() {
+            return Ok(ClientData::LobbySetup {
+                name: lobby_setup.name().unwrap_or_default().to_string(),
+                skin: lobby_setup.skin().unwrap_or_default().to_string(),
+                team: Some(lobby_setup.team()).filter(|_| lobby_setup.has_team()),
+            });
+        }
+    } else if event_type == RequestMessages::LobbyReady {
+        if let Some(lobby_ready) = gameplay.msg_as_lobby_ready() {
+            return Ok(ClientData::LobbyReady {
+                ready: lobby_ready.ready(),
+            });
+        }
+    } else if event_type == RequestMessages::PartyInvite {
+        if let Some(party_invite) = gameplay.msg_as_party_invite() {
+            return Ok(ClientData::PartyInvite {
+                player_id: party_invite.player_id() as usize,
+            });
+        }
+    } else if event_type == RequestMessages::PartyRespond {
+        if let Some(party_respond) = gameplay.msg_as_party_respond() {
+            return Ok(ClientData::PartyRespond {
+                leader_id: party_respond.leader_id() as usize,
+                accept: party_respond.accept(),
+            });
+        }
+    } else if event_type == RequestMessages::PartyLeave {
+        return Ok(ClientData::PartyLeave);
     }
 
-    ClientData::Unknown
+    Ok(ClientData::Unknown)
 }
 
-pub fn create_peer_position_bytes(player_id: usize, player_position: ClientPosition) -> Vec<u8> {
+/// encodes a message for the client, `None` for messages that only concern the connection
+pub fn create_player_data_bytes(data: &PeerPlayerData) -> Option<Vec<u8>> {
+    let bytes = match data {
+        PeerPlayerData::RemotePeerJoined { player_id, team, player_position } => create_peer_joined_bytes(*player_id, *team, *player_position),
+        PeerPlayerData::RemotePeerLeft { player_id } => create_peer_left_bytes(*player_id),
+        PeerPlayerData::RemotePeerPositionUpdate { player_id, team, player_position } => create_peer_position_bytes(*player_id, *team, *player_position),
+        PeerPlayerData::WorldUpdate { map_id, game_mode, world_data } => create_world_update_bytes(map_id, game_mode, world_data.clone()),
+        PeerPlayerData::ChatMessage { sender_id, channel, text } => create_chat_message_bytes(*sender_id, *channel, text),
+        PeerPlayerData::MatchPhaseChanged { phase, duration } => create_match_phase_bytes(*phase, *duration),
+        PeerPlayerData::PlayerKilled { killer_id, victim_id, respawn_position } => create_player_killed_bytes(*killer_id, *victim_id, *respawn_position),
+        PeerPlayerData::ScoreboardUpdate { scores } => create_scoreboard_bytes(scores),
+        PeerPlayerData::MatchSummary { summary } => create_match_summary_bytes(summary),
+        PeerPlayerData::FlagUpdate { team, event, player_id, position } => create_flag_update_bytes(*team, *event, *player_id, *position),
+        PeerPlayerData::LobbyUpdate { player_id, members, countdown } => create_lobby_update_bytes(*player_id, members, *countdown),
+        PeerPlayerData::PartyInvitation { leader_id } => create_party_invitation_bytes(*leader_id),
+        PeerPlayerData::PartyUpdate { leader_id, members } => create_party_update_bytes(*leader_id, members),
+        PeerPlayerData::Kicked { .. } => return None,
+    };
+    Some(bytes)
+}
+
+pub fn create_peer_position_bytes(player_id: usize, team: u8, player_position: ClientPosition) -> Vec<u8> {
     let mut bldr = FlatBufferBuilder::new();
     let mut bytes: Vec<u8> = Vec::new();
 
@@ -46,7 +115,7 @@ pub fn create_peer_position_bytes(player_id: usize, player_position: ClientPosit
     // (Note how we call `bldr.create_string` to create the UTF-8 string
     // ergonomically.)
     let player_position = Vec2::new(player_position.x, player_position.y);
-    let player_data = PlayerData::new(player_id as u64, &player_position);
+    let player_data = PlayerData::new(player_id as u64, &player_position, team);
 
     let msg = RemotePeerPositionUpdate::create(&mut bldr, &RemotePeerPositionUpdateArgs {
         player_data: Option::from(&player_data)
@@ -108,7 +177,7 @@ pub fn create_peer_left_bytes(player_id: usize) -> Vec<u8> {
     bytes
 }
 
-pub fn create_peer_joined_bytes(player_id: usize, player_position: ClientPosition) -> Vec<u8> {
+pub fn create_peer_joined_bytes(player_id: usize, team: u8, player_position: ClientPosition) -> Vec<u8> {
     let mut bldr = FlatBufferBuilder::new();
     let mut bytes: Vec<u8> = Vec::new();
 
@@ -118,7 +187,7 @@ pub fn create_peer_joined_bytes(player_id: usize, player_position: ClientPositio
     // Reset the `FlatBufferBuilder` to a clean state.
     bldr.reset();
 
-    let player_data = PlayerData::new(player_id as u64, &Vec2::new(player_position.x, player_position.y));
+    let player_data = PlayerData::new(player_id as u64, &Vec2::new(player_position.x, player_position.y), team);
 
     let msg = RemotePeerJoined::create(&mut bldr, &RemotePeerJoinedArgs {
         player_data: Option::from(&player_data)
@@ -145,7 +214,7 @@ pub fn create_peer_joined_bytes(player_id: usize, player_position: ClientPositio
     bytes
 }
 
-pub fn create_world_update_bytes(world_data: Vec<PeerPlayerInfo>) -> Vec<u8> {
+pub fn create_world_update_bytes(map_id: &str, game_mode: &str, world_data: Vec<PeerPlayerInfo>) -> Vec<u8> {
     let mut bldr = FlatBufferBuilder::new();
     let mut bytes: Vec<u8> = Vec::new();
 
@@ -163,14 +232,17 @@ pub fn create_world_update_bytes(world_data: Vec<PeerPlayerInfo>) -> Vec<u8> {
 
     let player_data_list = world_data.iter().map(|data| {
         let player_position = Vec2::new(data.x, data.y);
-        let player_data = PlayerData::new(data.player_id as u64, &player_position);
-        player_data
+        PlayerData::new(data.player_id as u64, &player_position, data.team)
     }).collect::<Vec<PlayerData>>();
 
     let player_data_vec = bldr.create_vector(&player_data_list);
+    let map_id = bldr.create_string(map_id);
+    let game_mode = bldr.create_string(game_mode);
 
     let msg = GameWorldUpdate::create(&mut bldr, &GameWorldUpdateArgs {
-        player_data_list: Option::from(player_data_vec)
+        player_data_list: Option::from(player_data_vec),
+        map_id: Option::from(map_id),
+        game_mode: Option::from(game_mode)
     }).as_union_value();
 
     let args = GameReponseEventArgs {
@@ -193,3 +265,436 @@ pub fn create_world_update_bytes(world_data: Vec<PeerPlayerInfo>) -> Vec<u8> {
 
     bytes
 }
+
+pub fn create_chat_message_bytes(sender_id: usize, channel: ChatChannel, text: &str) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+    let mut bytes: Vec<u8> = Vec::new();
+
+    // Reset the `bytes` Vec to a clean state.
+    bytes.clear();
+
+    // Reset the `FlatBufferBuilder` to a clean state.
+    bldr.reset();
+
+    let text = bldr.create_string(text);
+    let channel = to_fb_channel(channel);
+
+    let msg = ChatMessage::create(&mut bldr, &ChatMessageArgs {
+        channel,
+        sender_player_id: sender_id as u64,
+        text: Option::from(text)
+    }).as_union_value();
+
+    let args = GameReponseEventArgs {
+        msg_type: ResponseMessage::ChatMessage,
+        msg: Option::from(msg)
+    };
+
+    // Call the `User::create` function with the `FlatBufferBuilder` and our
+    // UserArgs object, to serialize the data to the FlatBuffer. The returned
+    // value is an offset used to track the location of this serializaed data.
+    let user_offset = GameReponseEvent::create(&mut bldr, &args);
+
+    // Finish the write operation by calling the generated function
+    // `finish_user_buffer` with the `user_offset` created by `User::create`.
+    bldr.finish(user_offset, None);
+
+    // Copy the serialized FlatBuffers data to our own byte buffer.
+    let finished_data = bldr.finished_data();
+    bytes.extend_from_slice(finished_data);
+
+    bytes
+}
+
+pub fn create_match_phase_bytes(phase: MatchPhase, duration: Option<Duration>) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = MatchPhaseChanged::create(&mut bldr, &MatchPhaseChangedArgs {
+        phase: to_fb_phase(phase),
+        duration_ms: duration_to_ms(duration)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::MatchPhaseChanged, msg)
+}
+
+pub fn create_player_killed_bytes(killer_id: usize, victim_id: usize, respawn_position: ClientPosition) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let respawn_position = Vec2::new(respawn_position.x, respawn_position.y);
+    let msg = PlayerKilled::create(&mut bldr, &PlayerKilledArgs {
+        killer_player_id: killer_id as u64,
+        victim_player_id: victim_id as u64,
+        respawn_position: Option::from(&respawn_position)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::PlayerKilled, msg)
+}
+
+pub fn create_scoreboard_bytes(scores: &[PlayerScore]) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let scores = bldr.create_vector(&to_fb_scores(scores));
+    let msg = ScoreboardUpdate::create(&mut bldr, &ScoreboardUpdateArgs {
+        scores: Option::from(scores)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::ScoreboardUpdate, msg)
+}
+
+pub fn create_match_summary_bytes(summary: &MatchSummary) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let scores = bldr.create_vector(&to_fb_scores(&summary.scores));
+    let msg = FBMatchSummary::create(&mut bldr, &MatchSummaryArgs {
+        winner_player_id: summary.winner_id.unwrap_or(0) as u64,
+        winning_team: summary.winning_team.unwrap_or(0),
+        is_team_draw: summary.winning_team.is_none(),
+        duration_ms: duration_to_ms(Some(summary.duration)),
+        scores: Option::from(scores)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::MatchSummary, msg)
+}
+
+pub fn create_flag_update_bytes(team: u8, event: FlagEvent, player_id: Option<usize>, position: ClientPosition) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let position = Vec2::new(position.x, position.y);
+    let msg = FlagUpdate::create(&mut bldr, &FlagUpdateArgs {
+        team,
+        event: to_fb_flag_event(event),
+        player_id: player_id.unwrap_or(0) as u64,
+        position: Option::from(&position)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::FlagUpdate, msg)
+}
+
+pub fn create_lobby_update_bytes(player_id: usize, members: &[LobbyMember], countdown: Option<Duration>) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let members = members.iter().map(|member| {
+        let name = bldr.create_string(&member.name);
+        let skin = bldr.create_string(&member.skin);
+        FBLobbyMember::create(&mut bldr, &LobbyMemberArgs {
+            player_id: member.player_id as u64,
+            name: Option::from(name),
+            skin: Option::from(skin),
+            team: member.team.unwrap_or(0),
+            has_team: member.team.is_some(),
+            ready: member.ready
+        })
+    }).collect::<Vec<_>>();
+    let members = bldr.create_vector(&members);
+    let msg = LobbyUpdate::create(&mut bldr, &LobbyUpdateArgs {
+        player_id: player_id as u64,
+        members: Option::from(members),
+        countdown_ms: duration_to_ms(countdown)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::LobbyUpdate, msg)
+}
+
+/// decodes a message sent by the server, the counterpart of `create_player_data_bytes`
+pub fn create_party_invitation_bytes(leader_id: usize) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = PartyInvitation::create(&mut bldr, &PartyInvitationArgs {
+        leader_id: leader_id as u64
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::PartyInvitation, msg)
+}
+
+pub fn create_party_update_bytes(leader_id: usize, members: &[usize]) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let members = members.iter().map(|&member_id| member_id as u64).collect::<Vec<u64>>();
+    let members = bldr.create_vector(&members);
+    let msg = PartyUpdate::create(&mut bldr, &PartyUpdateArgs {
+        leader_id: leader_id as u64,
+        members: Option::from(members)
+    }).as_union_value();
+
+    finish_response(bldr, ResponseMessage::PartyUpdate, msg)
+}
+
+pub fn read_player_data(buf: &[u8]) -> Result<Option<PeerPlayerData>, InvalidFlatbuffer> {
+    let response = flatbuffers::root::<GameReponseEvent>(buf)?;
+    let position = |player_data: Option<&PlayerData>| {
+        player_data.map(|data| (
+            data.player_id() as usize,
+            data.team(),
+            ClientPosition {
+                x: data.player_position().x(),
+                y: data.player_position().y(),
+            },
+        ))
+    };
+
+    let data = match response.msg_type() {
+        ResponseMessage::RemotePeerJoined => response.msg_as_remote_peer_joined()
+            .and_then(|msg| position(msg.player_data()))
+            .map(|(player_id, team, player_position)| PeerPlayerData::RemotePeerJoined { player_id, team, player_position }),
+        ResponseMessage::RemotePeerLeft => response.msg_as_remote_peer_left()
+            .map(|msg| PeerPlayerData::RemotePeerLeft { player_id: msg.player_id() as usize }),
+        ResponseMessage::RemotePeerPositionUpdate => response.msg_as_remote_peer_position_update()
+            .and_then(|msg| position(msg.player_data()))
+            .map(|(player_id, team, player_position)| PeerPlayerData::RemotePeerPositionUpdate { player_id, team, player_position }),
+        ResponseMessage::GameWorldUpdate => response.msg_as_game_world_update().map(|msg| PeerPlayerData::WorldUpdate {
+            map_id: msg.map_id().unwrap_or_default().to_string(),
+            game_mode: msg.game_mode().unwrap_or_default().to_string(),
+            world_data: msg.player_data_list()
+                .map(|list| list.iter().map(|data| PeerPlayerInfo {
+                    player_id: data.player_id() as usize,
+                    team: data.team(),
+                    x: data.player_position().x(),
+                    y: data.player_position().y(),
+                }).collect())
+                .unwrap_or_default(),
+        }),
+        ResponseMessage::ChatMessage => response.msg_as_chat_message().map(|msg| PeerPlayerData::ChatMessage {
+            sender_id: msg.sender_player_id() as usize,
+            channel: from_fb_channel(msg.channel()),
+            text: msg.text().unwrap_or_default().to_string(),
+        }),
+        ResponseMessage::MatchPhaseChanged => response.msg_as_match_phase_changed().map(|msg| PeerPlayerData::MatchPhaseChanged {
+            phase: from_fb_phase(msg.phase()),
+            duration: Some(Duration::from_millis(msg.duration_ms() as u64)).filter(|duration| !duration.is_zero()),
+        }),
+        ResponseMessage::PlayerKilled => response.msg_as_player_killed().map(|msg| PeerPlayerData::PlayerKilled {
+            killer_id: msg.killer_player_id() as usize,
+            victim_id: msg.victim_player_id() as usize,
+            respawn_position: msg.respawn_position()
+                .map(|position| ClientPosition { x: position.x(), y: position.y() })
+                .unwrap_or(ClientPosition { x: 0.0, y: 0.0 }),
+        }),
+        ResponseMessage::ScoreboardUpdate => response.msg_as_scoreboard_update().map(|msg| PeerPlayerData::ScoreboardUpdate {
+            scores: msg.scores().map(from_fb_scores).unwrap_or_default(),
+        }),
+        ResponseMessage::MatchSummary => response.msg_as_match_summary().map(|msg| PeerPlayerData::MatchSummary {
+            summary: MatchSummary {
+                winner_id: Some(msg.winner_player_id() as usize).filter(|&id| id != 0),
+                winning_team: Some(msg.winning_team()).filter(|_| !msg.is_team_draw()),
+                duration: Duration::from_millis(msg.duration_ms() as u64),
+                scores: msg.scores().map(from_fb_scores).unwrap_or_default(),
+            },
+        }),
+        ResponseMessage::LobbyUpdate => response.msg_as_lobby_update().map(|msg| PeerPlayerData::LobbyUpdate {
+            player_id: msg.player_id() as usize,
+            members: msg.members()
+                .map(|members| members.iter().map(|member| LobbyMember {
+                    player_id: member.player_id() as usize,
+                    name: member.name().unwrap_or_default().to_string(),
+                    skin: member.skin().unwrap_or_default().to_string(),
+                    team: Some(member.team()).filter(|_| member.has_team()),
+                    ready: member.ready(),
+                }).collect())
+                .unwrap_or_default(),
+            countdown: Some(Duration::from_millis(msg.countdown_ms() as u64)).filter(|duration| !duration.is_zero()),
+        }),
+        ResponseMessage::FlagUpdate => response.msg_as_flag_update().map(|msg| PeerPlayerData::FlagUpdate {
+            team: msg.team(),
+            event: from_fb_flag_event(msg.event()),
+            player_id: Some(msg.player_id() as usize).filter(|&id| id != 0),
+            position: msg.position()
+                .map(|position| ClientPosition { x: position.x(), y: position.y() })
+                .unwrap_or(ClientPosition { x: 0.0, y: 0.0 }),
+        }),
+        ResponseMessage::PartyInvitation => response.msg_as_party_invitation().map(|msg| PeerPlayerData::PartyInvitation {
+            leader_id: msg.leader_id() as usize,
+        }),
+        ResponseMessage::PartyUpdate => response.msg_as_party_update().map(|msg| PeerPlayerData::PartyUpdate {
+            leader_id: msg.leader_id() as usize,
+            members: msg.members()
+                .map(|members| members.iter().map(|member_id| member_id as usize).collect())
+                .unwrap_or_default(),
+        }),
+        _ => None,
+    };
+    Ok(data)
+}
+
+pub fn create_player_moved_bytes(player_controls: ClientControls, player_position: ClientPosition) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let controls = PlayerControl::new(player_controls.up, player_controls.down, player_controls.left, player_controls.right);
+    let position = Vec2::new(player_position.x, player_position.y);
+    let msg = PlayerMoved::create(&mut bldr, &PlayerMovedArgs {
+        player_controls: Option::from(&controls),
+        player_position: Option::from(&position)
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::PlayerMoved, msg)
+}
+
+pub fn create_weapon_fired_bytes(angle: f32, power: f32) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = WeaponFired::create(&mut bldr, &WeaponFiredArgs {
+        angle,
+        power
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::WeaponFired, msg)
+}
+
+pub fn create_chat_send_bytes(channel: ChatChannel, target_player_id: usize, text: &str) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let text = bldr.create_string(text);
+    let channel = to_fb_channel(channel);
+    let msg = ChatSend::create(&mut bldr, &ChatSendArgs {
+        channel,
+        target_player_id: target_player_id as u64,
+        text: Option::from(text)
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::ChatSend, msg)
+}
+
+pub fn create_lobby_setup_bytes(name: &str, skin: &str, team: Option<u8>) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let name = bldr.create_string(name);
+    let skin = bldr.create_string(skin);
+    let msg = LobbySetup::create(&mut bldr, &LobbySetupArgs {
+        name: Option::from(name),
+        skin: Option::from(skin),
+        team: team.unwrap_or(0),
+        has_team: team.is_some()
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::LobbySetup, msg)
+}
+
+pub fn create_lobby_ready_bytes(ready: bool) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = LobbyReady::create(&mut bldr, &LobbyReadyArgs {
+        ready
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::LobbyReady, msg)
+}
+
+pub fn create_party_invite_bytes(player_id: usize) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = PartyInvite::create(&mut bldr, &PartyInviteArgs {
+        player_id: player_id as u64
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::PartyInvite, msg)
+}
+
+pub fn create_party_respond_bytes(leader_id: usize, accept: bool) -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = PartyRespond::create(&mut bldr, &PartyRespondArgs {
+        leader_id: leader_id as u64,
+        accept
+    }).as_union_value();
+
+    finish_request(bldr, RequestMessages::PartyRespond, msg)
+}
+
+pub fn create_party_leave_bytes() -> Vec<u8> {
+    let mut bldr = FlatBufferBuilder::new();
+
+    let msg = PartyLeave::create(&mut bldr, &PartyLeaveArgs {}).as_union_value();
+
+    finish_request(bldr, RequestMessages::PartyLeave, msg)
+}
+
+fn finish_response(mut bldr: FlatBufferBuilder, msg_type: ResponseMessage, msg: WIPOffset<UnionWIPOffset>) -> Vec<u8> {
+    let response = GameReponseEvent::create(&mut bldr, &GameReponseEventArgs {
+        msg_type,
+        msg: Option::from(msg)
+    });
+    bldr.finish(response, None);
+    bldr.finished_data().to_vec()
+}
+
+fn finish_request(mut bldr: FlatBufferBuilder, msg_type: RequestMessages, msg: WIPOffset<UnionWIPOffset>) -> Vec<u8> {
+    let request = GameRequestEvent::create(&mut bldr, &GameRequestEventArgs {
+        msg_type,
+        msg: Option::from(msg)
+    });
+    bldr.finish(request, None);
+    bldr.finished_data().to_vec()
+}
+
+fn to_fb_channel(channel: ChatChannel) -> FBChatChannel {
+    match channel {
+        ChatChannel::Room => FBChatChannel::Room,
+        ChatChannel::Direct => FBChatChannel::Direct,
+        ChatChannel::Team => FBChatChannel::Team,
+    }
+}
+
+/// unknown channels from newer clients fall back to the room
+fn from_fb_channel(channel: FBChatChannel) -> ChatChannel {
+    match channel {
+        FBChatChannel::Direct => ChatChannel::Direct,
+        FBChatChannel::Team => ChatChannel::Team,
+        _ => ChatChannel::Room,
+    }
+}
+
+fn to_fb_phase(phase: MatchPhase) -> FBMatchPhase {
+    match phase {
+        MatchPhase::Warmup => FBMatchPhase::Warmup,
+        MatchPhase::Live => FBMatchPhase::Live,
+        MatchPhase::Ended => FBMatchPhase::Ended,
+    }
+}
+
+fn from_fb_phase(phase: FBMatchPhase) -> MatchPhase {
+    match phase {
+        FBMatchPhase::Live => MatchPhase::Live,
+        FBMatchPhase::Ended => MatchPhase::Ended,
+        _ => MatchPhase::Warmup,
+    }
+}
+
+fn to_fb_flag_event(event: FlagEvent) -> FBFlagEvent {
+    match event {
+        FlagEvent::Taken => FBFlagEvent::Taken,
+        FlagEvent::Dropped => FBFlagEvent::Dropped,
+        FlagEvent::Returned => FBFlagEvent::Returned,
+        FlagEvent::Captured => FBFlagEvent::Captured,
+    }
+}
+
+fn from_fb_flag_event(event: FBFlagEvent) -> FlagEvent {
+    match event {
+        FBFlagEvent::Dropped => FlagEvent::Dropped,
+        FBFlagEvent::Returned => FlagEvent::Returned,
+        FBFlagEvent::Captured => FlagEvent::Captured,
+        _ => FlagEvent::Taken,
+    }
+}
+
+fn to_fb_scores(scores: &[PlayerScore]) -> Vec<FBPlayerScore> {
+    scores.iter()
+        .map(|score| FBPlayerScore::new(score.player_id as u64, score.kills, score.deaths, score.points, score.team))
+        .collect()
+}
+
+fn from_fb_scores(scores: flatbuffers::Vector<FBPlayerScore>) -> Vec<PlayerScore> {
+    scores.iter()
+        .map(|score| PlayerScore {
+            player_id: score.player_id() as usize,
+            team: score.team(),
+            kills: score.kills(),
+            deaths: score.deaths(),
+            points: score.points(),
+        })
+        .collect()
+}
+
+/// 0 stands for no time limit
+fn duration_to_ms(duration: Option<Duration>) -> u32 {
+    duration.map_or(0, |duration| duration.as_millis().min(u32::MAX as u128) as u32)
+}
@@ -0,0 +1,221 @@
+use std::{fs, io};
+use serde::Deserialize;
+use crate::game_server::movement_validator::WorldBounds;
+use crate::game_server::peer::ClientPosition;
+use crate::game_server::spawn::SpawnPoint;
+
+/// Axis aligned solid rectangle, `x` and `y` are the top left corner
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Obstacle {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Obstacle {
+    /// point of the rectangle closest to `position`
+    fn closest_point(&self, position: ClientPosition) -> ClientPosition {
+        ClientPosition {
+            x: position.x.clamp(self.x, self.x + self.width),
+            y: position.y.clamp(self.y, self.y + self.height),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MapFile {
+    id: String,
+    #[serde(default)]
+    bounds: WorldBounds,
+    #[serde(default = "default_player_radius")]
+    player_radius: f32,
+    #[serde(default)]
+    obstacles: Vec<Obstacle>,
+    #[serde(default)]
+    tile_size: f32,
+    /// rows of tiles starting at 0,0, `#` marks a solid tile
+    #[serde(default)]
+    tiles: Vec<String>,
+    #[serde(default)]
+    spawn_points: Vec<SpawnPoint>,
+}
+
+fn default_player_radius() -> f32 {
+    24.0
+}
+
+/// Static geometry of a game world
+#[derive(Debug, Clone)]
+pub struct GameMap {
+    pub id: String,
+    pub bounds: WorldBounds,
+    /// players are treated as circles when colliding with obstacles
+    pub player_radius: f32,
+    pub obstacles: Vec<Obstacle>,
+    pub spawn_points: Vec<SpawnPoint>,
+}
+
+impl Default for GameMap {
+    /// an empty map the size of the client stage
+    fn default() -> Self {
+        Self {
+            id: "default".to_string(),
+            bounds: WorldBounds::default(),
+            player_radius: default_player_radius(),
+            obstacles: Vec::new(),
+            spawn_points: vec![SpawnPoint { x: 0.0, y: 0.0, team: None }],
+        }
+    }
+}
+
+impl GameMap {
+    pub fn load(path: &str) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let map_file: MapFile = serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Self::from_map_file(map_file).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, e)))
+    }
+
+    /// parses a map in the format of the files in `maps/`
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let map_file: MapFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        Self::from_map_file(map_file)
+    }
+
+    fn from_map_file(map_file: MapFile) -> Result<Self, String> {
+        let mut obstacles = map_file.obstacles;
+        if !map_file.tiles.is_empty() {
+            if map_file.tile_size <= 0.0 {
+                return Err("tileSize must be positive when tiles are set".to_string());
+            }
+            obstacles.extend(tile_obstacles(&map_file.tiles, map_file.tile_size));
+        }
+
+        let map = Self {
+            id: map_file.id,
+            bounds: map_file.bounds,
+            player_radius: map_file.player_radius,
+            obstacles,
+            spawn_points: map_file.spawn_points,
+        };
+
+        if map.bounds.min_x >= map.bounds.max_x || map.bounds.min_y >= map.bounds.max_y {
+            return Err("bounds are empty".to_string());
+        }
+        if map.spawn_points.is_empty() {
+            return Err("at least one spawn point is required".to_string());
+        }
+        if let Some(spawn_point) = map.spawn_points.iter().find(|p| !map.bounds.contains(p.position()) || map.is_blocked(p.position())) {
+            return Err(format!("spawn point {},{} is outside the bounds or inside an obstacle", spawn_point.x, spawn_point.y));
+        }
+
+        Ok(map)
+    }
+
+    pub fn is_blocked(&self, position: ClientPosition) -> bool {
+        self.obstacles.iter().any(|obstacle| {
+            let closest = obstacle.closest_point(position);
+            let (dx, dy) = (position.x - closest.x, position.y - closest.y);
+            dx * dx + dy * dy < self.player_radius * self.player_radius
+        })
+    }
+
+    /// pushes a player out of the obstacles it overlaps, along the shortest way out
+    ///
+    /// Pushing out of one obstacle can push into a neighbouring one, so this repeats until the position is stable.
+    /// Returns `None` if no free position was found close by.
+    pub fn resolve_collisions(&self, position: ClientPosition) -> Option<ClientPosition> {
+        let mut resolved = position;
+        for _ in 0..MAX_RESOLVE_PASSES {
+            let pushed = self.bounds.clamp(self.push_out(resolved));
+            if pushed == resolved {
+                break;
+            }
+            resolved = pushed;
+        }
+
+        (!self.is_blocked(resolved)).then_some(resolved)
+    }
+
+    /// a single pass over the obstacles
+    fn push_out(&self, position: ClientPosition) -> ClientPosition {
+        // a little extra so rounding doesn't leave the player overlapping by a hair
+        let radius = self.player_radius + COLLISION_SKIN;
+        let mut resolved = position;
+
+        for obstacle in &self.obstacles {
+            let closest = obstacle.closest_point(resolved);
+            let (dx, dy) = (resolved.x - closest.x, resolved.y - closest.y);
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance >= self.player_radius {
+                continue;
+            }
+
+            if distance > 0.0 {
+                // centre is outside the rectangle, move it away from the closest point
+                resolved.x = closest.x + dx / distance * radius;
+                resolved.y = closest.y + dy / distance * radius;
+            } else {
+                // centre is inside the rectangle, leave through the nearest edge that isn't blocked by another obstacle
+                let mut exits = [
+                    (resolved.x - obstacle.x, ClientPosition { x: obstacle.x - radius, y: resolved.y }),
+                    (obstacle.x + obstacle.width - resolved.x, ClientPosition { x: obstacle.x + obstacle.width + radius, y: resolved.y }),
+                    (resolved.y - obstacle.y, ClientPosition { x: resolved.x, y: obstacle.y - radius }),
+                    (obstacle.y + obstacle.height - resolved.y, ClientPosition { x: resolved.x, y: obstacle.y + obstacle.height + radius }),
+                ];
+                exits.sort_by(|a, b| a.0.total_cmp(&b.0));
+                resolved = exits
+                    .iter()
+                    .map(|(_, exit)| *exit)
+                    .find(|exit| !self.is_blocked(*exit))
+                    .unwrap_or(exits[0].1);
+            }
+        }
+
+        resolved
+    }
+}
+
+/// upper limit of push out passes in `GameMap::resolve_collisions`
+const MAX_RESOLVE_PASSES: usize = 8;
+
+const COLLISION_SKIN: f32 = 0.01;
+
+/// solid tiles as obstacles, runs of adjacent tiles are merged into one rectangle so players can't get caught on the seams
+fn tile_obstacles(tiles: &[String], tile_size: f32) -> Vec<Obstacle> {
+    // horizontal runs of solid tiles as (row, first column, length)
+    let mut runs = Vec::new();
+    for (row, line) in tiles.iter().enumerate() {
+        let mut start = None;
+        for (column, tile) in line.chars().chain(std::iter::once('.')).enumerate() {
+            match (tile == '#', start) {
+                (true, None) => start = Some(column),
+                (false, Some(first)) => {
+                    runs.push((row, first, column - first));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // runs spanning the same columns in consecutive rows become one rectangle
+    let mut merged: Vec<(usize, usize, usize, usize)> = Vec::new();
+    for (row, first, length) in runs {
+        match merged.iter_mut().find(|(top, column, width, height)| *column == first && *width == length && top + height == row) {
+            Some((_, _, _, height)) => *height += 1,
+            None => merged.push((row, first, length, 1)),
+        }
+    }
+
+    merged
+        .into_iter()
+        .map(|(row, column, width, height)| Obstacle {
+            x: column as f32 * tile_size,
+            y: row as f32 * tile_size,
+            width: width as f32 * tile_size,
+            height: height as f32 * tile_size,
+        })
+        .collect()
+}
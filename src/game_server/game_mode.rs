@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use serde::Deserialize;
+use crate::game_server::capture_the_flag::CaptureTheFlag;
+use crate::game_server::deathmatch::Deathmatch;
+use crate::game_server::game_map::GameMap;
+use crate::game_server::game_world::{PeerPlayerInfo, WorldEvent};
+use crate::game_server::match_state::{MatchConfig, MatchPhase, MatchState};
+use crate::game_server::peer::ClientPosition;
+use crate::game_server::simulation::SimTime;
+use crate::game_server::team::TeamConfig;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GameModeKind {
+    /// every kill scores, the first player to the score limit wins
+    #[default]
+    Deathmatch,
+    /// teams score by bringing the enemy flag to their own
+    CaptureTheFlag,
+}
+
+impl GameModeKind {
+    /// sent to clients in `GameWorldUpdate`
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameModeKind::Deathmatch => "deathmatch",
+            GameModeKind::CaptureTheFlag => "captureTheFlag",
+        }
+    }
+
+    pub fn create(&self, config: &MatchConfig, map: &GameMap, teams: &TeamConfig) -> Box<dyn GameMode> {
+        match self {
+            GameModeKind::Deathmatch => Box::new(Deathmatch),
+            GameModeKind::CaptureTheFlag => Box::new(CaptureTheFlag::new(config.capture_the_flag.clone(), map, teams.count)),
+        }
+    }
+}
+
+/// What the world lets a game mode see and change
+pub struct ModeContext<'a> {
+    pub now: SimTime,
+    pub match_state: &'a mut MatchState,
+    pub players: &'a BTreeMap<usize, PeerPlayerInfo>,
+}
+
+impl ModeContext<'_> {
+    pub fn player_position(&self, player_id: usize) -> Option<ClientPosition> {
+        self.players.get(&player_id).map(|data| ClientPosition { x: data.x, y: data.y })
+    }
+}
+
+/// A shot that hit a player
+#[derive(Debug, Clone, Copy)]
+pub struct Hit {
+    pub attacker_id: usize,
+    pub victim_id: usize,
+    /// where the victim was hit, a killed victim has already respawned elsewhere
+    pub position: ClientPosition,
+    pub killed: bool,
+}
+
+/// Rules of a match. `GameWorld` handles movement, hitscan, health and respawns, and calls these hooks so the mode
+/// can score and keep its own state. Events a hook returns are sent out after the world's own.
+pub trait GameMode: Debug {
+    fn kind(&self) -> GameModeKind;
+
+    /// after the player is placed in the world
+    fn on_join(&mut self, _ctx: &mut ModeContext, _player_id: usize) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+
+    /// before the player is removed from the world
+    fn on_leave(&mut self, _ctx: &mut ModeContext, _player_id: usize) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+
+    /// after a move passed validation, `position` is where the player ended up
+    fn on_input(&mut self, _ctx: &mut ModeContext, _player_id: usize, _position: ClientPosition) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+
+    /// on every match tick
+    fn on_tick(&mut self, _ctx: &mut ModeContext) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+
+    fn on_hit(&mut self, ctx: &mut ModeContext, hit: &Hit) -> Vec<WorldEvent> {
+        score_kill(ctx, hit).into_iter().collect()
+    }
+
+    /// when the match enters `phase`, scores have already been reset
+    fn on_phase(&mut self, _ctx: &mut ModeContext, _phase: MatchPhase) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+
+    /// ends a live match before its time limit
+    fn is_finished(&self, match_state: &MatchState) -> bool;
+
+    /// the mode's current state as events, for peers joining mid-match
+    fn state(&self, _players: &BTreeMap<usize, PeerPlayerInfo>) -> Vec<WorldEvent> {
+        Vec::new()
+    }
+}
+
+/// scores a kill during a live match
+pub fn score_kill(ctx: &mut ModeContext, hit: &Hit) -> Option<WorldEvent> {
+    if !hit.killed || ctx.match_state.phase() != MatchPhase::Live {
+        return None;
+    }
+    let kill_points = ctx.match_state.config.kill_points;
+    ctx.match_state.scoreboard.record_kill(hit.attacker_id, hit.victim_id, kill_points);
+    Some(WorldEvent::ScoresChanged { scores: ctx.match_state.scoreboard.ranked() })
+}
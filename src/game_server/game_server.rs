@@ -1,38 +1,699 @@
-use std::collections::{HashMap};
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use actix::prelude::*;
-use rand::{rngs::ThreadRng, Rng};
-use crate::game_server::game_world::GameWorld;
-use crate::game_server::message_types::{Connect, Disconnect, PeerPlayerData, PeerPlayerPositionUpdate};
-use crate::game_server::peer::ClientPosition;
+use actix_web::web::Bytes;
+use rand::Rng;
+use tracing::{debug, info, trace, warn};
+use crate::game_server::bot::{Bot, BotBehaviour, BotConfig};
+use crate::game_server::chat::{ChatChannel, ChatModerator};
+use crate::game_server::compression::{FrameEncoder, SharedFrames};
+use crate::game_server::flatbuffers_utils::create_player_data_bytes;
+use crate::game_server::game_world::{GameWorld, WorldCommand, WorldEvent, WorldSettings};
+use crate::game_server::lobby::{Lobby, LobbyConfig};
+use crate::game_server::match_state::MatchSummary;
+use crate::game_server::message_types::{Connect, Disconnect, GetRoomInfo, KickPlayer, PeerAddr, PeerBroadcast, PeerChatSend, PeerLobbyReady, PeerLobbySetup, PeerPartyInvite, PeerPartyLeave, PeerPartyRespond, PeerPlayerData, PeerPlayerPositionUpdate, PeerRole, PeerSession, PeerWeaponFired, RoomInfo, UpdateRoomSettings};
+use crate::game_server::party::{Party, PartyConfig, PartyError, PartyInvites, PartyRegistry};
+use crate::game_server::peer::{ClientData, ClientPosition};
+use crate::game_server::profile::{unix_ms, MatchRecord, MatchResult, Profile, ProfileStore, RatingChange};
+use crate::game_server::rating::RatingConfig;
+use crate::game_server::replay::{ReplayConfig, ReplayRecorder};
+use crate::game_server::room_registry::RoomLoad;
+use crate::game_server::simulation::{Clock, SimRng, SimTime};
+
+/// how often match timers are checked
+const MATCH_TICK: Duration = Duration::from_millis(100);
+/// most players a room owner can allow
+const MAX_ROOM_PLAYERS: u8 = 16;
 
 #[derive(Debug)]
 pub struct GameServer {
-    peer_addr_map: HashMap<usize, Recipient<PeerPlayerData>>,
-    rng: ThreadRng,
+    peer_addr_map: HashMap<usize, PeerAddr>,
+    spectator_addr_map: HashMap<usize, PeerAddr>,
+    rng: SimRng,
+    clock: Box<dyn Clock>,
     players_online_count: Arc<AtomicUsize>,
-    game_world: GameWorld
+    game_world: GameWorld,
+    chat: ChatModerator,
+    replay_config: ReplayConfig,
+    // recording of the current match, if enabled and a player is in the room
+    recorder: Option<ReplayRecorder>,
+    bot_config: BotConfig,
+    // bots taking up player slots, keyed by their player id
+    bots: BTreeMap<usize, Bot>,
+    lobby: Lobby,
+    // players wait in the lobby until the match starts, then newcomers join the world directly
+    lobby_open: bool,
+    // shared by all rooms of the server, a room started on its own keeps its own
+    parties: Arc<PartyRegistry>,
+    invites: PartyInvites,
+    // how the players are named in parties, their profile id or a guest key
+    party_keys: HashMap<usize, String>,
+    profile_store: Option<Arc<dyn ProfileStore>>,
+    ratings: RatingConfig,
+    // profiles of the players who joined authenticated
+    profiles: HashMap<usize, Profile>,
+    // private rooms stop once nobody has been connected for this long
+    idle_timeout: Option<Duration>,
+    idle_since: Option<SimTime>,
+    // deflates broadcasts once for the peers that asked for compression
+    frame_encoder: Option<FrameEncoder>,
+    // where a public room tells the matchmaker how full it is
+    load: Option<Arc<Mutex<RoomLoad>>>
 }
 
 impl GameServer {
-    pub fn new(players_online_count: Arc<AtomicUsize>) -> GameServer {
+    /// every id and room seed is drawn from `rng`, and the world only sees time through `clock`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(players_online_count: Arc<AtomicUsize>, world_settings: WorldSettings, chat: ChatModerator, replay_config: ReplayConfig, bot_config: BotConfig, lobby_config: LobbyConfig, party_config: PartyConfig, mut rng: SimRng, clock: Box<dyn Clock>) -> GameServer {
+        let game_world = GameWorld::new(rng.gen::<usize>(), rng.gen::<u64>(), &world_settings);
         Self {
             peer_addr_map: Default::default(),
-            rng: rand::thread_rng(),
+            spectator_addr_map: Default::default(),
+            rng,
+            clock,
             players_online_count,
-            game_world: Default::default()
+            game_world,
+            chat,
+            replay_config,
+            recorder: None,
+            bot_config,
+            bots: BTreeMap::new(),
+            lobby_open: lobby_config.enabled,
+            lobby: Lobby::new(lobby_config),
+            invites: PartyInvites::new(&party_config),
+            parties: Arc::new(PartyRegistry::new(party_config)),
+            party_keys: HashMap::new(),
+            profile_store: None,
+            ratings: RatingConfig::default(),
+            profiles: HashMap::new(),
+            idle_timeout: None,
+            idle_since: None,
+            frame_encoder: None,
+            load: None
+        }
+    }
+
+    /// stops the room once no player or spectator has been connected for `timeout`
+    pub fn close_when_idle(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// loads the profiles of authenticated players and keeps their lifetime stats and ratings
+    pub fn with_profile_store(mut self, profile_store: Arc<dyn ProfileStore>, ratings: RatingConfig) -> Self {
+        self.profile_store = Some(profile_store);
+        self.ratings = ratings;
+        self
+    }
+
+    /// keeps parties in `parties`, so they can follow their members into other rooms
+    pub fn with_parties(mut self, parties: Arc<PartyRegistry>) -> Self {
+        self.parties = parties;
+        self
+    }
+
+    /// compresses broadcasts for the peers that asked for it, otherwise every such peer deflates its own copy
+    pub fn with_frame_encoder(mut self, frame_encoder: FrameEncoder) -> Self {
+        self.frame_encoder = Some(frame_encoder);
+        self
+    }
+
+    /// room size other than the default, up to `MAX_ROOM_PLAYERS`
+    pub fn with_max_players(mut self, max_players: u8) -> Self {
+        self.game_world.set_max_players(max_players.clamp(1, MAX_ROOM_PLAYERS));
+        self
+    }
+
+    /// keeps `load` up to date as players join and leave
+    pub fn reporting_load(mut self, load: Arc<Mutex<RoomLoad>>) -> Self {
+        self.load = Some(load);
+        self.report_load();
+        self
+    }
+
+    fn report_load(&self) {
+        let Some(load) = &self.load else {
+            return;
+        };
+        let players = self.peer_addr_map.len();
+        let total = self.peer_addr_map
+            .keys()
+            .map(|id| self.profiles.get(id).map_or(self.ratings.initial, |profile| self.ratings.rating_of(profile)))
+            .sum::<f64>();
+        *load.lock().unwrap() = RoomLoad {
+            players,
+            max_players: self.game_world.max_players(),
+            rating: if players == 0 { 0.0 } else { total / players as f64 },
+        };
+    }
+
+    /// random id that no player, spectator or bot in this room has
+    fn next_player_id(&mut self) -> usize {
+        loop {
+            let id = self.rng.gen::<usize>();
+            if !self.peer_addr_map.contains_key(&id) && !self.spectator_addr_map.contains_key(&id) && !self.bots.contains_key(&id) {
+                return id;
+            }
+        }
+    }
+
+    fn check_idle(&mut self, ctx: &mut Context<Self>) {
+        let Some(timeout) = self.idle_timeout else {
+            return;
+        };
+        if !self.peer_addr_map.is_empty() || !self.spectator_addr_map.is_empty() {
+            self.idle_since = None;
+            return;
+        }
+
+        let now = self.clock.now();
+        let idle_since = *self.idle_since.get_or_insert(now);
+        if now.saturating_sub(idle_since) >= timeout {
+            info!(event = "room_closed", room_id = self.game_world.room_id, "closing idle room");
+            ctx.stop();
+        }
+    }
+
+    pub fn send_position_to_other_players(&mut self, data: PeerPlayerData, skip_id: Option<usize>) {
+        self.record_output(None, &data);
+
+        let skip_id_value = skip_id.unwrap_or(0);
+        // spectators see everything, including the player's own updates
+        let recipients = self.peer_addr_map
+            .iter()
+            .filter(|(id, _)| **id != skip_id_value)
+            .map(|(_, addr)| addr)
+            .chain(self.spectator_addr_map.values())
+            .collect::<Vec<&PeerAddr>>();
+        if recipients.is_empty() {
+            return;
+        }
+        let Some(raw) = create_player_data_bytes(&data) else {
+            return;
+        };
+
+        // encoded and deflated once, every peer sends the same bytes
+        let compressed = match &self.frame_encoder {
+            Some(encoder) if recipients.iter().any(|addr| addr.compress) => Some(encoder.frame(&raw)),
+            _ => None,
+        };
+        let frames = SharedFrames { raw: Bytes::from(raw), compressed };
+        for addr in recipients {
+            addr.broadcast.do_send(PeerBroadcast { data: data.clone(), frames: frames.clone() });
+        }
+    }
+
+    /// steps the world at the current clock time and sends out what happened
+    fn apply(&mut self, command: WorldCommand) {
+        let room_id = self.game_world.room_id;
+        for event in self.game_world.step(self.clock.now(), command) {
+            match event {
+                WorldEvent::Joined { player_id, team, position } => {
+                    info!(
+                        event = "join",
+                        player_id,
+                        room_id,
+                        team,
+                        map_id = self.game_world.map_id(),
+                        x = position.x,
+                        y = position.y,
+                        "player joined"
+                    );
+
+                    // send message to other users
+                    self.send_position_to_other_players(PeerPlayerData::RemotePeerJoined {
+                        player_id,
+                        team,
+                        player_position: position
+                    }, Option::from(player_id));
+                }
+                WorldEvent::RoomFull { player_id } => {
+                    // a connection outside the world would still get broadcasts and chat, its disconnect cleans up
+                    warn!(event = "room_full", player_id, room_id, "room is full, player is not placed in the world");
+                    self.send_to_player(player_id, PeerPlayerData::Kicked { reason: "room_full" });
+                }
+                WorldEvent::TeamChanged { player_id, team, position } => {
+                    info!(event = "team_change", player_id, room_id, team, "player switched teams");
+
+                    // the player's own client learns the new team too
+                    self.send_position_to_other_players(PeerPlayerData::RemotePeerPositionUpdate {
+                        player_id,
+                        team,
+                        player_position: position
+                    }, None);
+                }
+                WorldEvent::Left { player_id } => {
+                    info!(event = "leave", player_id, room_id, "player left");
+
+                    // send message to other users
+                    self.send_position_to_other_players(PeerPlayerData::RemotePeerLeft {
+                        player_id
+                    }, None);
+                }
+                WorldEvent::Moved { player_id, position, flags, suspicion } => {
+                    if !flags.is_empty() {
+                        warn!(
+                            event = "movement_violation",
+                            player_id,
+                            room_id,
+                            ?flags,
+                            suspicion,
+                            "rejected part of a position update"
+                        );
+                    }
+
+                    // the mover is not corrected, the client treats updates with its own id as a new opponent
+                    let team = self.game_world.player_team(player_id).unwrap_or_default();
+                    self.send_position_to_other_players(PeerPlayerData::RemotePeerPositionUpdate {
+                        player_position: position,
+                        player_id,
+                        team,
+                    }, Option::from(player_id));
+                }
+                WorldEvent::Kicked { player_id, reason } => {
+                    warn!(event = "movement_violation", player_id, room_id, "suspicion threshold reached");
+                    if self.bots.contains_key(&player_id) {
+                        self.remove_bot(player_id);
+                    } else {
+                        self.send_to_player(player_id, PeerPlayerData::Kicked { reason });
+                    }
+                }
+                WorldEvent::PhaseChanged { phase, duration } => {
+                    info!(event = "match_phase", room_id, ?phase, duration_ms = duration.map(|duration| duration.as_millis() as u64), "match phase changed");
+                    self.send_position_to_other_players(PeerPlayerData::MatchPhaseChanged { phase, duration }, None);
+                }
+                WorldEvent::Killed { killer_id, victim_id, respawn_position } => {
+                    info!(event = "kill", room_id, killer_id, victim_id, "player killed");
+                    self.send_position_to_other_players(PeerPlayerData::PlayerKilled {
+                        killer_id,
+                        victim_id,
+                        respawn_position
+                    }, None);
+                }
+                WorldEvent::ScoresChanged { scores } => {
+                    self.send_position_to_other_players(PeerPlayerData::ScoreboardUpdate { scores }, None);
+                }
+                WorldEvent::MatchEnded { summary } => {
+                    info!(
+                        event = "match_ended",
+                        room_id,
+                        winner_id = summary.winner_id,
+                        winning_team = summary.winning_team,
+                        duration_ms = summary.duration.as_millis() as u64,
+                        "match ended"
+                    );
+                    self.record_match(&summary);
+                    self.send_position_to_other_players(PeerPlayerData::MatchSummary { summary }, None);
+                }
+                WorldEvent::FlagChanged { team, event, player_id, position } => {
+                    info!(event = "flag", room_id, team, flag_event = ?event, player_id, "flag changed");
+                    self.send_position_to_other_players(PeerPlayerData::FlagUpdate { team, event, player_id, position }, None);
+                }
+            }
         }
     }
 
-    pub fn send_position_to_other_players(&self, data: PeerPlayerData, skip_id: Option<usize>) {
-        let skip_id_value = skip_id.unwrap_or_else(|| 0);
-        for id in self.peer_addr_map.keys() {
-            if *id != skip_id_value {
-                if let Some(addr) = self.peer_addr_map.get(id) {
-                    addr.do_send(data.clone());
+    /// stores the match, adds it to the lifetime stats of every player with a profile and rates them against each other
+    ///
+    /// The profiles kept for the room change right away, the store is written on a blocking thread so the room doesn't
+    /// wait for the disk.
+    fn record_match(&mut self, summary: &MatchSummary) {
+        let Some(store) = self.profile_store.clone() else {
+            return;
+        };
+        let results = summary.scores
+            .iter()
+            .filter_map(|score| {
+                let profile = self.profiles.get(&score.player_id)?;
+                Some((score.player_id, MatchResult::new(&profile.id, score, summary)))
+            })
+            .collect::<Vec<(usize, MatchResult)>>();
+        if results.is_empty() {
+            return;
+        }
+
+        let game_mode = self.game_world.game_mode().name().to_string();
+        let ended_at_ms = unix_ms();
+        let rated = results
+            .iter()
+            .map(|(player_id, result)| (self.ratings.rating_of(&self.profiles[player_id]), result))
+            .collect::<Vec<(f64, &MatchResult)>>();
+        let changes = self.ratings.changes(&rated);
+
+        let mut updates = Vec::new();
+        for (index, (player_id, result)) in results.iter().enumerate() {
+            let update = ProfileUpdate {
+                player_id: *player_id,
+                result: result.clone(),
+                rating_change: changes.get(index).copied(),
+                ended_at_ms,
+                game_mode: game_mode.clone(),
+                ratings: self.ratings.clone(),
+            };
+            if let Some(profile) = self.profiles.get_mut(player_id) {
+                update.apply(profile);
+            }
+            updates.push(update);
+        }
+
+        let record = MatchRecord {
+            game_mode,
+            ended_at_ms,
+            duration_ms: summary.duration.as_millis() as u64,
+            results: results.into_iter().map(|(_, result)| result).collect(),
+        };
+        let room_id = self.game_world.room_id;
+        actix_web::rt::task::spawn_blocking(move || {
+            for update in &updates {
+                match store.update(&update.result.profile_id, &mut |profile| update.apply(profile)) {
+                    Ok(Some(_)) => {}
+                    Ok(None) => warn!(player_id = update.player_id, profile_id = %update.result.profile_id, "profile disappeared, stats not recorded"),
+                    Err(error) => warn!(player_id = update.player_id, profile_id = %update.result.profile_id, %error, "could not record stats"),
                 }
             }
+            if let Err(error) = store.record_match(&record) {
+                warn!(room_id, %error, "could not store match results");
+            }
+        });
+    }
+
+    /// current phase, scores and game mode state, for peers joining mid-match
+    fn send_match_state(&self, recipient: &PeerAddr) {
+        recipient.do_send(PeerPlayerData::MatchPhaseChanged {
+            phase: self.game_world.match_phase(),
+            duration: self.game_world.match_remaining(self.clock.now())
+        });
+        recipient.do_send(PeerPlayerData::ScoreboardUpdate {
+            scores: self.game_world.scores()
+        });
+        for event in self.game_world.mode_state() {
+            if let WorldEvent::FlagChanged { team, event, player_id, position } = event {
+                recipient.do_send(PeerPlayerData::FlagUpdate { team, event, player_id, position });
+            }
+        }
+    }
+
+    pub fn send_to_player(&mut self, player_id: usize, data: PeerPlayerData) {
+        if !self.peer_addr_map.contains_key(&player_id) {
+            return;
+        }
+        self.record_output(Some(player_id), &data);
+        if let Some(addr) = self.peer_addr_map.get(&player_id) {
+            addr.do_send(data);
+        }
+    }
+
+    /// sends to the players of one team, spectators and bots are skipped
+    pub fn send_to_team(&mut self, team: u8, data: PeerPlayerData) {
+        let members = self.game_world
+            .players()
+            .filter(|info| info.team == team)
+            .map(|info| info.player_id)
+            .collect::<Vec<usize>>();
+        for player_id in members {
+            self.send_to_player(player_id, data.clone());
+        }
+    }
+
+    /// places a player in the world and sends them the world and match state
+    fn enter_world(&mut self, player_id: usize, team: Option<u8>) {
+        self.start_recording();
+        self.make_room_for_human();
+        let party = self.party_of_player(player_id)
+            .map(|party| self.party_ids(&party).into_iter().filter(|&id| id != player_id).collect())
+            .unwrap_or_default();
+        self.apply(WorldCommand::Join { player_id, team, party });
+
+        // send world update to current peer
+        let world_data = self.game_world.get_world_update(player_id);
+        self.send_to_player(player_id, PeerPlayerData::WorldUpdate {
+            map_id: self.game_world.map_id().to_string(),
+            game_mode: self.game_world.game_mode().name().to_string(),
+            world_data
+        });
+        if let Some(addr) = self.peer_addr_map.get(&player_id) {
+            self.send_match_state(addr);
+        }
+        self.balance_bots();
+    }
+
+    /// sends every lobby member the current members and countdown
+    fn send_lobby_update(&mut self) {
+        let members = self.lobby.members();
+        let countdown = self.lobby.remaining(self.clock.now());
+        for member in &members {
+            self.send_to_player(member.player_id, PeerPlayerData::LobbyUpdate {
+                player_id: member.player_id,
+                members: members.clone(),
+                countdown
+            });
+        }
+    }
+
+    /// the stored profile of an authenticated player, who is treated as anonymous if it can't be loaded
+    fn load_profile(&mut self, player_id: usize, profile_id: Option<&str>) -> Option<Profile> {
+        let profile_id = profile_id?;
+        let store = self.profile_store.as_ref()?;
+        let profile = match store.load(profile_id) {
+            Ok(Some(profile)) => profile,
+            Ok(None) => {
+                warn!(player_id, profile_id, "unknown profile, joining anonymously");
+                return None;
+            }
+            Err(error) => {
+                warn!(player_id, profile_id, %error, "could not load profile, joining anonymously");
+                return None;
+            }
+        };
+        info!(event = "profile_loaded", player_id, profile_id, room_id = self.game_world.room_id, "profile loaded");
+        self.profiles.insert(player_id, profile.clone());
+        Some(profile)
+    }
+
+    /// mean rating of the players with a profile, `None` without any
+    fn average_rating(&self) -> Option<f64> {
+        if self.profiles.is_empty() {
+            return None;
+        }
+        let total = self.profiles.values().map(|profile| self.ratings.rating_of(profile)).sum::<f64>();
+        Some(total / self.profiles.len() as f64)
+    }
+
+    /// the party of a player in this room
+    fn party_of_player(&self, player_id: usize) -> Option<Party> {
+        self.parties.party_of(self.party_keys.get(&player_id)?)
+    }
+
+    /// ids of the party members in this room, leader first when they are here
+    fn party_ids(&self, party: &Party) -> Vec<usize> {
+        party.members
+            .iter()
+            .filter_map(|member| self.party_keys.iter().find(|(_, key)| *key == member).map(|(&id, _)| id))
+            .collect()
+    }
+
+    /// sends the members in this room the party, or an empty one when it was disbanded
+    fn send_party_update(&mut self, party: &Party) {
+        let ids = self.party_ids(party);
+        let Some(&leader_id) = ids.first() else {
+            return;
+        };
+        let members = if party.members.len() > 1 { ids.clone() } else { Vec::new() };
+        for &member_id in &ids {
+            self.send_to_player(member_id, PeerPlayerData::PartyUpdate {
+                leader_id,
+                members: members.clone()
+            });
+        }
+    }
+
+    /// starts the match once everyone in the lobby is ready or the countdown ran out
+    fn check_lobby(&mut self) {
+        if !self.lobby_open || !self.lobby.should_start(self.clock.now()) {
+            return;
+        }
+
+        let mut members = self.lobby.start();
+        // party leaders go first, so their team is the one the party joins
+        members.sort_by_key(|member| {
+            self.party_of_player(member.player_id).is_some_and(|party| self.party_keys.get(&member.player_id) != Some(&party.leader))
+        });
+        info!(event = "lobby_start", room_id = self.game_world.room_id, players = members.len(), ready = members.iter().filter(|member| member.ready).count(), "match starting");
+        self.lobby_open = false;
+        for member in members {
+            self.enter_world(member.player_id, member.team);
+        }
+    }
+
+    /// tops the room up with bots while a human is in it, and removes them once the last human left
+    fn balance_bots(&mut self) {
+        if !self.bot_config.enabled {
+            return;
+        }
+
+        let humans = self.game_world.player_count() - self.bots.len();
+        if humans == 0 {
+            let bot_ids = self.bots.keys().copied().collect::<Vec<usize>>();
+            for bot_id in bot_ids {
+                self.remove_bot(bot_id);
+            }
+            return;
+        }
+
+        // the room owner lowered the player limit
+        while self.game_world.player_count() > self.game_world.max_players() {
+            let Some(&bot_id) = self.bots.keys().next() else {
+                break;
+            };
+            self.remove_bot(bot_id);
+        }
+
+        while !self.game_world.is_full() {
+            let bot_id = self.next_player_id();
+            let behaviour = match self.bot_config.behaviours.len() {
+                0 => BotBehaviour::Wander,
+                count => self.bot_config.behaviours[self.rng.gen_range(0..count)],
+            };
+
+            self.bots.insert(bot_id, Bot::new(bot_id, behaviour));
+            self.apply(WorldCommand::Join { player_id: bot_id, team: None, party: Vec::new() });
+            if self.game_world.player_position(bot_id).is_none() {
+                self.bots.remove(&bot_id);
+                return;
+            }
+            info!(event = "bot_joined", player_id = bot_id, room_id = self.game_world.room_id, ?behaviour, "bot took an empty slot");
+        }
+    }
+
+    /// frees a slot for a human joining a room that bots filled up
+    fn make_room_for_human(&mut self) {
+        if !self.game_world.is_full() {
+            return;
+        }
+        if let Some(&bot_id) = self.bots.keys().next() {
+            self.remove_bot(bot_id);
+        }
+    }
+
+    fn remove_bot(&mut self, bot_id: usize) {
+        if self.bots.remove(&bot_id).is_some() {
+            info!(event = "bot_left", player_id = bot_id, room_id = self.game_world.room_id, "bot removed");
+            self.apply(WorldCommand::Leave { player_id: bot_id });
+        }
+    }
+
+    /// lets every bot decide on a move, which then goes through the same validation as player input
+    fn tick_bots(&mut self) {
+        let dt = Duration::from_millis(self.bot_config.tick_ms);
+        let humans = self.game_world
+            .players()
+            .filter(|info| !self.bots.contains_key(&info.player_id))
+            .map(|info| ClientPosition { x: info.x, y: info.y })
+            .collect::<Vec<ClientPosition>>();
+
+        let bot_ids = self.bots.keys().copied().collect::<Vec<usize>>();
+        for bot_id in bot_ids {
+            let Some(position) = self.game_world.player_position(bot_id) else {
+                continue;
+            };
+            let Some(bot) = self.bots.get_mut(&bot_id) else {
+                continue;
+            };
+            let action = bot.think(position, &humans, self.game_world.map(), &self.bot_config, dt, &mut self.rng);
+
+            if let Some(angle) = action.fire_angle {
+                trace!(player_id = bot_id, angle, "bot fired");
+                self.record_input(bot_id, &ClientData::WeaponFired { angle, power: 1.0 });
+                self.apply(WorldCommand::Fire { player_id: bot_id, angle });
+            }
+            if action.position != position {
+                self.record_input(bot_id, &ClientData::PlayerMoved {
+                    player_controls: action.controls,
+                    player_position: action.position
+                });
+                self.apply(WorldCommand::Move {
+                    player_id: bot_id,
+                    position: action.position,
+                    controls: action.controls
+                });
+            }
+        }
+    }
+
+    fn start_recording(&mut self) {
+        if !self.replay_config.record || self.recorder.is_some() {
+            return;
+        }
+
+        match ReplayRecorder::create(&self.replay_config.directory, self.game_world.room_id, self.game_world.seed, self.game_world.map_id(), self.clock.now()) {
+            Ok(recorder) => {
+                info!(event = "recording_started", room_id = self.game_world.room_id, path = %recorder.path().display(), "recording match");
+                self.recorder = Some(recorder);
+            }
+            Err(error) => {
+                warn!(room_id = self.game_world.room_id, %error, "could not start match recording");
+            }
+        }
+    }
+
+    fn stop_recording(&mut self) {
+        self.flush_recording();
+        if let Some(recorder) = self.recorder.take() {
+            info!(event = "recording_finished", room_id = self.game_world.room_id, path = %recorder.path().display(), "match recording saved");
+        }
+    }
+
+    fn flush_recording(&mut self) {
+        if let Some(Err(error)) = self.recorder.as_mut().map(|recorder| recorder.flush()) {
+            self.abort_recording(error);
+        }
+    }
+
+    fn record_input(&mut self, player_id: usize, data: &ClientData) {
+        let now = self.clock.now();
+        if let Some(Err(error)) = self.recorder.as_mut().map(|recorder| recorder.record_input(now, player_id, data)) {
+            self.abort_recording(error);
+        }
+    }
+
+    fn record_output(&mut self, recipient: Option<usize>, data: &PeerPlayerData) {
+        let now = self.clock.now();
+        if let Some(Err(error)) = self.recorder.as_mut().map(|recorder| recorder.record_output(now, recipient, data)) {
+            self.abort_recording(error);
+        }
+    }
+
+    fn abort_recording(&mut self, error: std::io::Error) {
+        warn!(room_id = self.game_world.room_id, %error, "match recording failed, recording stopped");
+        self.recorder = None;
+    }
+}
+
+/// What one finished match changes in a player's profile
+#[derive(Debug)]
+struct ProfileUpdate {
+    player_id: usize,
+    result: MatchResult,
+    rating_change: Option<f64>,
+    ended_at_ms: u64,
+    game_mode: String,
+    ratings: RatingConfig,
+}
+
+impl ProfileUpdate {
+    fn apply(&self, profile: &mut Profile) {
+        profile.stats.add(&self.result);
+        if let Some(change) = self.rating_change {
+            let before = self.ratings.rating_of(profile);
+            profile.rating = Some(before + change);
+            profile.rating_history.push(RatingChange {
+                ended_at_ms: self.ended_at_ms,
+                game_mode: self.game_mode.clone(),
+                before,
+                after: before + change,
+            });
         }
     }
 }
@@ -41,52 +702,144 @@ impl Actor for GameServer {
     /// We are going to use simple Context, we just need ability to communicate
     /// with other actors.
     type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        // keep replay files readable while the match is still running
+        ctx.run_interval(Duration::from_secs(1), |act, _| act.flush_recording());
+        ctx.run_interval(MATCH_TICK, |act, ctx| {
+            act.check_lobby();
+            act.apply(WorldCommand::Tick);
+            act.check_idle(ctx);
+        });
+
+        if self.bot_config.enabled {
+            ctx.run_interval(Duration::from_millis(self.bot_config.tick_ms.max(1)), |act, _| act.tick_bots());
+        }
+    }
+
+    fn stopping(&mut self, _: &mut Self::Context) -> Running {
+        self.stop_recording();
+        Running::Stop
+    }
 }
 
 impl Handler<Connect> for GameServer {
-    type Result = usize;
+    type Result = PeerSession;
 
     /// triggered when an actor (peer) joined
-    fn handle(&mut self, msg: Connect, _: &mut Context<Self>) -> Self::Result {
-        println!("Someone joined");
-
+    fn handle(&mut self, msg: Connect, ctx: &mut Context<Self>) -> Self::Result {
         // register session with random id
-        let id = self.rng.gen::<usize>();
-        self.peer_addr_map.insert(id, msg.peer_addr);
+        let id = self.next_player_id();
 
-        self.game_world.add_player(id);
+        if msg.role == PeerRole::Spectator {
+            info!(event = "join", player_id = id, room_id = self.game_world.room_id, spectator = true, "spectator joined");
+            msg.peer_addr.do_send(PeerPlayerData::WorldUpdate {
+                map_id: self.game_world.map_id().to_string(),
+                game_mode: self.game_world.game_mode().name().to_string(),
+                world_data: self.game_world.get_world_update(id)
+            });
+            self.send_match_state(&msg.peer_addr);
+            self.spectator_addr_map.insert(id, msg.peer_addr);
+            return PeerSession {
+                player_id: id,
+                room_id: self.game_world.room_id,
+            };
+        }
 
-        // send message to other users
-        self.send_position_to_other_players(PeerPlayerData::RemotePeerJoined {
-            player_id: id,
-            player_position: ClientPosition { x: 10.0, y: 10.0 }
-        }, Option::from(id));
+        if self.lobby_open && self.lobby.len() >= self.game_world.max_players() {
+            // the peer closes the connection, its disconnect is then ignored as it never joined
+            info!(event = "lobby_full", player_id = id, room_id = self.game_world.room_id, "lobby is full, connection rejected");
+            msg.peer_addr.do_send(PeerPlayerData::Kicked { reason: "lobby_full" });
+            return PeerSession {
+                player_id: id,
+                room_id: self.game_world.room_id,
+            };
+        }
 
-        // send world update to current peer
-        let world_data = self.game_world.get_world_update(id);
-        if let Some(addr) = self.peer_addr_map.get(&id) {
-            addr.do_send(PeerPlayerData::WorldUpdate { world_data });
+        if !self.lobby_open && self.game_world.is_full() && self.bots.is_empty() {
+            // like a full lobby, the peer is never registered and closes the connection
+            info!(event = "room_full", player_id = id, room_id = self.game_world.room_id, "room is full, connection rejected");
+            msg.peer_addr.do_send(PeerPlayerData::Kicked { reason: "room_full" });
+            return PeerSession {
+                player_id: id,
+                room_id: self.game_world.room_id,
+            };
+        }
+
+        let profile_id = msg.profile_id.as_deref();
+        if profile_id.is_some_and(|profile_id| self.profiles.values().any(|profile| profile.id == profile_id)) {
+            // one profile plays once per room, otherwise its stats would count twice
+            info!(event = "duplicate_profile", player_id = id, profile_id, room_id = self.game_world.room_id, "profile already in the room, connection rejected");
+            msg.peer_addr.do_send(PeerPlayerData::Kicked { reason: "already_connected" });
+            return PeerSession {
+                player_id: id,
+                room_id: self.game_world.room_id,
+            };
+        }
+
+        let profile = self.load_profile(id, profile_id);
+        let team = msg.team.or(profile.as_ref().and_then(|profile| profile.settings.team));
+        let party_key = profile.as_ref().map_or_else(|| self.parties.guest_key(), |profile| profile.id.clone());
+        self.parties.entered(&party_key, ctx.address());
+        self.party_keys.insert(id, party_key);
+
+        self.peer_addr_map.insert(id, msg.peer_addr);
+        if !self.lobby_open {
+            self.enter_world(id, team);
+        } else {
+            info!(event = "lobby_join", player_id = id, room_id = self.game_world.room_id, "player entered the lobby");
+            self.lobby.join(id, team, self.clock.now());
+            if let Some(profile) = &profile {
+                self.lobby.set_profile(id, &profile.name, &profile.settings.skin, team);
+            }
+            self.send_lobby_update();
+        }
+        // a member coming back finds the party mates already in the room
+        if let Some(party) = self.party_of_player(id) {
+            self.send_party_update(&party);
         }
 
         self.players_online_count.fetch_add(1, Ordering::SeqCst);
-        id
+        self.report_load();
+        PeerSession {
+            player_id: id,
+            room_id: self.game_world.room_id,
+        }
     }
 }
 
 impl Handler<Disconnect> for GameServer {
     type Result = ();
 
-    fn handle(&mut self, msg: Disconnect, _: &mut Self::Context) -> Self::Result {
-        println!("Someone disconnected!");
+    fn handle(&mut self, msg: Disconnect, ctx: &mut Self::Context) -> Self::Result {
         // remove peer address
-        if let Some(_) = self.peer_addr_map.remove(&msg.id) {
-            // send message to other users
-            self.send_position_to_other_players(PeerPlayerData::RemotePeerLeft {
-                player_id: msg.id
-            }, None);
-
-            self.game_world.remove_player(msg.id);
+        if self.peer_addr_map.remove(&msg.id).is_some() {
+            let guest = self.profiles.remove(&msg.id).is_none();
+            self.invites.forget(msg.id);
+            if let Some(key) = self.party_keys.remove(&msg.id) {
+                if let Some(party) = self.parties.left(&key, &ctx.address(), guest) {
+                    self.send_party_update(&party);
+                }
+            }
+            if self.lobby.leave(msg.id) {
+                info!(event = "lobby_leave", player_id = msg.id, room_id = self.game_world.room_id, "player left the lobby");
+                self.send_lobby_update();
+            } else {
+                self.apply(WorldCommand::Leave { player_id: msg.id });
+                self.balance_bots();
+            }
             self.players_online_count.fetch_sub(1, Ordering::SeqCst);
+            self.report_load();
+
+            if self.peer_addr_map.is_empty() {
+                self.stop_recording();
+                // the next players gather in the lobby again
+                self.lobby_open = self.lobby.is_enabled();
+            }
+        } else if self.spectator_addr_map.remove(&msg.id).is_some() {
+            info!(event = "leave", player_id = msg.id, room_id = self.game_world.room_id, spectator = true, "spectator left");
+        } else {
+            debug!(player_id = msg.id, "disconnect for unknown player ignored");
         }
     }
 }
@@ -95,11 +848,237 @@ impl Handler<PeerPlayerPositionUpdate> for GameServer {
     type Result = ();
 
     fn handle(&mut self, msg: PeerPlayerPositionUpdate, _: &mut Self::Context) -> Self::Result {
-        let player_position_update = PeerPlayerData::RemotePeerPositionUpdate {
-            player_position: msg.player_position,
+        self.record_input(msg.player_id, &ClientData::PlayerMoved {
+            player_controls: msg.player_controls,
+            player_position: msg.player_position
+        });
+
+        self.apply(WorldCommand::Move {
             player_id: msg.player_id,
+            position: msg.player_position,
+            controls: msg.player_controls
+        });
+    }
+}
+
+impl Handler<PeerWeaponFired> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerWeaponFired, _: &mut Self::Context) -> Self::Result {
+        self.record_input(msg.player_id, &ClientData::WeaponFired {
+            angle: msg.angle,
+            power: msg.power
+        });
+
+        // power doesn't change the shot yet, every hit deals the configured damage
+        self.apply(WorldCommand::Fire {
+            player_id: msg.player_id,
+            angle: msg.angle
+        });
+    }
+}
+
+impl Handler<PeerChatSend> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerChatSend, _: &mut Self::Context) -> Self::Result {
+        self.record_input(msg.player_id, &ClientData::ChatSend {
+            channel: msg.channel,
+            target_player_id: msg.target_player_id,
+            text: msg.text.clone()
+        });
+
+        let text = match self.chat.moderate(msg.player_id, &msg.text) {
+            Ok(text) => text,
+            Err(reason) => {
+                debug!(event = "chat_rejected", player_id = msg.player_id, reason, "chat message not delivered");
+                return;
+            }
+        };
+
+        let chat_message = PeerPlayerData::ChatMessage {
+            sender_id: msg.player_id,
+            channel: msg.channel,
+            text
+        };
+
+        match msg.channel {
+            ChatChannel::Room => {
+                self.send_position_to_other_players(chat_message, None);
+            }
+            ChatChannel::Team => {
+                let Some(team) = self.game_world.player_team(msg.player_id) else {
+                    debug!(event = "chat_rejected", player_id = msg.player_id, reason = "no_team", "chat message not delivered");
+                    return;
+                };
+                self.send_to_team(team, chat_message);
+            }
+            ChatChannel::Direct => {
+                if !self.peer_addr_map.contains_key(&msg.target_player_id) {
+                    debug!(event = "chat_rejected", player_id = msg.player_id, target_player_id = msg.target_player_id, reason = "unknown_target", "chat message not delivered");
+                    return;
+                }
+                self.send_to_player(msg.target_player_id, chat_message.clone());
+
+                // echo back so the sender sees what was delivered
+                if msg.target_player_id != msg.player_id {
+                    self.send_to_player(msg.player_id, chat_message);
+                }
+            }
+        }
+    }
+}
+
+impl Handler<PeerLobbySetup> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerLobbySetup, _: &mut Self::Context) -> Self::Result {
+        if !self.lobby.set_profile(msg.player_id, &msg.name, &msg.skin, msg.team) {
+            debug!(player_id = msg.player_id, "lobby setup from player outside the lobby ignored");
+            return;
+        }
+        self.send_lobby_update();
+    }
+}
+
+impl Handler<PeerLobbyReady> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerLobbyReady, _: &mut Self::Context) -> Self::Result {
+        if !self.lobby.set_ready(msg.player_id, msg.ready) {
+            debug!(player_id = msg.player_id, "ready check from player outside the lobby ignored");
+            return;
+        }
+        debug!(event = "lobby_ready", player_id = msg.player_id, ready = msg.ready, "ready state changed");
+        self.send_lobby_update();
+        self.check_lobby();
+    }
+}
+
+impl Handler<PeerPartyInvite> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerPartyInvite, _: &mut Self::Context) -> Self::Result {
+        if !self.peer_addr_map.contains_key(&msg.target_id) {
+            debug!(event = "party_rejected", player_id = msg.player_id, target_id = msg.target_id, reason = "unknown_target", "party invite not sent");
+            return;
+        }
+        let (Some(leader), Some(target)) = (self.party_keys.get(&msg.player_id), self.party_keys.get(&msg.target_id)) else {
+            return;
+        };
+        if let Err(error) = self.parties.check_invite(leader, target) {
+            debug!(event = "party_rejected", player_id = msg.player_id, target_id = msg.target_id, reason = error.as_str(), "party invite not sent");
+            return;
+        }
+        self.invites.send(msg.player_id, msg.target_id, self.clock.now());
+        debug!(event = "party_invite", player_id = msg.player_id, target_id = msg.target_id, "party invite sent");
+        self.send_to_player(msg.target_id, PeerPlayerData::PartyInvitation { leader_id: msg.player_id });
+    }
+}
+
+impl Handler<PeerPartyRespond> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerPartyRespond, _: &mut Self::Context) -> Self::Result {
+        let joined = match (self.party_keys.get(&msg.leader_id), self.party_keys.get(&msg.player_id)) {
+            _ if !self.invites.take(msg.player_id, msg.leader_id, self.clock.now()) => Err(PartyError::NoInvite),
+            _ if !msg.accept => {
+                debug!(event = "party_decline", player_id = msg.player_id, leader_id = msg.leader_id, "party invite declined");
+                return;
+            }
+            (Some(leader), Some(member)) => self.parties.join(leader, member),
+            // the leader left the room since inviting
+            _ => Err(PartyError::NoInvite),
         };
-        self.game_world.update_player_position(msg.player_id, msg.player_position.x, msg.player_position.y);
-        self.send_position_to_other_players(player_position_update, Option::from(msg.player_id));
+        let party = match joined {
+            Ok(party) => party,
+            Err(error) => {
+                debug!(event = "party_rejected", player_id = msg.player_id, leader_id = msg.leader_id, reason = error.as_str(), "party invite not accepted");
+                return;
+            }
+        };
+
+        info!(event = "party_join", player_id = msg.player_id, leader_id = msg.leader_id, room_id = self.game_world.room_id, members = party.members.len(), "player joined a party");
+        self.send_party_update(&party);
+
+        // joining mid-match moves the player over to the party's team
+        if let Some(team) = self.game_world.player_team(msg.leader_id) {
+            if self.game_world.player_team(msg.player_id).is_some_and(|current| current != team) {
+                self.apply(WorldCommand::SwitchTeam { player_id: msg.player_id, team });
+            }
+        }
+    }
+}
+
+impl Handler<PeerPartyLeave> for GameServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerPartyLeave, _: &mut Self::Context) -> Self::Result {
+        let Some(party) = self.party_keys.get(&msg.player_id).and_then(|key| self.parties.leave(key)) else {
+            debug!(player_id = msg.player_id, "party leave from player outside a party ignored");
+            return;
+        };
+        self.invites.forget(msg.player_id);
+        info!(event = "party_leave", player_id = msg.player_id, room_id = self.game_world.room_id, "player left a party");
+        self.send_to_player(msg.player_id, PeerPlayerData::PartyUpdate { leader_id: msg.player_id, members: Vec::new() });
+        self.send_party_update(&party);
+    }
+}
+
+impl Handler<KickPlayer> for GameServer {
+    type Result = bool;
+
+    fn handle(&mut self, msg: KickPlayer, _: &mut Self::Context) -> Self::Result {
+        let room_id = self.game_world.room_id;
+        if self.bots.contains_key(&msg.player_id) {
+            info!(event = "owner_kick", player_id = msg.player_id, room_id, bot = true, "room owner removed a bot");
+            self.remove_bot(msg.player_id);
+            return true;
+        }
+        if !self.peer_addr_map.contains_key(&msg.player_id) {
+            return false;
+        }
+
+        // the peer closes and its disconnect removes it from the room
+        info!(event = "owner_kick", player_id = msg.player_id, room_id, "room owner kicked a player");
+        self.send_to_player(msg.player_id, PeerPlayerData::Kicked { reason: "kicked_by_owner" });
+        true
+    }
+}
+
+impl Handler<UpdateRoomSettings> for GameServer {
+    type Result = Result<(), &'static str>;
+
+    fn handle(&mut self, msg: UpdateRoomSettings, _: &mut Self::Context) -> Self::Result {
+        if let Some(max_players) = msg.max_players {
+            if !(1..=MAX_ROOM_PLAYERS).contains(&max_players) {
+                return Err("invalid_max_players");
+            }
+        }
+        if let Some(game_mode) = msg.game_mode {
+            self.game_world.set_game_mode(game_mode)?;
+        }
+        if let Some(max_players) = msg.max_players {
+            self.game_world.set_max_players(max_players);
+            self.balance_bots();
+            self.report_load();
+        }
+
+        info!(event = "room_settings", room_id = self.game_world.room_id, max_players = msg.max_players, game_mode = ?msg.game_mode, "room settings changed");
+        Ok(())
+    }
+}
+
+impl Handler<GetRoomInfo> for GameServer {
+    type Result = RoomInfo;
+
+    fn handle(&mut self, _: GetRoomInfo, _: &mut Self::Context) -> Self::Result {
+        RoomInfo {
+            room_id: self.game_world.room_id,
+            players: self.game_world.player_count() + self.lobby.len(),
+            max_players: self.game_world.max_players(),
+            game_mode: self.game_world.game_mode(),
+            average_rating: self.average_rating(),
+        }
     }
 }
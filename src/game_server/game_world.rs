@@ -1,59 +1,505 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::game_server::capture_the_flag::FlagEvent;
+use crate::game_server::game_map::GameMap;
+use crate::game_server::game_mode::{GameMode, GameModeKind, Hit, ModeContext};
+use crate::game_server::match_state::{MatchConfig, MatchPhase, MatchState, MatchSummary, PlayerScore};
+use crate::game_server::movement_validator::{MovementConfig, MovementFlag, MovementValidator};
+use crate::game_server::peer::{ClientControls, ClientPosition};
+use crate::game_server::simulation::{seeded_rng, SimRng, SimTime};
+use crate::game_server::spawn::{Occupant, SpawnSelector, SpawnStrategy};
+use crate::game_server::team::TeamConfig;
 
 #[derive(Debug, Clone)]
 pub struct PeerPlayerInfo {
     pub player_id: usize,
+    pub team: u8,
     pub x: f32,
     pub y: f32
 }
 
+/// Input to a simulation step
+#[derive(Debug, Clone)]
+pub enum WorldCommand {
+    /// `team` is the player's choice, the world may put them elsewhere to keep teams balanced. A player joins the team
+    /// of any `party` member already in the world, balanced or not.
+    Join {
+        player_id: usize,
+        team: Option<u8>,
+        party: Vec<usize>,
+    },
+    /// moves a player to another team, so they can play with their party
+    SwitchTeam {
+        player_id: usize,
+        team: u8,
+    },
+    Leave {
+        player_id: usize,
+    },
+    Move {
+        player_id: usize,
+        position: ClientPosition,
+        controls: ClientControls,
+    },
+    /// hitscan shot from the player's position, `angle` in radians
+    Fire {
+        player_id: usize,
+        angle: f32,
+    },
+    /// advances the match timers
+    Tick,
+}
+
+/// Outcome of a simulation step
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorldEvent {
+    Joined {
+        player_id: usize,
+        team: u8,
+        position: ClientPosition,
+    },
+    RoomFull {
+        player_id: usize,
+    },
+    TeamChanged {
+        player_id: usize,
+        team: u8,
+        position: ClientPosition,
+    },
+    Left {
+        player_id: usize,
+    },
+    /// `flags` lists what had to be corrected in the reported position
+    Moved {
+        player_id: usize,
+        position: ClientPosition,
+        flags: Vec<MovementFlag>,
+        suspicion: f32,
+    },
+    Kicked {
+        player_id: usize,
+        reason: &'static str,
+    },
+    /// `duration` is what is left of the new phase, `None` when it has no time limit
+    PhaseChanged {
+        phase: MatchPhase,
+        duration: Option<Duration>,
+    },
+    /// the victim is back at `respawn_position` with full health
+    Killed {
+        killer_id: usize,
+        victim_id: usize,
+        respawn_position: ClientPosition,
+    },
+    ScoresChanged {
+        scores: Vec<PlayerScore>,
+    },
+    MatchEnded {
+        summary: MatchSummary,
+    },
+    /// `player_id` is who caused it, `None` when a flag returned by itself
+    FlagChanged {
+        team: u8,
+        event: FlagEvent,
+        player_id: Option<usize>,
+        position: ClientPosition,
+    },
+}
+
+/// Everything needed to create a game world, shared by all rooms
+#[derive(Debug, Clone)]
+pub struct WorldSettings {
+    pub map: Arc<GameMap>,
+    pub movement: MovementConfig,
+    pub spawn_strategy: SpawnStrategy,
+    pub teams: TeamConfig,
+    pub match_config: MatchConfig,
+}
+
 #[derive(Debug)]
 pub struct GameWorld {
+    pub room_id: usize,
+    /// seed of the world's RNG, replaying the same commands with it reproduces the same events
+    pub seed: u64,
     max_players_count: u8,
     current_players_count: u8,
-    player_map: HashMap<usize, PeerPlayerInfo>,
+    // ordered, so iterating players doesn't depend on hashing
+    player_map: BTreeMap<usize, PeerPlayerInfo>,
+    map: Arc<GameMap>,
+    movement_validator: MovementValidator,
+    spawn_selector: SpawnSelector,
+    teams: TeamConfig,
+    match_state: MatchState,
+    mode: Box<dyn GameMode>,
+    // not part of `PeerPlayerInfo`, clients don't see it
+    health: BTreeMap<usize, u32>,
+    rng: SimRng,
 }
 
-impl Default for GameWorld {
-    fn default() -> Self {
+impl GameWorld {
+    pub fn new(room_id: usize, seed: u64, settings: &WorldSettings) -> Self {
         Self {
+            room_id,
+            seed,
             max_players_count: 2,
             current_players_count: 0,
-            player_map: HashMap::new()
+            player_map: BTreeMap::new(),
+            map: settings.map.clone(),
+            movement_validator: MovementValidator::new(settings.movement.clone(), settings.map.bounds),
+            spawn_selector: SpawnSelector::new(settings.spawn_strategy),
+            teams: settings.teams.clone(),
+            match_state: MatchState::new(settings.match_config.clone(), SimTime::ZERO),
+            mode: settings.match_config.mode.create(&settings.match_config, &settings.map, &settings.teams),
+            health: BTreeMap::new(),
+            rng: seeded_rng(seed)
         }
     }
-}
 
-impl GameWorld {
-    pub fn add_player(&mut self, player_id: usize) {
-        let peer_data = PeerPlayerInfo {
-            player_id,
-            x: 0.0,
-            y: 0.0
+    pub fn map_id(&self) -> &str {
+        &self.map.id
+    }
+
+    pub fn map(&self) -> &GameMap {
+        &self.map
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.current_players_count as usize
+    }
+
+    pub fn max_players(&self) -> usize {
+        self.max_players_count as usize
+    }
+
+    /// players already in the world stay when the limit drops below their number
+    pub fn set_max_players(&mut self, max_players: u8) {
+        self.max_players_count = max_players;
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.current_players_count >= self.max_players_count
+    }
+
+    pub fn player_position(&self, player_id: usize) -> Option<ClientPosition> {
+        self.player_map.get(&player_id).map(|data| ClientPosition { x: data.x, y: data.y })
+    }
+
+    pub fn player_team(&self, player_id: usize) -> Option<u8> {
+        self.player_map.get(&player_id).map(|data| data.team)
+    }
+
+    pub fn game_mode(&self) -> GameModeKind {
+        self.mode.kind()
+    }
+
+    /// switches the rules, only while nobody is in the world
+    pub fn set_game_mode(&mut self, kind: GameModeKind) -> Result<(), &'static str> {
+        if self.player_count() > 0 {
+            return Err("room_in_use");
+        }
+        self.mode = kind.create(&self.match_state.config, &self.map, &self.teams);
+        Ok(())
+    }
+
+    /// the game mode's state as events, for peers joining mid-match
+    pub fn mode_state(&self) -> Vec<WorldEvent> {
+        self.mode.state(&self.player_map)
+    }
+
+    pub fn match_phase(&self) -> MatchPhase {
+        self.match_state.phase()
+    }
+
+    /// time left in the current match phase, `None` when it has no time limit
+    pub fn match_remaining(&self, now: SimTime) -> Option<Duration> {
+        self.match_state.remaining(now)
+    }
+
+    pub fn scores(&self) -> Vec<PlayerScore> {
+        self.match_state.scoreboard.ranked()
+    }
+
+    /// friendly fire rule, `false` when either player isn't in the world
+    pub fn can_damage(&self, attacker_id: usize, victim_id: usize) -> bool {
+        match (self.player_team(attacker_id), self.player_team(victim_id)) {
+            (Some(attacker_team), Some(victim_team)) => self.teams.can_damage(attacker_team, victim_team),
+            _ => false,
+        }
+    }
+
+    /// Advances the world by one command. The result only depends on the world state, the
+    /// command, `now` and the RNG, so the same inputs always produce the same events.
+    pub fn step(&mut self, now: SimTime, command: WorldCommand) -> Vec<WorldEvent> {
+        match command {
+            WorldCommand::Join { player_id, team, party } => match self.add_player(player_id, team, &party, now) {
+                Some((team, position)) => {
+                    let mut events = vec![WorldEvent::Joined { player_id, team, position }];
+                    events.extend(self.with_mode(now, |mode, ctx| mode.on_join(ctx, player_id)));
+                    events
+                }
+                None => vec![WorldEvent::RoomFull { player_id }],
+            },
+            WorldCommand::Leave { player_id } => {
+                // the mode still sees the player, so it can drop what they carried
+                let mode_events = self.with_mode(now, |mode, ctx| mode.on_leave(ctx, player_id));
+                if self.remove_player(player_id) {
+                    let mut events = vec![WorldEvent::Left { player_id }];
+                    events.extend(mode_events);
+                    events
+                } else {
+                    Vec::new()
+                }
+            }
+            WorldCommand::SwitchTeam { player_id, team } => self.switch_team(player_id, team, now),
+            WorldCommand::Move { player_id, position, controls } => {
+                let Some(event) = self.update_player_position(player_id, position, &controls, now) else {
+                    return Vec::new();
+                };
+                let WorldEvent::Moved { position, .. } = event else {
+                    return vec![event];
+                };
+                let mut events = vec![event];
+                let mode_events = self.with_mode(now, |mode, ctx| mode.on_input(ctx, player_id, position));
+                events.extend(self.after_mode_events(now, mode_events));
+                events
+            }
+            WorldCommand::Fire { player_id, angle } => self.fire(player_id, angle, now),
+            WorldCommand::Tick => {
+                let mut events = self.with_mode(now, |mode, ctx| mode.on_tick(ctx));
+                events.extend(self.advance_match(now));
+                events
+            }
+        }
+    }
+
+    /// runs a game mode hook with a view of the world
+    fn with_mode<F>(&mut self, now: SimTime, hook: F) -> Vec<WorldEvent>
+    where
+        F: FnOnce(&mut dyn GameMode, &mut ModeContext) -> Vec<WorldEvent>,
+    {
+        let mut ctx = ModeContext {
+            now,
+            match_state: &mut self.match_state,
+            players: &self.player_map,
         };
+        hook(self.mode.as_mut(), &mut ctx)
+    }
 
-        if self.current_players_count >= self.max_players_count {
+    /// a hook that changed something may have finished a live match
+    fn after_mode_events(&mut self, now: SimTime, mut events: Vec<WorldEvent>) -> Vec<WorldEvent> {
+        if !events.is_empty() && self.match_state.phase() == MatchPhase::Live {
+            events.extend(self.advance_match(now));
+        }
+        events
+    }
+
+    /// moves the match to its next phase when the current one is over
+    fn advance_match(&mut self, now: SimTime) -> Vec<WorldEvent> {
+        let mut events = Vec::new();
+        let live_elapsed = self.match_state.elapsed(now);
+        let finished = self.mode.is_finished(&self.match_state);
+        let Some(phase) = self.match_state.advance(now, self.player_count(), finished) else {
+            return events;
+        };
+
+        events.push(WorldEvent::PhaseChanged { phase, duration: self.match_state.remaining(now) });
+        if phase == MatchPhase::Ended {
+            events.push(WorldEvent::MatchEnded { summary: self.match_state.scoreboard.summary(live_elapsed) });
+        } else {
+            // scores start over with every warmup and live phase
+            let max_health = self.match_state.config.max_health;
+            for health in self.health.values_mut() {
+                *health = max_health;
+            }
+            events.push(WorldEvent::ScoresChanged { scores: self.scores() });
+        }
+        events.extend(self.with_mode(now, |mode, ctx| mode.on_phase(ctx, phase)));
+        events
+    }
+
+    /// Hitscan along `angle` from the shooter: the nearest damageable player within range whose line of sight isn't
+    /// blocked by the map is hit. What a hit is worth is up to the game mode.
+    fn fire(&mut self, player_id: usize, angle: f32, now: SimTime) -> Vec<WorldEvent> {
+        if self.match_state.phase() == MatchPhase::Ended {
+            return Vec::new();
+        }
+        let Some(origin) = self.player_position(player_id) else {
+            return Vec::new();
+        };
+
+        let (dir_x, dir_y) = (angle.cos(), angle.sin());
+        let range = self.match_state.config.weapon.range;
+        let radius = self.map.player_radius;
+        let victim = self.player_map
+            .values()
+            .filter(|data| data.player_id != player_id && self.can_damage(player_id, data.player_id))
+            .filter_map(|data| {
+                let (dx, dy) = (data.x - origin.x, data.y - origin.y);
+                let along = dx * dir_x + dy * dir_y;
+                let across = (dx * dir_y - dy * dir_x).abs();
+                (along > 0.0 && along <= range && across <= radius).then_some((along, data.player_id))
+            })
+            .filter(|(along, _)| !self.is_shot_blocked(origin, (dir_x, dir_y), *along))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, victim_id)| victim_id);
+        let Some(victim_id) = victim else {
+            return Vec::new();
+        };
+
+        let damage = self.match_state.config.weapon.damage;
+        let Some(position) = self.player_position(victim_id) else {
+            return Vec::new();
+        };
+        let Some(health) = self.health.get_mut(&victim_id) else {
+            return Vec::new();
+        };
+        *health = health.saturating_sub(damage);
+        let killed = *health == 0;
+
+        let mut events = Vec::new();
+        if killed {
+            let respawn_position = self.respawn(victim_id);
+            events.push(WorldEvent::Killed { killer_id: player_id, victim_id, respawn_position });
+        }
+        let hit = Hit { attacker_id: player_id, victim_id, position, killed };
+        let mode_events = self.with_mode(now, |mode, ctx| mode.on_hit(ctx, &hit));
+        events.extend(self.after_mode_events(now, mode_events));
+        events
+    }
+
+    /// samples the map along the shot, solid geometry stops it
+    fn is_shot_blocked(&self, origin: ClientPosition, (dir_x, dir_y): (f32, f32), distance: f32) -> bool {
+        let step = (self.map.player_radius / 2.0).max(1.0);
+        let mut travelled = step;
+        while travelled < distance {
+            let point = ClientPosition { x: origin.x + dir_x * travelled, y: origin.y + dir_y * travelled };
+            if self.map.is_blocked(point) {
+                return true;
+            }
+            travelled += step;
+        }
+        false
+    }
+
+    /// puts a killed player back on a spawn point with full health
+    fn respawn(&mut self, player_id: usize) -> ClientPosition {
+        let team = self.player_team(player_id);
+        let occupants = self.player_map
+            .values()
+            .filter(|data| data.player_id != player_id)
+            .map(|data| Occupant { position: ClientPosition { x: data.x, y: data.y }, team: Some(data.team) })
+            .collect::<Vec<Occupant>>();
+        let position = self.spawn_selector
+            .pick(&self.map.spawn_points, team, &occupants, &mut self.rng)
+            .unwrap_or(ClientPosition { x: 0.0, y: 0.0 });
+
+        if let Some(data) = self.player_map.get_mut(&player_id) {
+            data.x = position.x;
+            data.y = position.y;
+        }
+        self.health.insert(player_id, self.match_state.config.max_health);
+        self.movement_validator.reposition(player_id, position);
+        position
+    }
+
+    /// puts the player in a team and on a spawn point, returns `None` when the room is full
+    fn add_player(&mut self, player_id: usize, requested_team: Option<u8>, party: &[usize], now: SimTime) -> Option<(u8, ClientPosition)> {
+        if self.is_full() {
             // room is full
-            return;
+            return None;
+        }
+
+        let mut team_sizes = vec![0; self.teams.count as usize];
+        for data in self.player_map.values() {
+            if let Some(size) = team_sizes.get_mut(data.team as usize) {
+                *size += 1;
+            }
         }
+        let party_team = party.iter().find_map(|&member_id| self.player_team(member_id));
+        let team = party_team.unwrap_or_else(|| self.teams.assign(requested_team, &team_sizes));
+
+        let occupants = self.player_map
+            .values()
+            .map(|data| Occupant { position: ClientPosition { x: data.x, y: data.y }, team: Some(data.team) })
+            .collect::<Vec<Occupant>>();
+        let position = self.spawn_selector
+            .pick(&self.map.spawn_points, Some(team), &occupants, &mut self.rng)
+            .unwrap_or(ClientPosition { x: 0.0, y: 0.0 });
+
+        let peer_data = PeerPlayerInfo {
+            player_id,
+            team,
+            x: position.x,
+            y: position.y
+        };
 
-        if let Some(_) = self.player_map.insert(player_id, peer_data) {
+        self.health.insert(player_id, self.match_state.config.max_health);
+        self.match_state.scoreboard.add_player(player_id, team);
+        if self.player_map.insert(player_id, peer_data).is_none() {
             self.current_players_count += 1;
         };
+        self.movement_validator.track(player_id, position, now);
+        Some((team, position))
     }
 
-    pub fn remove_player(&mut self, player_id: usize) {
-        if let Some(_) = self.player_map.remove(&player_id) {
+    /// the mode sees the player leave their old team and join the new one, so a flag carrier drops the flag
+    fn switch_team(&mut self, player_id: usize, team: u8, now: SimTime) -> Vec<WorldEvent> {
+        let Some(position) = self.player_position(player_id) else {
+            return Vec::new();
+        };
+        if team >= self.teams.count.max(1) || self.player_team(player_id) == Some(team) {
+            return Vec::new();
+        }
+
+        let mut events = self.with_mode(now, |mode, ctx| mode.on_leave(ctx, player_id));
+        if let Some(data) = self.player_map.get_mut(&player_id) {
+            data.team = team;
+        }
+        self.match_state.scoreboard.set_team(player_id, team);
+        events.push(WorldEvent::TeamChanged { player_id, team, position });
+        events.extend(self.with_mode(now, |mode, ctx| mode.on_join(ctx, player_id)));
+        events
+    }
+
+    fn remove_player(&mut self, player_id: usize) -> bool {
+        self.movement_validator.forget(player_id);
+        self.match_state.scoreboard.remove_player(player_id);
+        self.health.remove(&player_id);
+        if self.player_map.remove(&player_id).is_some() {
             self.current_players_count -= 1;
             // remove this game world is player count is 0
+            return true;
         }
+        false
+    }
+
+    /// validates the reported position, resolves it against the map and stores the result
+    fn update_player_position(&mut self, player_id: usize, position: ClientPosition, controls: &ClientControls, now: SimTime) -> Option<WorldEvent> {
+        let data = self.player_map.get_mut(&player_id)?;
+        let mut check = self.movement_validator.validate(player_id, position, controls, now)?;
+        // stay put if there's no free position close to the reported one
+        let resolved = self.map.resolve_collisions(check.position).unwrap_or(ClientPosition { x: data.x, y: data.y });
+        if resolved != check.position {
+            self.movement_validator.reposition(player_id, resolved);
+            check.position = resolved;
+        }
+        data.x = check.position.x;
+        data.y = check.position.y;
+
+        if check.kick {
+            return Some(WorldEvent::Kicked { player_id, reason: "movement_violation" });
+        }
+        Some(WorldEvent::Moved {
+            player_id,
+            position: check.position,
+            flags: check.flags,
+            suspicion: check.suspicion,
+        })
     }
 
-    pub fn update_player_position(&mut self, player_id: usize, x: f32, y: f32) {
-        self.player_map.entry(player_id).and_modify(|data| {
-            data.x = x;
-            data.y = y;
-        });
+    pub fn players(&self) -> impl Iterator<Item = &PeerPlayerInfo> {
+        self.player_map.values()
     }
 
     pub fn get_world_update(&self, skip_id: usize) -> Vec<PeerPlayerInfo> {
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use serde::{Deserialize, Serialize};
+use crate::game_server::profile::{LifetimeStats, ProfileStore};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LeaderboardPeriod {
+    #[default]
+    AllTime,
+    /// since Monday 00:00 UTC
+    Weekly,
+}
+
+impl LeaderboardPeriod {
+    /// first moment counted at `now_ms`
+    pub fn start_ms(&self, now_ms: u64) -> u64 {
+        match self {
+            LeaderboardPeriod::AllTime => 0,
+            LeaderboardPeriod::Weekly => {
+                let days = now_ms / DAY_MS;
+                // the epoch was a Thursday
+                let since_monday = (days + 3) % 7;
+                (days - since_monday) * DAY_MS
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    /// 1 is the best
+    pub rank: usize,
+    pub profile_id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub stats: LifetimeStats,
+}
+
+/// Ranks every profile that played in the period by points, then wins, then kills. `mode` only counts matches of
+/// that game mode.
+pub fn rank(store: &dyn ProfileStore, period: LeaderboardPeriod, mode: Option<&str>, now_ms: u64) -> io::Result<Vec<LeaderboardEntry>> {
+    let mut stats = HashMap::<String, LifetimeStats>::new();
+    for record in store.matches_since(period.start_ms(now_ms))? {
+        if mode.is_some_and(|mode| mode != record.game_mode) {
+            continue;
+        }
+        for result in &record.results {
+            stats.entry(result.profile_id.clone()).or_default().add(result);
+        }
+    }
+
+    let mut entries = stats
+        .into_iter()
+        .map(|(profile_id, stats)| {
+            let name = store.load(&profile_id)?.map(|profile| profile.name).unwrap_or_default();
+            Ok(LeaderboardEntry { rank: 0, profile_id, name, stats })
+        })
+        .collect::<io::Result<Vec<LeaderboardEntry>>>()?;
+    entries.sort_by(|a, b| {
+        (b.stats.points, b.stats.wins, b.stats.kills)
+            .cmp(&(a.stats.points, a.stats.wins, a.stats.kills))
+            .then_with(|| a.profile_id.cmp(&b.profile_id))
+    });
+    for (index, entry) in entries.iter_mut().enumerate() {
+        entry.rank = index + 1;
+    }
+    Ok(entries)
+}
+
+/// Rankings by period and mode, computed on the first request and kept until the next match is recorded
+#[derive(Debug, Default)]
+pub struct LeaderboardCache {
+    rankings: Mutex<HashMap<RankingKey, CachedRanking>>,
+}
+
+/// period start and game mode of a ranking
+type RankingKey = (u64, Option<String>);
+
+#[derive(Debug)]
+struct CachedRanking {
+    matches_recorded: usize,
+    entries: Arc<Vec<LeaderboardEntry>>,
+}
+
+impl LeaderboardCache {
+    /// like `rank`, names are the ones profiles had when the ranking was computed
+    pub fn rank(&self, store: &dyn ProfileStore, period: LeaderboardPeriod, mode: Option<&str>, now_ms: u64) -> io::Result<Arc<Vec<LeaderboardEntry>>> {
+        let key = (period.start_ms(now_ms), mode.map(str::to_string));
+        let matches_recorded = store.matches_recorded()?;
+        if let Some(cached) = self.rankings.lock().unwrap().get(&key).filter(|cached| cached.matches_recorded == matches_recorded) {
+            return Ok(cached.entries.clone());
+        }
+
+        // not holding the lock, requests for other rankings don't wait for this one
+        let entries = Arc::new(rank(store, period, mode, now_ms)?);
+        let mut rankings = self.rankings.lock().unwrap();
+        rankings.retain(|_, cached| cached.matches_recorded == matches_recorded);
+        rankings.insert(key, CachedRanking { matches_recorded, entries: entries.clone() });
+        Ok(entries)
+    }
+}
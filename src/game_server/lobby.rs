@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use serde::Deserialize;
+use crate::game_server::simulation::SimTime;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LobbyConfig {
+    /// hold players in a lobby until the match starts, without it they join the world right away
+    pub enabled: bool,
+    /// the match starts this long after the first player entered the lobby, ready or not. 0 waits for everyone to be ready
+    pub countdown_secs: u64,
+    /// players needed before everyone being ready starts the match
+    pub min_players: usize,
+    /// longer names are cut
+    pub max_name_length: usize,
+}
+
+impl Default for LobbyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            countdown_secs: 30,
+            min_players: 2,
+            max_name_length: 16,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LobbyMember {
+    pub player_id: usize,
+    pub name: String,
+    pub skin: String,
+    /// `None` lets the server balance teams
+    pub team: Option<u8>,
+    pub ready: bool,
+}
+
+/// Players of a room waiting for the match to start
+#[derive(Debug)]
+pub struct Lobby {
+    config: LobbyConfig,
+    members: BTreeMap<usize, LobbyMember>,
+    countdown_started: Option<SimTime>,
+}
+
+impl Lobby {
+    pub fn new(config: LobbyConfig) -> Self {
+        Self {
+            config,
+            members: BTreeMap::new(),
+            countdown_started: None,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn members(&self) -> Vec<LobbyMember> {
+        self.members.values().cloned().collect()
+    }
+
+    /// the first member starts the countdown
+    pub fn join(&mut self, player_id: usize, team: Option<u8>, now: SimTime) {
+        self.members.insert(player_id, LobbyMember {
+            player_id,
+            name: String::new(),
+            skin: String::new(),
+            team,
+            ready: false,
+        });
+        self.countdown_started.get_or_insert(now);
+    }
+
+    pub fn leave(&mut self, player_id: usize) -> bool {
+        let removed = self.members.remove(&player_id).is_some();
+        if self.members.is_empty() {
+            self.countdown_started = None;
+        }
+        removed
+    }
+
+    /// returns `false` for players not in the lobby
+    pub fn set_profile(&mut self, player_id: usize, name: &str, skin: &str, team: Option<u8>) -> bool {
+        let Some(member) = self.members.get_mut(&player_id) else {
+            return false;
+        };
+        member.name = name.trim().chars().take(self.config.max_name_length).collect();
+        member.skin = skin.trim().to_string();
+        member.team = team;
+        true
+    }
+
+    /// returns `false` for players not in the lobby
+    pub fn set_ready(&mut self, player_id: usize, ready: bool) -> bool {
+        let Some(member) = self.members.get_mut(&player_id) else {
+            return false;
+        };
+        member.ready = ready;
+        true
+    }
+
+    /// time left until the match starts anyway, `None` without a countdown
+    pub fn remaining(&self, now: SimTime) -> Option<Duration> {
+        let started = self.countdown_started?;
+        Some(Duration::from_secs(self.config.countdown_secs))
+            .filter(|countdown| !countdown.is_zero())
+            .map(|countdown| countdown.saturating_sub(now.saturating_sub(started)))
+    }
+
+    /// everyone is ready, or the countdown ran out
+    pub fn should_start(&self, now: SimTime) -> bool {
+        if self.members.is_empty() {
+            return false;
+        }
+        let all_ready = self.members.len() >= self.config.min_players.max(1) && self.members.values().all(|member| member.ready);
+        all_ready || self.remaining(now).is_some_and(|remaining| remaining.is_zero())
+    }
+
+    /// empties the lobby, the members go into the match
+    pub fn start(&mut self) -> Vec<LobbyMember> {
+        self.countdown_started = None;
+        std::mem::take(&mut self.members).into_values().collect()
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use serde::Deserialize;
+use crate::game_server::capture_the_flag::CaptureTheFlagConfig;
+use crate::game_server::game_mode::GameModeKind;
+use crate::game_server::simulation::SimTime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchPhase {
+    /// waiting for enough players, kills don't score
+    Warmup,
+    Live,
+    /// showing the result, a new warmup follows
+    Ended,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WeaponConfig {
+    /// hitscan range in world units
+    pub range: f32,
+    pub damage: u32,
+}
+
+impl Default for WeaponConfig {
+    fn default() -> Self {
+        Self {
+            range: 800.0,
+            damage: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MatchConfig {
+    pub mode: GameModeKind,
+    /// players needed before the warmup counts down
+    pub min_players: usize,
+    pub warmup_secs: u64,
+    /// 0 plays until the score limit is reached
+    pub time_limit_secs: u64,
+    /// points that end a deathmatch, 0 plays until the time limit
+    pub score_limit: i32,
+    /// how long the result is shown before the next warmup
+    pub ended_secs: u64,
+    pub kill_points: i32,
+    pub max_health: u32,
+    pub weapon: WeaponConfig,
+    pub capture_the_flag: CaptureTheFlagConfig,
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self {
+            mode: GameModeKind::default(),
+            min_players: 2,
+            warmup_secs: 10,
+            time_limit_secs: 300,
+            score_limit: 10,
+            ended_secs: 10,
+            kill_points: 1,
+            max_health: 100,
+            weapon: WeaponConfig::default(),
+            capture_the_flag: CaptureTheFlagConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlayerScore {
+    pub player_id: usize,
+    pub team: u8,
+    pub kills: u32,
+    pub deaths: u32,
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchSummary {
+    /// `None` when the top score is shared
+    pub winner_id: Option<usize>,
+    /// team with the most points, `None` on a draw
+    pub winning_team: Option<u8>,
+    pub duration: Duration,
+    /// best first
+    pub scores: Vec<PlayerScore>,
+}
+
+/// Kills, deaths and points of the players in the room
+#[derive(Debug, Default)]
+pub struct Scoreboard {
+    scores: BTreeMap<usize, PlayerScore>,
+}
+
+impl Scoreboard {
+    pub fn add_player(&mut self, player_id: usize, team: u8) {
+        self.scores.insert(player_id, PlayerScore { player_id, team, ..PlayerScore::default() });
+    }
+
+    /// points stay with the player, team totals move with them
+    pub fn set_team(&mut self, player_id: usize, team: u8) {
+        if let Some(score) = self.scores.get_mut(&player_id) {
+            score.team = team;
+        }
+    }
+
+    pub fn remove_player(&mut self, player_id: usize) {
+        self.scores.remove(&player_id);
+    }
+
+    pub fn record_kill(&mut self, killer_id: usize, victim_id: usize, points: i32) {
+        if let Some(score) = self.scores.get_mut(&killer_id) {
+            score.kills += 1;
+            score.points += points;
+        }
+        if let Some(score) = self.scores.get_mut(&victim_id) {
+            score.deaths += 1;
+        }
+    }
+
+    pub fn add_points(&mut self, player_id: usize, points: i32) {
+        if let Some(score) = self.scores.get_mut(&player_id) {
+            score.points += points;
+        }
+    }
+
+    pub fn get(&self, player_id: usize) -> Option<&PlayerScore> {
+        self.scores.get(&player_id)
+    }
+
+    pub fn reset(&mut self) {
+        for score in self.scores.values_mut() {
+            *score = PlayerScore { player_id: score.player_id, team: score.team, ..PlayerScore::default() };
+        }
+    }
+
+    /// most points first, then fewest deaths, then player id so the order is stable
+    pub fn ranked(&self) -> Vec<PlayerScore> {
+        let mut scores = self.scores.values().copied().collect::<Vec<PlayerScore>>();
+        scores.sort_by(|a, b| b.points.cmp(&a.points).then(a.deaths.cmp(&b.deaths)).then(a.player_id.cmp(&b.player_id)));
+        scores
+    }
+
+    pub fn team_points(&self) -> BTreeMap<u8, i32> {
+        let mut points = BTreeMap::new();
+        for score in self.scores.values() {
+            *points.entry(score.team).or_insert(0) += score.points;
+        }
+        points
+    }
+
+    pub fn summary(&self, duration: Duration) -> MatchSummary {
+        let scores = self.ranked();
+        let winner_id = match scores.as_slice() {
+            [first, second, ..] if first.points == second.points => None,
+            [first, ..] => Some(first.player_id),
+            [] => None,
+        };
+
+        let team_points = self.team_points();
+        let best = team_points.values().copied().max();
+        let mut leaders = team_points.iter().filter(|(_, points)| Some(**points) == best);
+        let winning_team = match (leaders.next(), leaders.next()) {
+            (Some((team, _)), None) => Some(*team),
+            _ => None,
+        };
+
+        MatchSummary {
+            winner_id,
+            winning_team,
+            duration,
+            scores,
+        }
+    }
+}
+
+/// Phase and timers of the current match
+#[derive(Debug)]
+pub struct MatchState {
+    pub config: MatchConfig,
+    phase: MatchPhase,
+    phase_started: SimTime,
+    pub scoreboard: Scoreboard,
+}
+
+impl MatchState {
+    pub fn new(config: MatchConfig, now: SimTime) -> Self {
+        Self {
+            config,
+            phase: MatchPhase::Warmup,
+            phase_started: now,
+            scoreboard: Scoreboard::default(),
+        }
+    }
+
+    pub fn phase(&self) -> MatchPhase {
+        self.phase
+    }
+
+    /// `None` for phases without a time limit
+    pub fn phase_duration(&self, phase: MatchPhase) -> Option<Duration> {
+        let secs = match phase {
+            MatchPhase::Warmup => self.config.warmup_secs,
+            MatchPhase::Live => self.config.time_limit_secs,
+            MatchPhase::Ended => self.config.ended_secs,
+        };
+        Some(Duration::from_secs(secs)).filter(|duration| !duration.is_zero())
+    }
+
+    pub fn score_limit_reached(&self) -> bool {
+        self.config.score_limit > 0
+            && self.scoreboard.ranked().first().is_some_and(|score| score.points >= self.config.score_limit)
+    }
+
+    /// time left in the current phase, `None` when it has no time limit
+    pub fn remaining(&self, now: SimTime) -> Option<Duration> {
+        self.phase_duration(self.phase).map(|duration| duration.saturating_sub(self.elapsed(now)))
+    }
+
+    pub fn elapsed(&self, now: SimTime) -> Duration {
+        now.saturating_sub(self.phase_started)
+    }
+
+    /// Moves to the next phase once the current one is over, returns the new phase.
+    /// `finished` ends a live match early.
+    pub fn advance(&mut self, now: SimTime, player_count: usize, finished: bool) -> Option<MatchPhase> {
+        let expired = self.phase_duration(self.phase).is_some_and(|duration| self.elapsed(now) >= duration);
+        let next = match self.phase {
+            // the countdown only runs while there are enough players
+            MatchPhase::Warmup if player_count < self.config.min_players.max(1) => {
+                self.phase_started = now;
+                None
+            }
+            MatchPhase::Warmup if expired || self.phase_duration(MatchPhase::Warmup).is_none() => Some(MatchPhase::Live),
+            MatchPhase::Live if player_count == 0 => Some(MatchPhase::Warmup),
+            MatchPhase::Live if expired || finished => Some(MatchPhase::Ended),
+            MatchPhase::Ended if expired || self.phase_duration(MatchPhase::Ended).is_none() => Some(MatchPhase::Warmup),
+            _ => None,
+        }?;
+
+        self.phase = next;
+        self.phase_started = now;
+        if next != MatchPhase::Ended {
+            self.scoreboard.reset();
+        }
+        Some(next)
+    }
+}
@@ -1,12 +1,53 @@
-use actix::{Message, Recipient};
+use std::time::Duration;
+use actix::{Message, MessageResponse, Recipient};
+use crate::game_server::capture_the_flag::FlagEvent;
+use crate::game_server::compression::SharedFrames;
+use crate::game_server::game_mode::GameModeKind;
 use crate::game_server::game_world::PeerPlayerInfo;
-use crate::game_server::peer::ClientPosition;
+use crate::game_server::lobby::LobbyMember;
+use crate::game_server::chat::ChatChannel;
+use crate::game_server::match_state::{MatchPhase, MatchSummary, PlayerScore};
+use crate::game_server::peer::{ClientControls, ClientPosition};
 
 /// New chat session is created
 #[derive(Message)]
-#[rtype(usize)]
+#[rtype(result = "PeerSession")]
 pub struct Connect {
-    pub peer_addr: Recipient<PeerPlayerData>,
+    pub peer_addr: PeerAddr,
+    pub role: PeerRole,
+    /// requested team, `None` lets the server balance teams
+    pub team: Option<u8>,
+    /// profile the peer authenticated as, its stats are updated when a match ends
+    pub profile_id: Option<String>,
+}
+
+/// Where the game server sends a peer's messages
+#[derive(Debug, Clone)]
+pub struct PeerAddr {
+    pub data: Recipient<PeerPlayerData>,
+    pub broadcast: Recipient<PeerBroadcast>,
+    /// the peer asked for compressed frames
+    pub compress: bool,
+}
+
+impl PeerAddr {
+    pub fn do_send(&self, data: PeerPlayerData) {
+        self.data.do_send(data);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerRole {
+    Player,
+    /// watches the world stream without taking a player slot
+    Spectator,
+}
+
+/// Identifiers handed back to a peer once it has joined a game world
+#[derive(MessageResponse, Debug, Clone, Copy)]
+pub struct PeerSession {
+    pub player_id: usize,
+    pub room_id: usize,
 }
 
 #[derive(Message, Debug, Clone)]
@@ -14,6 +55,7 @@ pub struct Connect {
 pub enum PeerPlayerData {
     RemotePeerJoined {
         player_id: usize,
+        team: u8,
         player_position: ClientPosition,
     },
     RemotePeerLeft {
@@ -21,18 +63,86 @@ pub enum PeerPlayerData {
     },
     RemotePeerPositionUpdate {
         player_id: usize,
+        team: u8,
         player_position: ClientPosition,
     },
     WorldUpdate {
+        map_id: String,
+        game_mode: String,
         world_data: Vec<PeerPlayerInfo>,
+    },
+    ChatMessage {
+        sender_id: usize,
+        channel: ChatChannel,
+        text: String,
+    },
+    MatchPhaseChanged {
+        phase: MatchPhase,
+        /// time left in the phase, `None` when it has no time limit
+        duration: Option<Duration>,
+    },
+    PlayerKilled {
+        killer_id: usize,
+        victim_id: usize,
+        respawn_position: ClientPosition,
+    },
+    ScoreboardUpdate {
+        scores: Vec<PlayerScore>,
+    },
+    MatchSummary {
+        summary: MatchSummary,
+    },
+    FlagUpdate {
+        team: u8,
+        event: FlagEvent,
+        /// `None` when the flag returned by itself
+        player_id: Option<usize>,
+        position: ClientPosition,
+    },
+    /// sent to every lobby member when someone joins, leaves or changes, `player_id` is the receiver's own id
+    LobbyUpdate {
+        player_id: usize,
+        members: Vec<LobbyMember>,
+        /// time until the match starts anyway, `None` without a countdown
+        countdown: Option<Duration>,
+    },
+    /// `leader_id` invites the receiver to their party
+    PartyInvitation {
+        leader_id: usize,
+    },
+    /// sent to every member when the party changes, `members` is empty once the receiver is no longer in a party
+    PartyUpdate {
+        leader_id: usize,
+        members: Vec<usize>,
+    },
+    /// the server asks the peer to close its connection
+    Kicked {
+        reason: &'static str,
     }
 }
 
+/// A message going to several peers, with the frames the game server encoded once for all of them
+#[derive(Message, Debug, Clone)]
+#[rtype(result = "()")]
+pub struct PeerBroadcast {
+    pub data: PeerPlayerData,
+    pub frames: SharedFrames,
+}
+
 #[derive(Message)]
 #[rtype(result = "()")]
 pub struct PeerPlayerPositionUpdate {
     pub player_position: ClientPosition,
+    pub player_controls: ClientControls,
+    pub player_id: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerWeaponFired {
     pub player_id: usize,
+    pub angle: f32,
+    pub power: f32,
 }
 
 /// Session is disconnected
@@ -41,3 +151,81 @@ pub struct PeerPlayerPositionUpdate {
 pub struct Disconnect {
     pub id: usize,
 }
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerChatSend {
+    pub player_id: usize,
+    pub channel: ChatChannel,
+    /// only used for `ChatChannel::Direct`
+    pub target_player_id: usize,
+    pub text: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerLobbySetup {
+    pub player_id: usize,
+    pub name: String,
+    pub skin: String,
+    pub team: Option<u8>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerLobbyReady {
+    pub player_id: usize,
+    pub ready: bool,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerPartyInvite {
+    pub player_id: usize,
+    pub target_id: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerPartyRespond {
+    pub player_id: usize,
+    pub leader_id: usize,
+    pub accept: bool,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PeerPartyLeave {
+    pub player_id: usize,
+}
+
+/// Room owner removes a player or bot, answers `false` when it isn't in the room
+#[derive(Message)]
+#[rtype(result = "bool")]
+pub struct KickPlayer {
+    pub player_id: usize,
+}
+
+/// Room owner changes the room, fields left `None` stay as they are
+#[derive(Message)]
+#[rtype(result = "Result<(), &'static str>")]
+pub struct UpdateRoomSettings {
+    pub max_players: Option<u8>,
+    /// only while no one is in the world
+    pub game_mode: Option<GameModeKind>,
+}
+
+#[derive(Message)]
+#[rtype(result = "RoomInfo")]
+pub struct GetRoomInfo;
+
+#[derive(MessageResponse, Debug, Clone)]
+pub struct RoomInfo {
+    pub room_id: usize,
+    /// players in the world and the lobby, bots included
+    pub players: usize,
+    pub max_players: usize,
+    pub game_mode: GameModeKind,
+    /// mean rating of the players with a profile, for matching players to rooms of their skill
+    pub average_rating: Option<f64>,
+}
@@ -1,5 +1,32 @@
+#[allow(clippy::module_inception)]
 pub mod game_server;
 pub mod peer;
+pub mod peer_setup;
 pub mod flatbuffers_utils;
 pub mod message_types;
 pub mod game_world;
+pub mod game_map;
+pub mod movement_validator;
+pub mod rate_limiter;
+pub mod spawn;
+pub mod chat;
+pub mod replay;
+pub mod replay_peer;
+pub mod simulation;
+pub mod bot;
+pub mod team;
+pub mod match_state;
+pub mod game_mode;
+pub mod deathmatch;
+pub mod capture_the_flag;
+pub mod lobby;
+pub mod room_registry;
+pub mod party;
+pub mod profile;
+pub mod leaderboard;
+pub mod rating;
+pub mod reliability;
+pub mod udp_peer;
+pub mod udp_server;
+pub mod outbox;
+pub mod compression;
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use serde::Deserialize;
+use crate::game_server::peer::{ClientControls, ClientPosition};
+use crate::game_server::simulation::SimTime;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct WorldBounds {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub max_x: f32,
+    pub max_y: f32,
+}
+
+impl Default for WorldBounds {
+    /// the client stage (1024x600) plus the sprite overflow it wraps around at
+    fn default() -> Self {
+        Self {
+            min_x: -92.0,
+            min_y: -92.0,
+            max_x: 1116.0,
+            max_y: 692.0,
+        }
+    }
+}
+
+impl WorldBounds {
+    pub fn contains(&self, position: ClientPosition) -> bool {
+        position.x >= self.min_x && position.x <= self.max_x
+            && position.y >= self.min_y && position.y <= self.max_y
+    }
+
+    pub fn clamp(&self, position: ClientPosition) -> ClientPosition {
+        ClientPosition {
+            x: position.x.clamp(self.min_x, self.max_x),
+            y: position.y.clamp(self.min_y, self.max_y),
+        }
+    }
+
+    fn wrap(&self, position: ClientPosition) -> ClientPosition {
+        ClientPosition {
+            x: self.min_x + (position.x - self.min_x).rem_euclid(self.max_x - self.min_x),
+            y: self.min_y + (position.y - self.min_y).rem_euclid(self.max_y - self.min_y),
+        }
+    }
+}
+
+/// How much suspicion each kind of violation adds, and what happens when it piles up
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SuspicionPolicy {
+    pub speed_violation: f32,
+    pub impossible_controls: f32,
+    pub out_of_bounds: f32,
+    pub invalid_position: f32,
+    pub decay_per_second: f32,
+    /// players reaching this score are kicked, `None` only logs
+    pub kick_threshold: Option<f32>,
+}
+
+impl Default for SuspicionPolicy {
+    fn default() -> Self {
+        Self {
+            speed_violation: 1.0,
+            impossible_controls: 0.5,
+            out_of_bounds: 1.0,
+            invalid_position: 5.0,
+            decay_per_second: 0.5,
+            kick_threshold: Some(10.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MovementConfig {
+    /// units per second
+    pub max_speed: f32,
+    /// extra time granted on top of the elapsed time to absorb network jitter
+    pub latency_allowance_ms: u64,
+    /// crossing an edge re-enters from the opposite one, like the client does
+    pub wrap_around: bool,
+    pub suspicion: SuspicionPolicy,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            // client moves 6 units per frame on each axis at 60 fps
+            max_speed: 520.0,
+            latency_allowance_ms: 100,
+            wrap_around: true,
+            suspicion: SuspicionPolicy::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovementFlag {
+    SpeedViolation,
+    ImpossibleControls,
+    OutOfBounds,
+    /// NaN or infinite coordinates, the previous position is kept
+    InvalidPosition,
+}
+
+#[derive(Debug)]
+pub struct MovementCheck {
+    /// position after clamping and speed correction
+    pub position: ClientPosition,
+    pub flags: Vec<MovementFlag>,
+    pub suspicion: f32,
+    pub kick: bool,
+}
+
+#[derive(Debug)]
+struct MovementTrack {
+    position: ClientPosition,
+    updated_at: SimTime,
+    suspicion: f32,
+}
+
+/// Sanity checks for client reported positions, until movement is simulated on the server
+#[derive(Debug)]
+pub struct MovementValidator {
+    config: MovementConfig,
+    bounds: WorldBounds,
+    tracks: HashMap<usize, MovementTrack>,
+}
+
+impl MovementValidator {
+    pub fn new(config: MovementConfig, bounds: WorldBounds) -> Self {
+        Self {
+            config,
+            bounds,
+            tracks: HashMap::new(),
+        }
+    }
+
+    pub fn track(&mut self, player_id: usize, position: ClientPosition, now: SimTime) {
+        self.tracks.insert(player_id, MovementTrack {
+            position,
+            updated_at: now,
+            suspicion: 0.0,
+        });
+    }
+
+    pub fn forget(&mut self, player_id: usize) {
+        self.tracks.remove(&player_id);
+    }
+
+    /// overrides the last known position, e.g. after the world pushed the player out of an obstacle
+    pub fn reposition(&mut self, player_id: usize, position: ClientPosition) {
+        if let Some(track) = self.tracks.get_mut(&player_id) {
+            track.position = position;
+        }
+    }
+
+    /// returns `None` for players that are not tracked
+    pub fn validate(&mut self, player_id: usize, claimed: ClientPosition, controls: &ClientControls, now: SimTime) -> Option<MovementCheck> {
+        let config = &self.config;
+        let bounds = &self.bounds;
+        let track = self.tracks.get_mut(&player_id)?;
+        let elapsed = now.saturating_sub(track.updated_at);
+        let mut flags = Vec::new();
+
+        track.suspicion = (track.suspicion - config.suspicion.decay_per_second * elapsed.as_secs_f32()).max(0.0);
+
+        if (controls.up && controls.down) || (controls.left && controls.right) {
+            flags.push(MovementFlag::ImpossibleControls);
+        }
+
+        let mut position = claimed;
+        if !position.x.is_finite() || !position.y.is_finite() {
+            flags.push(MovementFlag::InvalidPosition);
+            position = track.position;
+        } else if !bounds.contains(position) {
+            flags.push(MovementFlag::OutOfBounds);
+            position = bounds.clamp(position);
+        }
+
+        let (dx, dy) = displacement(config.wrap_around, bounds, track.position, position);
+        let distance = (dx * dx + dy * dy).sqrt();
+        let allowed = config.max_speed * (elapsed + Duration::from_millis(config.latency_allowance_ms)).as_secs_f32();
+        if distance > allowed {
+            flags.push(MovementFlag::SpeedViolation);
+            let scale = allowed / distance;
+            let corrected = ClientPosition {
+                x: track.position.x + dx * scale,
+                y: track.position.y + dy * scale,
+            };
+            position = if config.wrap_around {
+                bounds.wrap(corrected)
+            } else {
+                bounds.clamp(corrected)
+            };
+        }
+
+        for flag in &flags {
+            track.suspicion += match flag {
+                MovementFlag::SpeedViolation => config.suspicion.speed_violation,
+                MovementFlag::ImpossibleControls => config.suspicion.impossible_controls,
+                MovementFlag::OutOfBounds => config.suspicion.out_of_bounds,
+                MovementFlag::InvalidPosition => config.suspicion.invalid_position,
+            };
+        }
+        track.position = position;
+        track.updated_at = now;
+
+        Some(MovementCheck {
+            position,
+            flags,
+            suspicion: track.suspicion,
+            kick: config.suspicion.kick_threshold.is_some_and(|threshold| track.suspicion >= threshold),
+        })
+    }
+}
+
+/// shortest displacement between two positions, going across the edges when the world wraps around
+fn displacement(wrap_around: bool, bounds: &WorldBounds, from: ClientPosition, to: ClientPosition) -> (f32, f32) {
+    let mut dx = to.x - from.x;
+    let mut dy = to.y - from.y;
+
+    if wrap_around {
+        let width = bounds.max_x - bounds.min_x;
+        let height = bounds.max_y - bounds.min_y;
+        if dx.abs() > width / 2.0 {
+            dx -= width.copysign(dx);
+        }
+        if dy.abs() > height / 2.0 {
+            dy -= height.copysign(dy);
+        }
+    }
+
+    (dx, dy)
+}
@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+use std::mem;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use serde::Deserialize;
+use crate::game_server::compression::SharedFrames;
+use crate::game_server::message_types::PeerPlayerData;
+
+/// websocket close code for peers dropped for not keeping up
+pub const SLOW_CONSUMER_CLOSE_CODE: u16 = 4001;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OutboundConfig {
+    /// messages wait in the peer's outbox once this many bytes are written but not yet taken by the connection
+    pub backlog_bytes: usize,
+    /// how often a peer with waiting messages checks whether the connection caught up
+    pub flush_ms: u64,
+    /// a peer with more messages waiting can't keep up and is disconnected
+    pub max_queued_messages: usize,
+    /// a peer whose connection stays backed up this long is disconnected
+    pub max_lag_ms: u64,
+}
+
+impl Default for OutboundConfig {
+    fn default() -> Self {
+        Self {
+            backlog_bytes: 64 * 1024,
+            flush_ms: 20,
+            max_queued_messages: 1024,
+            max_lag_ms: 10_000,
+        }
+    }
+}
+
+/// Why a peer is disconnected for not keeping up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// more than `max_queued_messages` are waiting
+    Queue,
+    /// the connection has been backed up for `max_lag_ms`
+    Lag,
+}
+
+/// Which waiting messages go out first, from least to most important
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// unreliable, superseded by the player's next position
+    Position,
+    /// scores, the newest message tells the whole story
+    State,
+    /// chat, kills, flags, parties, the lobby and match progress
+    Event,
+    /// who is in the world, everything else refers to it
+    Membership,
+}
+
+impl Priority {
+    const ALL: [Priority; 4] = [Priority::Membership, Priority::Event, Priority::State, Priority::Position];
+
+    pub fn of(data: &PeerPlayerData) -> Self {
+        match data {
+            PeerPlayerData::RemotePeerPositionUpdate { .. } => Priority::Position,
+            PeerPlayerData::ScoreboardUpdate { .. } => Priority::State,
+            // the lobby and the match phase have to arrive in the order they changed
+            PeerPlayerData::LobbyUpdate { .. }
+            | PeerPlayerData::ChatMessage { .. }
+            | PeerPlayerData::MatchPhaseChanged { .. }
+            | PeerPlayerData::PlayerKilled { .. }
+            | PeerPlayerData::MatchSummary { .. }
+            | PeerPlayerData::FlagUpdate { .. }
+            | PeerPlayerData::PartyInvitation { .. }
+            | PeerPlayerData::PartyUpdate { .. } => Priority::Event,
+            PeerPlayerData::RemotePeerJoined { .. }
+            | PeerPlayerData::RemotePeerLeft { .. }
+            | PeerPlayerData::WorldUpdate { .. }
+            | PeerPlayerData::Kicked { .. } => Priority::Membership,
+        }
+    }
+}
+
+/// A message waiting in an outbox
+#[derive(Debug)]
+pub struct Outgoing {
+    pub data: PeerPlayerData,
+    /// set for broadcasts, which the game server encoded once for every peer
+    pub frames: Option<SharedFrames>,
+}
+
+/// Messages for one websocket peer that wait while its connection is backed up. Reliable messages always go out, the
+/// most important first and in order within a priority; a player's queued position is replaced by their next one, a
+/// world update drops all queued positions, and a queued scoreboard or lobby update makes way for the next one of its
+/// kind.
+#[derive(Debug)]
+pub struct Outbox {
+    config: Arc<OutboundConfig>,
+    queues: [VecDeque<Outgoing>; 4],
+    // bytes handed to the websocket context, and bytes the connection has taken from it
+    written: usize,
+    drained: Arc<AtomicUsize>,
+    backed_up_since: Option<Instant>,
+    superseded: u64,
+}
+
+impl Outbox {
+    pub fn new(config: Arc<OutboundConfig>) -> Self {
+        Self {
+            config,
+            queues: Default::default(),
+            written: 0,
+            drained: Arc::new(AtomicUsize::new(0)),
+            backed_up_since: None,
+            superseded: 0,
+        }
+    }
+
+    /// to be bumped with every byte the connection takes from the websocket context
+    pub fn drained_counter(&self) -> Arc<AtomicUsize> {
+        self.drained.clone()
+    }
+
+    pub fn config(&self) -> &OutboundConfig {
+        &self.config
+    }
+
+    pub fn push(&mut self, data: PeerPlayerData) {
+        self.push_outgoing(Outgoing { data, frames: None });
+    }
+
+    /// queues a message along with the frames it was already encoded into
+    pub fn push_outgoing(&mut self, outgoing: Outgoing) {
+        match &outgoing.data {
+            PeerPlayerData::RemotePeerPositionUpdate { player_id, .. } => {
+                let player_id = *player_id;
+                let positions = &mut self.queues[Priority::Position as usize];
+                if let Some(queued) = positions.iter_mut().find(|queued| position_of(&queued.data) == Some(player_id)) {
+                    *queued = outgoing;
+                    self.superseded += 1;
+                    return;
+                }
+            }
+            PeerPlayerData::ScoreboardUpdate { .. } => {
+                let state = &mut self.queues[Priority::State as usize];
+                if let Some(queued) = state.iter_mut().find(|queued| mem::discriminant(&queued.data) == mem::discriminant(&outgoing.data)) {
+                    *queued = outgoing;
+                    self.superseded += 1;
+                    return;
+                }
+            }
+            // the new update goes to the back, so it stays behind the events queued before it
+            PeerPlayerData::LobbyUpdate { .. } => {
+                let events = &mut self.queues[Priority::Event as usize];
+                let before = events.len();
+                events.retain(|queued| !matches!(queued.data, PeerPlayerData::LobbyUpdate { .. }));
+                self.superseded += (before - events.len()) as u64;
+            }
+            // it carries every player's current position, the queued ones are older
+            PeerPlayerData::WorldUpdate { .. } => {
+                let positions = &mut self.queues[Priority::Position as usize];
+                self.superseded += positions.len() as u64;
+                positions.clear();
+            }
+            // a position arriving after the player left would bring them back
+            PeerPlayerData::RemotePeerLeft { player_id } => {
+                let player_id = *player_id;
+                self.queues[Priority::Position as usize].retain(|queued| position_of(&queued.data) != Some(player_id));
+            }
+            _ => {}
+        }
+        self.queues[Priority::of(&outgoing.data) as usize].push_back(outgoing);
+    }
+
+    /// the next message to write, if the connection isn't backed up
+    pub fn pop(&mut self) -> Option<PeerPlayerData> {
+        self.pop_outgoing().map(|outgoing| outgoing.data)
+    }
+
+    /// like `pop`, with the frames the message was pushed with
+    pub fn pop_outgoing(&mut self) -> Option<Outgoing> {
+        if self.is_backed_up() {
+            return None;
+        }
+        Priority::ALL.iter().find_map(|priority| self.queues[*priority as usize].pop_front())
+    }
+
+    /// `frame_len` bytes were handed to the websocket context
+    pub fn record_written(&mut self, frame_len: usize) {
+        self.written += frame_len;
+    }
+
+    /// bytes written but not yet taken by the connection
+    pub fn backlog(&self) -> usize {
+        self.written.saturating_sub(self.drained.load(Ordering::Relaxed))
+    }
+
+    pub fn is_backed_up(&self) -> bool {
+        self.backlog() >= self.config.backlog_bytes
+    }
+
+    /// messages waiting
+    pub fn len(&self) -> usize {
+        self.queues.iter().map(VecDeque::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queues.iter().all(VecDeque::is_empty)
+    }
+
+    /// Fails once the peer can't keep up: too many messages are waiting, or the connection has been backed up for too
+    /// long. Called after every flush.
+    pub fn check(&mut self, now: Instant) -> Result<(), Overflow> {
+        if self.len() > self.config.max_queued_messages {
+            return Err(Overflow::Queue);
+        }
+        if !self.is_backed_up() {
+            self.backed_up_since = None;
+            return Ok(());
+        }
+        let since = *self.backed_up_since.get_or_insert(now);
+        if now.duration_since(since) >= Duration::from_millis(self.config.max_lag_ms) {
+            return Err(Overflow::Lag);
+        }
+        Ok(())
+    }
+
+    /// position and state updates replaced before they went out
+    pub fn superseded(&self) -> u64 {
+        self.superseded
+    }
+}
+
+/// size on the wire of an unmasked binary frame carrying `payload_len` bytes
+pub fn frame_len(payload_len: usize) -> usize {
+    let header = match payload_len {
+        0..=125 => 2,
+        126..=65_535 => 4,
+        _ => 10,
+    };
+    header + payload_len
+}
+
+fn position_of(data: &PeerPlayerData) -> Option<usize> {
+    match data {
+        PeerPlayerData::RemotePeerPositionUpdate { player_id, .. } => Some(*player_id),
+        _ => None,
+    }
+}
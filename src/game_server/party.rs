@@ -0,0 +1,263 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use actix::Addr;
+use serde::Deserialize;
+use crate::game_server::game_server::GameServer;
+use crate::game_server::simulation::SimTime;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PartyConfig {
+    /// most players in one party, leader included
+    pub max_size: usize,
+    /// unanswered invites expire after this long
+    pub invite_timeout_secs: u64,
+    /// members with a profile keep their place this long after disconnecting, as does a seat held for them
+    pub rejoin_secs: u64,
+}
+
+impl Default for PartyConfig {
+    fn default() -> Self {
+        Self {
+            max_size: 4,
+            invite_timeout_secs: 60,
+            rejoin_secs: 60,
+        }
+    }
+}
+
+/// Players who want to play together, `members` starts with the leader. Members are named by their profile id, or by a
+/// key of their connection when they play without a profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Party {
+    pub leader: String,
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartyError {
+    InviteSelf,
+    NotLeader,
+    AlreadyInParty,
+    PartyFull,
+    NoInvite,
+}
+
+impl PartyError {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PartyError::InviteSelf => "invite_self",
+            PartyError::NotLeader => "not_leader",
+            PartyError::AlreadyInParty => "already_in_party",
+            PartyError::PartyFull => "party_full",
+            PartyError::NoInvite => "no_invite",
+        }
+    }
+}
+
+impl fmt::Display for PartyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Unanswered invites of a room, by invited player and leader. Invites name players by their id in the room, so unlike
+/// parties they end with it.
+#[derive(Debug)]
+pub struct PartyInvites {
+    timeout: Duration,
+    // invited player and leader to when the invite was sent
+    sent: HashMap<(usize, usize), SimTime>,
+}
+
+impl PartyInvites {
+    pub fn new(config: &PartyConfig) -> Self {
+        Self {
+            timeout: Duration::from_secs(config.invite_timeout_secs),
+            sent: HashMap::new(),
+        }
+    }
+
+    pub fn send(&mut self, leader_id: usize, target_id: usize, now: SimTime) {
+        self.expire(now);
+        self.sent.insert((target_id, leader_id), now);
+    }
+
+    /// removes the invite, `false` if there was none or it expired
+    pub fn take(&mut self, player_id: usize, leader_id: usize, now: SimTime) -> bool {
+        self.expire(now);
+        self.sent.remove(&(player_id, leader_id)).is_some()
+    }
+
+    /// drops the invites the player sent or got
+    pub fn forget(&mut self, player_id: usize) {
+        self.sent.retain(|(invited, invited_by), _| *invited != player_id && *invited_by != player_id);
+    }
+
+    fn expire(&mut self, now: SimTime) {
+        let timeout = self.timeout;
+        self.sent.retain(|_, sent| now.saturating_sub(*sent) < timeout);
+    }
+}
+
+/// The parties of the whole server, keyed by leader, and the room each member plays in
+///
+/// Parties outlive rooms: a member with a profile who disconnects keeps their place for `rejoin_secs`, so the
+/// matchmaker can bring the party back together in one public room. Members without a profile can't come back as
+/// themselves and leave their party with the room.
+#[derive(Debug)]
+pub struct PartyRegistry {
+    config: PartyConfig,
+    state: Mutex<PartyState>,
+    next_guest: AtomicUsize,
+}
+
+#[derive(Debug, Default)]
+struct PartyState {
+    parties: BTreeMap<String, Party>,
+    rooms: HashMap<String, Addr<GameServer>>,
+    // members who disconnected and when
+    away: HashMap<String, Instant>,
+}
+
+impl PartyState {
+    fn party_of(&self, member: &str) -> Option<&Party> {
+        self.parties.values().find(|party| party.members.iter().any(|id| id == member))
+    }
+
+    fn leave(&mut self, member: &str) -> Option<Party> {
+        self.away.remove(member);
+        let leader = self.party_of(member)?.leader.clone();
+        let mut party = self.parties.remove(&leader)?;
+        party.members.retain(|id| id != member);
+        if let Some(next_leader) = party.members.first() {
+            party.leader = next_leader.clone();
+        }
+        if party.members.len() > 1 {
+            self.parties.insert(party.leader.clone(), party.clone());
+        } else {
+            for id in &party.members {
+                self.away.remove(id);
+            }
+        }
+        Some(party)
+    }
+}
+
+impl PartyRegistry {
+    pub fn new(config: PartyConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(PartyState::default()),
+            next_guest: AtomicUsize::new(1),
+        }
+    }
+
+    pub fn config(&self) -> &PartyConfig {
+        &self.config
+    }
+
+    /// names a member without a profile, unique for the lifetime of the server
+    pub fn guest_key(&self) -> String {
+        format!("guest-{}", self.next_guest.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// the party the member is in, if any
+    pub fn party_of(&self, member: &str) -> Option<Party> {
+        self.state().party_of(member).cloned()
+    }
+
+    /// the room the member plays in, if any
+    pub fn room_of(&self, member: &str) -> Option<Addr<GameServer>> {
+        self.state().rooms.get(member).cloned()
+    }
+
+    /// checks that `leader` may invite `target` into their party, a player outside any party leads a new one
+    pub fn check_invite(&self, leader: &str, target: &str) -> Result<(), PartyError> {
+        if leader == target {
+            return Err(PartyError::InviteSelf);
+        }
+        let state = self.state();
+        if let Some(party) = state.party_of(leader) {
+            if party.leader != leader {
+                return Err(PartyError::NotLeader);
+            }
+            if party.members.len() >= self.config.max_size {
+                return Err(PartyError::PartyFull);
+            }
+        }
+        if state.party_of(target).is_some() {
+            return Err(PartyError::AlreadyInParty);
+        }
+        Ok(())
+    }
+
+    /// Adds `member` to the leader's party, starting it if needed, and returns it
+    pub fn join(&self, leader: &str, member: &str) -> Result<Party, PartyError> {
+        let mut state = self.state();
+        if state.party_of(member).is_some() {
+            return Err(PartyError::AlreadyInParty);
+        }
+        // the leader may have joined another party since inviting
+        if state.party_of(leader).is_some_and(|party| party.leader != leader) {
+            return Err(PartyError::NoInvite);
+        }
+        if state.party_of(leader).map_or(1, |party| party.members.len()) >= self.config.max_size {
+            return Err(PartyError::PartyFull);
+        }
+        let party = state.parties
+            .entry(leader.to_string())
+            .or_insert_with(|| Party { leader: leader.to_string(), members: vec![leader.to_string()] });
+        party.members.push(member.to_string());
+        Ok(party.clone())
+    }
+
+    /// Takes the member out of their party and returns the remaining members: the next member leads once the leader
+    /// leaves, and a party left with one member is disbanded.
+    pub fn leave(&self, member: &str) -> Option<Party> {
+        self.state().leave(member)
+    }
+
+    /// the member started playing in `room`
+    pub fn entered(&self, member: &str, room: Addr<GameServer>) {
+        let mut state = self.state();
+        state.away.remove(member);
+        state.rooms.insert(member.to_string(), room);
+    }
+
+    /// The member left `room`. A guest leaves their party too, which is returned, a member with a profile keeps their
+    /// place for `rejoin_secs`.
+    pub fn left(&self, member: &str, room: &Addr<GameServer>, guest: bool) -> Option<Party> {
+        let mut state = self.state();
+        // the member may already play in another room
+        if state.rooms.get(member) != Some(room) {
+            return None;
+        }
+        state.rooms.remove(member);
+        if guest {
+            return state.leave(member);
+        }
+        if state.party_of(member).is_some() {
+            state.away.insert(member.to_string(), Instant::now());
+        }
+        None
+    }
+
+    /// the state, without the members who have been away for longer than `rejoin_secs`
+    fn state(&self) -> MutexGuard<'_, PartyState> {
+        let mut state = self.state.lock().unwrap();
+        let rejoin = Duration::from_secs(self.config.rejoin_secs);
+        let gone = state.away
+            .iter()
+            .filter(|(_, since)| since.elapsed() >= rejoin)
+            .map(|(member, _)| member.clone())
+            .collect::<Vec<String>>();
+        for member in gone {
+            state.leave(&member);
+        }
+        state
+    }
+}
@@ -1,11 +1,20 @@
-use std::time::Instant;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use crate::game_server::game_server;
 use actix::{Actor, ActorContext, ActorFutureExt, Addr, AsyncContext, ContextFutureSpawner, fut, Handler, Running, StreamHandler, WrapFuture};
+use actix_web::web::Bytes;
 use actix_web_actors::ws;
-use crate::game_server::flatbuffers_utils::{create_peer_joined_bytes, create_peer_left_bytes, create_peer_position_bytes, create_world_update_bytes, read_gameplay_data};
-use crate::game_server::message_types::{Connect, Disconnect, PeerPlayerData, PeerPlayerPositionUpdate};
+use tracing::{debug, field, info, info_span, warn, Span};
+use crate::game_server::chat::ChatChannel;
+use crate::game_server::compression::{FrameEncoder, SharedFrames};
+use crate::game_server::flatbuffers_utils::{create_player_data_bytes, read_gameplay_data};
+use crate::game_server::outbox::{frame_len, Outbox, Outgoing, SLOW_CONSUMER_CLOSE_CODE};
+use crate::game_server::message_types::{Connect, Disconnect, PeerAddr, PeerBroadcast, PeerChatSend, PeerLobbyReady, PeerLobbySetup, PeerPartyInvite, PeerPartyLeave, PeerPartyRespond, PeerPlayerData, PeerPlayerPositionUpdate, PeerRole, PeerWeaponFired};
+use crate::game_server::rate_limiter::{InboundRateLimiter, MessageKind, Verdict};
+use crate::game_server::peer_setup::PeerSetup;
+use crate::game_server::room_registry::Reservation;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ClientControls {
     pub up: bool,
     pub down: bool,
@@ -13,7 +22,7 @@ pub struct ClientControls {
     pub right: bool
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ClientPosition {
     pub x: f32,
     pub y: f32
@@ -25,7 +34,31 @@ pub enum ClientData {
         player_controls: ClientControls,
         player_position: ClientPosition
     },
-    WeaponFired {},
+    WeaponFired {
+        angle: f32,
+        power: f32
+    },
+    ChatSend {
+        channel: ChatChannel,
+        target_player_id: usize,
+        text: String
+    },
+    LobbySetup {
+        name: String,
+        skin: String,
+        team: Option<u8>
+    },
+    LobbyReady {
+        ready: bool
+    },
+    PartyInvite {
+        player_id: usize
+    },
+    PartyRespond {
+        leader_id: usize,
+        accept: bool
+    },
+    PartyLeave,
     Unknown
 }
 
@@ -37,110 +70,395 @@ pub struct Peer {
 
     // game server actor address
     pub game_server_addr: Addr<game_server::GameServer>,
+
+    // span carrying player id, room id and remote address for this connection
+    pub span: Span,
+
+    pub role: PeerRole,
+
+    // team asked for when connecting, players may still be balanced into another one
+    requested_team: Option<u8>,
+
+    // profile the connection authenticated as
+    profile_id: Option<String>,
+
+    // spectators receive world updates this much later than players
+    broadcast_delay: Option<Duration>,
+
+    inbound: InboundLimits,
+
+    // outgoing messages waiting for a backed up connection
+    outbox: Outbox,
+
+    // frames every payload when the client asked for compression
+    compression: Option<FrameEncoder>,
+
+    // seat held in a public room until the game server has taken the player in
+    reservation: Option<Reservation>,
 }
 
 impl Peer {
-    pub fn create(game_server_addr: Addr<game_server::GameServer>) -> Self {
+    pub fn create(setup: PeerSetup, remote_addr: String, rate_limiter: InboundRateLimiter, outbox: Outbox, compression: Option<FrameEncoder>) -> Self {
+        let span = info_span!(
+            "peer",
+            player_id = field::Empty,
+            room_id = field::Empty,
+            remote_addr = %remote_addr,
+            profile_id = setup.profile_id.as_deref(),
+            spectator = setup.role == PeerRole::Spectator,
+        );
         Self {
             // id is re-assigned when connection is established
             id: 0,
             heart_beat: Instant::now(),
-            game_server_addr
+            game_server_addr: setup.game_server_addr,
+            span,
+            role: setup.role,
+            requested_team: setup.team,
+            profile_id: setup.profile_id,
+            broadcast_delay: setup.broadcast_delay,
+            inbound: InboundLimits::new(rate_limiter),
+            outbox,
+            compression,
+            reservation: setup.reservation,
         }
     }
 }
 
+/// Inbound rate limiting state of a peer, whatever its transport
+pub struct InboundLimits {
+    rate_limiter: InboundRateLimiter,
+
+    // latest throttled message per kind, waiting for a token
+    throttled: HashMap<MessageKind, ClientData>,
+}
+
+impl InboundLimits {
+    pub fn new(rate_limiter: InboundRateLimiter) -> Self {
+        Self {
+            rate_limiter,
+            throttled: HashMap::new(),
+        }
+    }
+}
+
+/// A connection that hands client data to a game server, implemented by websocket and UDP peers
+pub trait ClientDataPeer: Actor {
+    fn role(&self) -> PeerRole;
+
+    fn span(&self) -> &Span;
+
+    fn inbound(&mut self) -> &mut InboundLimits;
+
+    /// the game server and the id the peer joined it with
+    fn session(&self) -> (&Addr<game_server::GameServer>, usize);
+
+    /// closes the connection because an inbound limit tripped
+    fn close(&mut self, reason: &'static str, ctx: &mut Self::Context);
+}
+
+/// checks the size of a received frame, `false` if it has to be dropped
+pub fn accept_frame<A: ClientDataPeer>(act: &mut A, frame_len: usize, ctx: &mut A::Context) -> bool {
+    match act.inbound().rate_limiter.check_frame_size(frame_len) {
+        Verdict::Allow => true,
+        Verdict::Disconnect => {
+            warn!(event = "kick", reason = "oversized_frame", frame_len, "closing connection");
+            act.close("oversized_frame", ctx);
+            false
+        }
+        _ => {
+            debug!(event = "rate_limited", reason = "oversized_frame", frame_len, "dropping frame");
+            false
+        }
+    }
+}
+
+/// applies the inbound rate limits before handing the data over to the game server
+pub fn handle_client_data<A>(act: &mut A, data: ClientData, ctx: &mut A::Context)
+where
+    A: ClientDataPeer,
+    A::Context: AsyncContext<A>,
+{
+    let kind = MessageKind::of(&data);
+
+    if act.role() == PeerRole::Spectator {
+        debug!(?kind, "ignoring input from spectator");
+        return;
+    }
+
+    // a retry is already scheduled, the newer message simply replaces the pending one
+    let inbound = act.inbound();
+    if let Some(pending) = inbound.throttled.get_mut(&kind) {
+        *pending = data;
+        return;
+    }
+
+    match inbound.rate_limiter.check(kind, Instant::now()) {
+        Verdict::Allow => {
+            let (game_server_addr, player_id) = act.session();
+            forward_client_data(game_server_addr, player_id, data);
+        }
+        Verdict::Drop => {
+            debug!(event = "rate_limited", ?kind, action = "drop", "dropping message");
+        }
+        Verdict::Throttle(wait) => {
+            debug!(event = "rate_limited", ?kind, action = "throttle", wait_ms = wait.as_millis() as u64, "deferring message");
+            inbound.throttled.insert(kind, data);
+            ctx.run_later(wait, move |act, ctx| {
+                let _entered = act.span().clone().entered();
+                if let Some(data) = act.inbound().throttled.remove(&kind) {
+                    handle_client_data(act, data, ctx);
+                }
+            });
+        }
+        Verdict::Disconnect => {
+            warn!(event = "kick", reason = "rate_limited", ?kind, "closing connection");
+            act.close("rate_limited", ctx);
+        }
+    }
+}
+
+/// hands data a peer received over to its game server, shared by websocket and UDP peers
+pub fn forward_client_data(game_server_addr: &Addr<game_server::GameServer>, player_id: usize, data: ClientData) {
+    match data {
+        ClientData::PlayerMoved {  player_position, player_controls } => {
+            game_server_addr.do_send(PeerPlayerPositionUpdate {
+                player_position,
+                player_controls,
+                player_id
+            });
+        }
+        ClientData::WeaponFired { angle, power } => {
+            game_server_addr.do_send(PeerWeaponFired {
+                player_id,
+                angle,
+                power
+            });
+        }
+        ClientData::ChatSend { channel, target_player_id, text } => {
+            game_server_addr.do_send(PeerChatSend {
+                player_id,
+                channel,
+                target_player_id,
+                text
+            });
+        }
+        ClientData::LobbySetup { name, skin, team } => {
+            game_server_addr.do_send(PeerLobbySetup {
+                player_id,
+                name,
+                skin,
+                team
+            });
+        }
+        ClientData::LobbyReady { ready } => {
+            game_server_addr.do_send(PeerLobbyReady {
+                player_id,
+                ready
+            });
+        }
+        ClientData::PartyInvite { player_id: target_id } => {
+            game_server_addr.do_send(PeerPartyInvite {
+                player_id,
+                target_id
+            });
+        }
+        ClientData::PartyRespond { leader_id, accept } => {
+            game_server_addr.do_send(PeerPartyRespond {
+                player_id,
+                leader_id,
+                accept
+            });
+        }
+        ClientData::PartyLeave => {
+            game_server_addr.do_send(PeerPartyLeave {
+                player_id
+            });
+        }
+        ClientData::Unknown => {}
+    }
+}
+
+impl ClientDataPeer for Peer {
+    fn role(&self) -> PeerRole {
+        self.role
+    }
+
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn inbound(&mut self) -> &mut InboundLimits {
+        &mut self.inbound
+    }
+
+    fn session(&self) -> (&Addr<game_server::GameServer>, usize) {
+        (&self.game_server_addr, self.id)
+    }
+
+    fn close(&mut self, _reason: &'static str, ctx: &mut Self::Context) {
+        ctx.stop();
+    }
+}
+
 impl Actor for Peer {
     type Context = ws::WebsocketContext<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
-        let peer_addr = ctx.address();
+        let _entered = self.span.clone().entered();
+        info!(event = "connect", "peer connected");
+
+        ctx.run_interval(Duration::from_millis(self.outbox.config().flush_ms), |act, ctx| act.flush(ctx));
+        let addr = ctx.address();
+        let peer_addr = PeerAddr { data: addr.clone().recipient(), broadcast: addr.recipient(), compress: self.compression.is_some() };
         self.game_server_addr
-            .send(Connect { peer_addr: peer_addr.recipient() })
+            .send(Connect { peer_addr, role: self.role, team: self.requested_team, profile_id: self.profile_id.clone() })
             .into_actor(self)
             .then(|res, act, ctx| {
                 match res {
-                    Ok(res) => {
-                        act.id = res;
+                    Ok(session) => {
+                        act.id = session.player_id;
+                        act.span.record("player_id", session.player_id);
+                        act.span.record("room_id", session.room_id);
+                        act.reservation = None;
                     },
                     // something is wrong with chat server
-                    _ => ctx.stop(),
+                    Err(error) => {
+                        warn!(parent: &act.span, event = "kick", reason = "join_failed", %error, "could not join game server");
+                        ctx.stop();
+                    }
                 }
                 fut::ready(())
             })
-            .then(|_, act,_| {
-                println!("actor connected! id: {:?}", act.id);
-                fut::ready(())
-            })
             .wait(ctx);
     }
 
     fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        let _entered = self.span.clone().entered();
+        info!(event = "disconnect", superseded = self.outbox.superseded(), "peer disconnected");
+
         // notify game server
         self.game_server_addr.do_send(Disconnect { id: self.id });
         Running::Stop
     }
 }
 
+impl Peer {
+    fn send_player_data(&mut self, msg: Outgoing, ctx: &mut ws::WebsocketContext<Self>) {
+        if let PeerPlayerData::Kicked { reason } = msg.data {
+            let _entered = self.span.clone().entered();
+            warn!(event = "kick", reason, "closing connection");
+            ctx.close(Some(ws::CloseReason {
+                code: ws::CloseCode::Policy,
+                description: Some(reason.to_string()),
+            }));
+            ctx.stop();
+            return;
+        }
+
+        self.outbox.push_outgoing(msg);
+        self.flush(ctx);
+    }
+
+    /// writes waiting messages until the connection backs up, and drops the peer once it can't keep up
+    fn flush(&mut self, ctx: &mut ws::WebsocketContext<Self>) {
+        if ctx.state().stopping() {
+            return;
+        }
+        while let Some(Outgoing { data, frames }) = self.outbox.pop_outgoing() {
+            // a broadcast was encoded by the game server, other messages are encoded here
+            let bytes = match (frames, &self.compression) {
+                (Some(frames), None) => frames.raw,
+                (Some(SharedFrames { raw, compressed: Some(frame) }), Some(encoder)) => {
+                    encoder.count(raw.len(), &frame);
+                    frame
+                }
+                (_, compression) => {
+                    let Some(bytes) = create_player_data_bytes(&data) else {
+                        continue;
+                    };
+                    match compression {
+                        Some(encoder) => encoder.encode(bytes),
+                        None => Bytes::from(bytes),
+                    }
+                }
+            };
+            self.outbox.record_written(frame_len(bytes.len()));
+            ctx.binary(bytes);
+        }
+
+        if let Err(overflow) = self.outbox.check(Instant::now()) {
+            let _entered = self.span.clone().entered();
+            warn!(event = "kick", reason = "slow_consumer", ?overflow, backlog = self.outbox.backlog(), queued = self.outbox.len(), "closing connection");
+            ctx.close(Some(ws::CloseReason {
+                code: ws::CloseCode::Other(SLOW_CONSUMER_CLOSE_CODE),
+                description: Some("slow_consumer".to_string()),
+            }));
+            ctx.stop();
+        }
+    }
+}
+
 /// Handle messages from game server, we simply send it to peer websocket
 impl Handler<PeerPlayerData> for Peer {
     type Result = ();
 
     fn handle(&mut self, msg: PeerPlayerData, ctx: &mut Self::Context) {
-        // println!("Peer {:?} - game_server::PeerPlayerData {:?}", self.id, msg);
-
-        match msg {
-            PeerPlayerData::RemotePeerJoined { player_id, player_position } => {
-                let bytes = create_peer_joined_bytes(player_id, player_position);
-                ctx.binary(bytes);
-            }
-            PeerPlayerData::RemotePeerLeft { player_id } => {
-                let bytes = create_peer_left_bytes(player_id);
-                ctx.binary(bytes);
-            }
-            PeerPlayerData::RemotePeerPositionUpdate { player_id, player_position } => {
-                let bytes = create_peer_position_bytes(player_id, player_position);
-                ctx.binary(bytes);
+        let msg = Outgoing { data: msg, frames: None };
+        match self.broadcast_delay {
+            Some(delay) if !matches!(msg.data, PeerPlayerData::Kicked { .. }) => {
+                ctx.run_later(delay, move |act, ctx| act.send_player_data(msg, ctx));
             }
-            PeerPlayerData::WorldUpdate { world_data } => {
-                let bytes = create_world_update_bytes(world_data);
-                ctx.binary(bytes);
+            _ => self.send_player_data(msg, ctx),
+        }
+    }
+}
+
+impl Handler<PeerBroadcast> for Peer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerBroadcast, ctx: &mut Self::Context) {
+        let msg = Outgoing { data: msg.data, frames: Some(msg.frames) };
+        match self.broadcast_delay {
+            Some(delay) => {
+                ctx.run_later(delay, move |act, ctx| act.send_player_data(msg, ctx));
             }
+            None => self.send_player_data(msg, ctx),
         }
     }
 }
 
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Peer {
     fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let _entered = self.span.clone().entered();
         let msg = match msg {
-            Err(_) => {
+            Err(error) => {
+                warn!(event = "kick", reason = "protocol_error", %error, "closing connection");
                 ctx.stop();
                 return;
             },
             Ok(msg) => msg,
         };
 
-        // println!("websocket message {:?}", msg);
-
         match msg {
             ws::Message::Text(text) => {
-                println!("received from client (text), {:?}", text);
+                debug!(frame_len = text.len(), "ignoring text frame");
             }
             ws::Message::Binary(bytes) => {
-                let gameplay_data = read_gameplay_data(&bytes);
-                match gameplay_data {
-                    ClientData::PlayerMoved {  player_position, .. } => {
-                        self.game_server_addr.do_send(PeerPlayerPositionUpdate {
-                            player_position: player_position,
-                            player_id: self.id
-                        });
+                if !accept_frame(self, bytes.len(), ctx) {
+                    return;
+                }
+
+                match read_gameplay_data(&bytes) {
+                    Ok(data) => handle_client_data(self, data, ctx),
+                    Err(error) => {
+                        warn!(event = "decode_error", frame_len = bytes.len(), %error, "dropping malformed frame");
                     }
-                    ClientData::WeaponFired { .. } => {}
-                    ClientData::Unknown => {}
                 }
             }
             ws::Message::Ping(msg) => {
                 self.heart_beat = Instant::now();
+                self.outbox.record_written(frame_len(msg.len()));
                 ctx.pong(&msg);
             }
             ws::Message::Pong(_) => {
@@ -151,6 +469,7 @@ impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for Peer {
                 ctx.stop();
             }
             ws::Message::Continuation(_) => {
+                warn!(event = "kick", reason = "continuation_frame", "closing connection");
                 ctx.stop();
             }
             ws::Message::Nop => {}
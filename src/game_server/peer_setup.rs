@@ -0,0 +1,99 @@
+use std::fmt;
+use std::io;
+use std::time::Duration;
+use actix::Addr;
+use serde::Deserialize;
+use crate::config::SpectatorConfig;
+use crate::game_server::game_server::GameServer;
+use crate::game_server::message_types::PeerRole;
+use crate::game_server::profile::ProfileStore;
+use crate::game_server::room_registry::{Reservation, RoomError, RoomRegistry};
+
+/// What a client asks for when it connects, the query string of `/ws` and the payload of a UDP `Connect`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ConnectQuery {
+    /// join as a spectator instead of a player
+    pub spectate: bool,
+    /// name of a replay file to play back instead of joining the game
+    pub replay: Option<String>,
+    /// team to join, ignored for spectators
+    pub team: Option<u8>,
+    /// join code of a private room, without one matchmaking picks a public room
+    pub room: Option<String>,
+    /// password of a protected private room
+    pub password: Option<String>,
+    /// profile token, joins as that profile instead of anonymously
+    pub token: Option<String>,
+    /// every frame the server sends starts with a flag byte, and large payloads are deflated
+    pub compress: bool,
+}
+
+impl ConnectQuery {
+    /// parses a query string like `room=ABCDEF&token=...`
+    pub fn parse(query: &str) -> Result<Self, serde_urlencoded::de::Error> {
+        serde_urlencoded::from_str(query)
+    }
+}
+
+/// Why a connection can't join
+#[derive(Debug)]
+pub enum SetupError {
+    Room(RoomError),
+    UnknownToken,
+    Store(io::Error),
+}
+
+impl fmt::Display for SetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetupError::Room(error) => error.fmt(f),
+            SetupError::UnknownToken => write!(f, "unknown profile token"),
+            SetupError::Store(error) => write!(f, "profile store failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SetupError {}
+
+/// What a connection joins as, shared by websocket and UDP peers
+#[derive(Debug)]
+pub struct PeerSetup {
+    pub game_server_addr: Addr<GameServer>,
+    pub role: PeerRole,
+    pub team: Option<u8>,
+    pub profile_id: Option<String>,
+    pub broadcast_delay: Option<Duration>,
+    /// the seat matchmaking holds in a public room, to be dropped once the room counted the peer
+    pub reservation: Option<Reservation>,
+}
+
+impl PeerSetup {
+    /// authenticates the profile asked for in `query` and picks the room, by its code or by matchmaking
+    pub fn resolve(query: &ConnectQuery, room_registry: &RoomRegistry, profile_store: &dyn ProfileStore, spectator_config: &SpectatorConfig) -> Result<Self, SetupError> {
+        let profile = match &query.token {
+            Some(token) => match profile_store.authenticate(token).map_err(SetupError::Store)? {
+                Some(profile) => Some(profile),
+                None => return Err(SetupError::UnknownToken),
+            },
+            None => None,
+        };
+        let (game_server_addr, reservation) = match &query.room {
+            Some(code) => (room_registry.join(code, query.password.as_deref()).map_err(SetupError::Room)?, None),
+            None if query.spectate => (room_registry.spectate(), None),
+            None => {
+                let (addr, reservation) = room_registry.matchmake(profile.as_ref()).map_err(SetupError::Room)?;
+                (addr, Some(reservation))
+            }
+        };
+
+        let (role, broadcast_delay) = if query.spectate {
+            let delay = Duration::from_millis(spectator_config.broadcast_delay_ms);
+            (PeerRole::Spectator, Some(delay).filter(|delay| !delay.is_zero()))
+        } else {
+            (PeerRole::Player, None)
+        };
+        let profile_id = profile.map(|profile| profile.id);
+        Ok(Self { game_server_addr, role, team: query.team, profile_id, broadcast_delay, reservation })
+    }
+}
@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tracing::warn;
+use crate::game_server::match_state::{MatchSummary, PlayerScore};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProfileConfig {
+    /// SQLite database file profiles are stored in, takes precedence over `directory`
+    pub database: Option<PathBuf>,
+    /// where profiles are stored as JSON files, they are only kept in memory without this or `database`
+    pub directory: Option<PathBuf>,
+    /// longer names are cut
+    pub max_name_length: usize,
+}
+
+impl Default for ProfileConfig {
+    fn default() -> Self {
+        Self {
+            database: None,
+            directory: None,
+            max_name_length: 16,
+        }
+    }
+}
+
+/// A player's identity across connections
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    pub created_at_ms: u64,
+    #[serde(default)]
+    pub settings: ProfileSettings,
+    #[serde(default)]
+    pub stats: LifetimeStats,
+    /// skill rating, `None` until the first rated match
+    #[serde(default)]
+    pub rating: Option<f64>,
+    /// every rating change, oldest first
+    #[serde(default)]
+    pub rating_history: Vec<RatingChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingChange {
+    pub ended_at_ms: u64,
+    pub game_mode: String,
+    pub before: f64,
+    pub after: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ProfileSettings {
+    pub skin: String,
+    /// team asked for when joining without `?team=`
+    pub team: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LifetimeStats {
+    pub matches: u32,
+    pub wins: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub points: i64,
+}
+
+impl LifetimeStats {
+    pub fn add(&mut self, result: &MatchResult) {
+        self.matches += 1;
+        if result.won {
+            self.wins += 1;
+        }
+        self.kills += result.kills;
+        self.deaths += result.deaths;
+        self.points += result.points as i64;
+    }
+}
+
+/// A finished match, only players with a profile are kept
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchRecord {
+    pub game_mode: String,
+    pub ended_at_ms: u64,
+    pub duration_ms: u64,
+    pub results: Vec<MatchResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchResult {
+    pub profile_id: String,
+    pub team: u8,
+    pub kills: u32,
+    pub deaths: u32,
+    pub points: i32,
+    /// the player won, or their team did
+    pub won: bool,
+}
+
+impl MatchResult {
+    pub fn new(profile_id: &str, score: &PlayerScore, summary: &MatchSummary) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+            team: score.team,
+            kills: score.kills,
+            deaths: score.deaths,
+            points: score.points,
+            won: summary.winner_id == Some(score.player_id) || summary.winning_team == Some(score.team),
+        }
+    }
+}
+
+/// milliseconds since the unix epoch
+pub fn unix_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Where profiles and match results live. Implementations have to be safe to share between the HTTP workers and every room.
+pub trait ProfileStore: fmt::Debug + Send + Sync {
+    fn load(&self, profile_id: &str) -> io::Result<Option<Profile>>;
+
+    /// the profile `token` was issued for
+    fn authenticate(&self, token: &str) -> io::Result<Option<Profile>>;
+
+    /// stores a new profile along with the token that proves its ownership
+    fn create(&self, profile: &Profile, token: &str) -> io::Result<()>;
+
+    /// Changes a stored profile in place, so changes from different rooms and requests don't overwrite each other.
+    /// Returns the changed profile, `None` if there is no such profile.
+    fn update(&self, profile_id: &str, change: &mut dyn FnMut(&mut Profile)) -> io::Result<Option<Profile>>;
+
+    fn record_match(&self, record: &MatchRecord) -> io::Result<()>;
+
+    /// matches that ended at or after `since_ms`, oldest first
+    fn matches_since(&self, since_ms: u64) -> io::Result<Vec<MatchRecord>>;
+
+    /// how many matches have been recorded, goes up with every `record_match`
+    fn matches_recorded(&self) -> io::Result<usize>;
+}
+
+/// Creates and stores a profile, returns it with its token
+pub fn create_profile(store: &dyn ProfileStore, name: &str) -> io::Result<(Profile, String)> {
+    let mut rng = rand::thread_rng();
+    let profile = Profile {
+        id: (&mut rng).sample_iter(Alphanumeric).take(16).map(char::from).collect(),
+        name: name.to_string(),
+        created_at_ms: unix_ms(),
+        settings: ProfileSettings::default(),
+        stats: LifetimeStats::default(),
+        rating: None,
+        rating_history: Vec::new(),
+    };
+    let token = (&mut rng).sample_iter(Alphanumeric).take(32).map(char::from).collect::<String>();
+    store.create(&profile, &token)?;
+    Ok((profile, token))
+}
+
+/// the store picked by the config
+pub fn open_store(config: &ProfileConfig) -> io::Result<Box<dyn ProfileStore>> {
+    Ok(match (&config.database, &config.directory) {
+        (Some(database), _) => Box::new(SqliteProfileStore::open(database)?),
+        (None, Some(directory)) => Box::new(FileProfileStore::open(directory)?),
+        (None, None) => Box::new(MemoryProfileStore::default()),
+    })
+}
+
+/// match results of `FileProfileStore`, one JSON object per line
+const MATCHES_FILE: &str = "matches.jsonl";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredProfile {
+    /// the token itself is only handed out once, when the profile is created
+    #[serde(default)]
+    token_hash: String,
+    /// plaintext token of a profile stored before tokens were hashed, replaced by its hash when the store opens
+    #[serde(default, skip_serializing)]
+    token: Option<String>,
+    profile: Profile,
+}
+
+impl StoredProfile {
+    fn new(profile: &Profile, token: &str) -> Self {
+        Self { token_hash: hash_token(token), token: None, profile: profile.clone() }
+    }
+
+    /// hashes a plaintext token left by an older version, true if there was one
+    fn hash_legacy_token(&mut self) -> bool {
+        match self.token.take() {
+            Some(token) => {
+                self.token_hash = hash_token(&token);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// hex encoded SHA-256 of a profile token, tokens are random enough to not need a salt
+fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Keeps profiles for as long as the server runs, and stands in for a real store in tests
+#[derive(Debug, Default)]
+pub struct MemoryProfileStore {
+    records: Mutex<HashMap<String, StoredProfile>>,
+    // profile ids by the hash of their token
+    token_hashes: Mutex<HashMap<String, String>>,
+    matches: Mutex<Vec<MatchRecord>>,
+}
+
+impl MemoryProfileStore {
+    fn insert(&self, record: StoredProfile) {
+        self.token_hashes.lock().unwrap().insert(record.token_hash.clone(), record.profile.id.clone());
+        self.records.lock().unwrap().insert(record.profile.id.clone(), record);
+    }
+
+    fn record(&self, profile_id: &str) -> Option<StoredProfile> {
+        self.records.lock().unwrap().get(profile_id).cloned()
+    }
+}
+
+impl ProfileStore for MemoryProfileStore {
+    fn load(&self, profile_id: &str) -> io::Result<Option<Profile>> {
+        Ok(self.records.lock().unwrap().get(profile_id).map(|record| record.profile.clone()))
+    }
+
+    fn authenticate(&self, token: &str) -> io::Result<Option<Profile>> {
+        let token_hash = hash_token(token);
+        let Some(profile_id) = self.token_hashes.lock().unwrap().get(&token_hash).cloned() else {
+            return Ok(None);
+        };
+        Ok(self.record(&profile_id)
+            .filter(|record| record.token_hash.as_bytes().ct_eq(token_hash.as_bytes()).into())
+            .map(|record| record.profile))
+    }
+
+    fn create(&self, profile: &Profile, token: &str) -> io::Result<()> {
+        self.insert(StoredProfile::new(profile, token));
+        Ok(())
+    }
+
+    fn update(&self, profile_id: &str, change: &mut dyn FnMut(&mut Profile)) -> io::Result<Option<Profile>> {
+        let mut records = self.records.lock().unwrap();
+        let Some(record) = records.get_mut(profile_id) else {
+            return Ok(None);
+        };
+        change(&mut record.profile);
+        Ok(Some(record.profile.clone()))
+    }
+
+    fn record_match(&self, record: &MatchRecord) -> io::Result<()> {
+        self.matches.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+
+    fn matches_since(&self, since_ms: u64) -> io::Result<Vec<MatchRecord>> {
+        Ok(self.matches
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| record.ended_at_ms >= since_ms)
+            .cloned()
+            .collect())
+    }
+
+    fn matches_recorded(&self) -> io::Result<usize> {
+        Ok(self.matches.lock().unwrap().len())
+    }
+}
+
+/// One JSON file per profile in a directory and the match results in `matches.jsonl` next to them, all of it is read
+/// into memory when the store is opened
+#[derive(Debug)]
+pub struct FileProfileStore {
+    directory: PathBuf,
+    cache: MemoryProfileStore,
+    // keeps the files in the same order as the changes
+    writes: Mutex<()>,
+}
+
+impl FileProfileStore {
+    pub fn open(directory: impl AsRef<Path>) -> io::Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+
+        let store = Self { directory, cache: MemoryProfileStore::default(), writes: Mutex::new(()) };
+        for entry in fs::read_dir(&store.directory)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "json") {
+                let mut record: StoredProfile = serde_json::from_slice(&fs::read(&path)?).map_err(|error| invalid_data(&path, error))?;
+                if record.hash_legacy_token() {
+                    store.write(&record)?;
+                }
+                store.cache.insert(record);
+            }
+        }
+
+        let cache = &store.cache;
+        let matches_path = store.directory.join(MATCHES_FILE);
+        if matches_path.exists() {
+            let contents = fs::read_to_string(&matches_path)?;
+            let mut offset = 0;
+            for line in contents.split_inclusive('\n') {
+                let line_start = offset;
+                offset += line.len();
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<MatchRecord>(line) {
+                    Ok(record) => cache.record_match(&record)?,
+                    // a crash while appending leaves half a line at the end, drop it so the next match starts a new line
+                    Err(error) if offset == contents.len() => {
+                        warn!(path = %matches_path.display(), %error, "dropping incomplete last match record");
+                        fs::OpenOptions::new().write(true).open(&matches_path)?.set_len(line_start as u64)?;
+                    }
+                    Err(error) => return Err(invalid_data(&matches_path, error)),
+                }
+            }
+            // a complete record that only lost its newline would be glued to the next one
+            if fs::read(&matches_path)?.last().is_some_and(|byte| *byte != b'\n') {
+                fs::OpenOptions::new().append(true).open(&matches_path)?.write_all(b"\n")?;
+            }
+        }
+        Ok(store)
+    }
+
+    /// writes next to the file and renames it over, so a crash never leaves half a profile behind
+    fn write(&self, record: &StoredProfile) -> io::Result<()> {
+        let path = self.directory.join(format!("{}.json", record.profile.id));
+        let partial_path = path.with_extension("json.partial");
+        fs::write(&partial_path, serde_json::to_vec_pretty(record)?)?;
+        fs::rename(partial_path, path)
+    }
+}
+
+impl ProfileStore for FileProfileStore {
+    fn load(&self, profile_id: &str) -> io::Result<Option<Profile>> {
+        self.cache.load(profile_id)
+    }
+
+    fn authenticate(&self, token: &str) -> io::Result<Option<Profile>> {
+        self.cache.authenticate(token)
+    }
+
+    fn create(&self, profile: &Profile, token: &str) -> io::Result<()> {
+        let record = StoredProfile::new(profile, token);
+        self.write(&record)?;
+        self.cache.insert(record);
+        Ok(())
+    }
+
+    fn update(&self, profile_id: &str, change: &mut dyn FnMut(&mut Profile)) -> io::Result<Option<Profile>> {
+        let _writing = self.writes.lock().unwrap();
+        // the cache only sees the change once it is on disk
+        let Some(mut record) = self.cache.record(profile_id) else {
+            return Ok(None);
+        };
+        change(&mut record.profile);
+        self.write(&record)?;
+        self.cache.insert(record.clone());
+        Ok(Some(record.profile))
+    }
+
+    fn record_match(&self, record: &MatchRecord) -> io::Result<()> {
+        let _writing = self.writes.lock().unwrap();
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.directory.join(MATCHES_FILE))?
+            .write_all(line.as_bytes())?;
+        self.cache.record_match(record)
+    }
+
+    fn matches_since(&self, since_ms: u64) -> io::Result<Vec<MatchRecord>> {
+        self.cache.matches_since(since_ms)
+    }
+
+    fn matches_recorded(&self) -> io::Result<usize> {
+        self.cache.matches_recorded()
+    }
+}
+
+/// Profiles and match results in an SQLite database, both stored as JSON so new fields don't need a migration. Like
+/// `FileProfileStore` everything is read into memory when the store is opened, and only changes touch the database.
+#[derive(Debug)]
+pub struct SqliteProfileStore {
+    connection: Mutex<Connection>,
+    cache: MemoryProfileStore,
+}
+
+impl SqliteProfileStore {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if let Some(directory) = path.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+            fs::create_dir_all(directory)?;
+        }
+        let connection = Connection::open(path).map_err(sql_error)?;
+        connection
+            .execute_batch(
+                "PRAGMA journal_mode = WAL;
+                 CREATE TABLE IF NOT EXISTS profiles (id TEXT PRIMARY KEY, token_hash TEXT NOT NULL UNIQUE, profile TEXT NOT NULL);
+                 CREATE TABLE IF NOT EXISTS matches (id INTEGER PRIMARY KEY AUTOINCREMENT, ended_at_ms INTEGER NOT NULL, record TEXT NOT NULL);",
+            )
+            .map_err(sql_error)?;
+        hash_legacy_tokens(&connection)?;
+
+        let cache = MemoryProfileStore::default();
+        {
+            let mut statement = connection.prepare("SELECT token_hash, profile FROM profiles").map_err(sql_error)?;
+            let rows = statement
+                .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+                .map_err(sql_error)?;
+            for row in rows {
+                let (token_hash, profile) = row.map_err(sql_error)?;
+                let profile: Profile = serde_json::from_str(&profile).map_err(|error| invalid_data(path, error))?;
+                cache.insert(StoredProfile { token_hash, token: None, profile });
+            }
+
+            let mut statement = connection.prepare("SELECT record FROM matches ORDER BY id").map_err(sql_error)?;
+            let rows = statement.query_map([], |row| row.get::<_, String>(0)).map_err(sql_error)?;
+            for row in rows {
+                let record: MatchRecord = serde_json::from_str(&row.map_err(sql_error)?).map_err(|error| invalid_data(path, error))?;
+                cache.record_match(&record)?;
+            }
+        }
+
+        Ok(Self { connection: Mutex::new(connection), cache })
+    }
+}
+
+impl ProfileStore for SqliteProfileStore {
+    fn load(&self, profile_id: &str) -> io::Result<Option<Profile>> {
+        self.cache.load(profile_id)
+    }
+
+    fn authenticate(&self, token: &str) -> io::Result<Option<Profile>> {
+        self.cache.authenticate(token)
+    }
+
+    fn create(&self, profile: &Profile, token: &str) -> io::Result<()> {
+        let record = StoredProfile::new(profile, token);
+        let connection = self.connection.lock().unwrap();
+        connection
+            .execute(
+                "INSERT INTO profiles (id, token_hash, profile) VALUES (?1, ?2, ?3)",
+                params![profile.id, record.token_hash, serde_json::to_string(profile)?],
+            )
+            .map_err(sql_error)?;
+        self.cache.insert(record);
+        Ok(())
+    }
+
+    fn update(&self, profile_id: &str, change: &mut dyn FnMut(&mut Profile)) -> io::Result<Option<Profile>> {
+        // holding the connection keeps concurrent changes to the same profile in order
+        let connection = self.connection.lock().unwrap();
+        let Some(mut record) = self.cache.record(profile_id) else {
+            return Ok(None);
+        };
+        change(&mut record.profile);
+        let updated = connection
+            .execute("UPDATE profiles SET profile = ?2 WHERE id = ?1", params![profile_id, serde_json::to_string(&record.profile)?])
+            .map_err(sql_error)?;
+        if updated == 0 {
+            return Ok(None);
+        }
+        self.cache.insert(record.clone());
+        Ok(Some(record.profile))
+    }
+
+    fn record_match(&self, record: &MatchRecord) -> io::Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection
+            .execute(
+                "INSERT INTO matches (ended_at_ms, record) VALUES (?1, ?2)",
+                params![record.ended_at_ms as i64, serde_json::to_string(record)?],
+            )
+            .map_err(sql_error)?;
+        self.cache.record_match(record)
+    }
+
+    fn matches_since(&self, since_ms: u64) -> io::Result<Vec<MatchRecord>> {
+        self.cache.matches_since(since_ms)
+    }
+
+    fn matches_recorded(&self) -> io::Result<usize> {
+        self.cache.matches_recorded()
+    }
+}
+
+/// databases written before tokens were hashed have a `token` column with the plaintext tokens
+fn hash_legacy_tokens(connection: &Connection) -> io::Result<()> {
+    let legacy = connection
+        .query_row("SELECT COUNT(*) FROM pragma_table_info('profiles') WHERE name = 'token'", [], |row| row.get::<_, i64>(0))
+        .map_err(sql_error)?;
+    if legacy == 0 {
+        return Ok(());
+    }
+
+    // all at once, a database left half hashed would lock out the rest of the profiles
+    let transaction = connection.unchecked_transaction().map_err(sql_error)?;
+    transaction.execute_batch("ALTER TABLE profiles RENAME COLUMN token TO token_hash;").map_err(sql_error)?;
+    let tokens = transaction
+        .prepare("SELECT id, token_hash FROM profiles")
+        .map_err(sql_error)?
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+        .map_err(sql_error)?
+        .collect::<Result<Vec<(String, String)>, _>>()
+        .map_err(sql_error)?;
+    for (profile_id, token) in tokens {
+        transaction
+            .execute("UPDATE profiles SET token_hash = ?2 WHERE id = ?1", params![profile_id, hash_token(&token)])
+            .map_err(sql_error)?;
+    }
+    transaction.commit().map_err(sql_error)
+}
+
+fn sql_error(error: rusqlite::Error) -> io::Error {
+    io::Error::other(error)
+}
+
+fn invalid_data(path: &Path, error: serde_json::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), error))
+}
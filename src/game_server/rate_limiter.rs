@@ -0,0 +1,237 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::game_server::peer::ClientData;
+
+/// What to do with an inbound frame once its limit has tripped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RateLimitAction {
+    /// discard the frame
+    Drop,
+    /// hold on to the latest frame of that type and deliver it once a token is available
+    Throttle,
+    /// close the connection
+    Disconnect,
+}
+
+/// Keys left out of a bucket fall back to `BucketConfig::default()`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BucketConfig {
+    /// maximum burst size
+    pub capacity: f64,
+    pub refill_per_second: f64,
+    pub action: RateLimitAction,
+}
+
+impl Default for BucketConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 10.0,
+            refill_per_second: 10.0,
+            action: RateLimitAction::Drop,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RateLimitConfig {
+    /// binary frames larger than this (in bytes) are never decoded
+    pub max_frame_size: usize,
+    /// `Throttle` is treated as `Drop` here, an oversized frame can't become valid later
+    pub oversized_frame_action: RateLimitAction,
+    pub player_moved: BucketConfig,
+    pub weapon_fired: BucketConfig,
+    pub chat: BucketConfig,
+    pub other: BucketConfig,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_frame_size: 1024,
+            oversized_frame_action: RateLimitAction::Disconnect,
+            player_moved: BucketConfig {
+                capacity: 30.0,
+                refill_per_second: 60.0,
+                action: RateLimitAction::Throttle,
+            },
+            weapon_fired: BucketConfig {
+                capacity: 5.0,
+                refill_per_second: 10.0,
+                action: RateLimitAction::Drop,
+            },
+            chat: BucketConfig {
+                capacity: 5.0,
+                refill_per_second: 1.0,
+                action: RateLimitAction::Drop,
+            },
+            other: BucketConfig::default(),
+        }
+    }
+}
+
+/// Inbound message categories that get their own bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKind {
+    PlayerMoved,
+    WeaponFired,
+    Chat,
+    Other,
+}
+
+impl MessageKind {
+    pub fn of(data: &ClientData) -> Self {
+        match data {
+            ClientData::PlayerMoved { .. } => MessageKind::PlayerMoved,
+            ClientData::WeaponFired { .. } => MessageKind::WeaponFired,
+            ClientData::ChatSend { .. } => MessageKind::Chat,
+            _ => MessageKind::Other,
+        }
+    }
+}
+
+/// Server wide counters of how often inbound limits tripped
+#[derive(Debug, Default)]
+pub struct RateLimitMetrics {
+    pub dropped: AtomicUsize,
+    pub throttled: AtomicUsize,
+    pub disconnected: AtomicUsize,
+    pub oversized_frames: AtomicUsize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RateLimitMetricsSnapshot {
+    pub dropped: usize,
+    pub throttled: usize,
+    pub disconnected: usize,
+    #[serde(rename = "oversizedFrames")]
+    pub oversized_frames: usize,
+}
+
+impl RateLimitMetrics {
+    pub fn snapshot(&self) -> RateLimitMetricsSnapshot {
+        RateLimitMetricsSnapshot {
+            dropped: self.dropped.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+            disconnected: self.disconnected.load(Ordering::Relaxed),
+            oversized_frames: self.oversized_frames.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record(&self, verdict: &Verdict) {
+        let counter = match verdict {
+            Verdict::Allow => return,
+            Verdict::Drop => &self.dropped,
+            Verdict::Throttle(_) => &self.throttled,
+            Verdict::Disconnect => &self.disconnected,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Allow,
+    Drop,
+    /// try again after the given delay
+    Throttle(Duration),
+    Disconnect,
+}
+
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    config: BucketConfig,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(config: BucketConfig, now: Instant) -> Self {
+        Self {
+            config,
+            tokens: config.capacity,
+            last_refill: now,
+        }
+    }
+
+    /// takes one token, or returns how long until one is available (`None` if never)
+    pub(crate) fn try_take(&mut self, now: Instant) -> Result<(), Option<Duration>> {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.config.refill_per_second).min(self.config.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        if self.config.refill_per_second <= 0.0 {
+            return Err(None);
+        }
+        let missing = 1.0 - self.tokens;
+        Err(Some(Duration::from_secs_f64(missing / self.config.refill_per_second)))
+    }
+}
+
+/// Per peer token buckets, one for every `MessageKind`
+#[derive(Debug)]
+pub struct InboundRateLimiter {
+    config: Arc<RateLimitConfig>,
+    metrics: Arc<RateLimitMetrics>,
+    player_moved: TokenBucket,
+    weapon_fired: TokenBucket,
+    chat: TokenBucket,
+    other: TokenBucket,
+}
+
+impl InboundRateLimiter {
+    pub fn new(config: Arc<RateLimitConfig>, metrics: Arc<RateLimitMetrics>) -> Self {
+        let now = Instant::now();
+        Self {
+            player_moved: TokenBucket::new(config.player_moved, now),
+            weapon_fired: TokenBucket::new(config.weapon_fired, now),
+            chat: TokenBucket::new(config.chat, now),
+            other: TokenBucket::new(config.other, now),
+            config,
+            metrics,
+        }
+    }
+
+    pub fn check_frame_size(&self, frame_len: usize) -> Verdict {
+        if frame_len <= self.config.max_frame_size {
+            return Verdict::Allow;
+        }
+
+        self.metrics.oversized_frames.fetch_add(1, Ordering::Relaxed);
+        let verdict = match self.config.oversized_frame_action {
+            RateLimitAction::Disconnect => Verdict::Disconnect,
+            RateLimitAction::Drop | RateLimitAction::Throttle => Verdict::Drop,
+        };
+        self.metrics.record(&verdict);
+        verdict
+    }
+
+    pub fn check(&mut self, kind: MessageKind, now: Instant) -> Verdict {
+        let bucket = match kind {
+            MessageKind::PlayerMoved => &mut self.player_moved,
+            MessageKind::WeaponFired => &mut self.weapon_fired,
+            MessageKind::Chat => &mut self.chat,
+            MessageKind::Other => &mut self.other,
+        };
+
+        let verdict = match bucket.try_take(now) {
+            Ok(()) => Verdict::Allow,
+            Err(wait) => match (bucket.config.action, wait) {
+                (RateLimitAction::Throttle, Some(wait)) => Verdict::Throttle(wait),
+                (RateLimitAction::Drop | RateLimitAction::Throttle, _) => Verdict::Drop,
+                (RateLimitAction::Disconnect, _) => Verdict::Disconnect,
+            },
+        };
+        self.metrics.record(&verdict);
+        verdict
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use crate::game_server::profile::{MatchResult, Profile};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RatingConfig {
+    /// rating of a profile before its first rated match
+    pub initial: f64,
+    /// most a rating moves in one match
+    pub k_factor: f64,
+}
+
+impl Default for RatingConfig {
+    fn default() -> Self {
+        Self {
+            initial: 1500.0,
+            k_factor: 32.0,
+        }
+    }
+}
+
+impl RatingConfig {
+    /// the profile's rating, the initial one before it played a rated match
+    pub fn rating_of(&self, profile: &Profile) -> f64 {
+        profile.rating.unwrap_or(self.initial)
+    }
+
+    /// Elo changes for the players of one match, in the order given. Every pair of players counts as a game between
+    /// the two: winning beats losing, and between two winners or two losers the one with more points wins. Each pair
+    /// gets an equal share of the K-factor, so a match moves a rating by at most `k_factor`. Empty with fewer than two
+    /// players, a match without rated opponents doesn't count.
+    pub fn changes(&self, players: &[(f64, &MatchResult)]) -> Vec<f64> {
+        if players.len() < 2 {
+            return Vec::new();
+        }
+        let share = self.k_factor / (players.len() - 1) as f64;
+
+        players
+            .iter()
+            .enumerate()
+            .map(|(index, (rating, result))| {
+                players
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_index, _)| *other_index != index)
+                    .map(|(_, (other_rating, other_result))| {
+                        let expected = 1.0 / (1.0 + 10f64.powf((other_rating - rating) / 400.0));
+                        let actual = match (result.won, result.points).cmp(&(other_result.won, other_result.points)) {
+                            std::cmp::Ordering::Greater => 1.0,
+                            std::cmp::Ordering::Equal => 0.5,
+                            std::cmp::Ordering::Less => 0.0,
+                        };
+                        share * (actual - expected)
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use crate::game_server::message_types::PeerPlayerData;
+
+/// kind, flags, sequence, ack and ack bitfield in front of every UDP payload
+pub const HEADER_LEN: usize = 10;
+
+const RELIABLE_FLAG: u8 = 1;
+const ACK_FLAG: u8 = 2;
+
+/// bytes of the cookie in front of a `Connect` payload and in a `Challenge`
+pub const COOKIE_LEN: usize = 8;
+
+/// reliable sequences remembered to drop resent duplicates
+const DELIVERED_HISTORY: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketKind {
+    /// first packet of a client, the payload is the cookie of the last `Challenge` (zeros before one arrived) followed
+    /// by the same query string `/ws` takes
+    Connect,
+    /// the server refused the connection, the payload is the reason
+    Rejected,
+    /// a FlatBuffers payload
+    Data,
+    /// carries acks only, sent when there is nothing else to piggyback them on
+    Ack,
+    /// either side is gone, the payload is the reason
+    Disconnect,
+    /// the server's answer to a `Connect` without a valid cookie, the payload is the cookie to connect with
+    Challenge,
+}
+
+impl PacketKind {
+    fn from_u8(value: u8) -> Option<Self> {
+        Some(match value {
+            0 => PacketKind::Connect,
+            1 => PacketKind::Rejected,
+            2 => PacketKind::Data,
+            3 => PacketKind::Ack,
+            4 => PacketKind::Disconnect,
+            5 => PacketKind::Challenge,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    /// may be lost, older packets than the newest received are dropped
+    Unreliable,
+    /// resent until acknowledged, delivered once but in no particular order
+    Reliable,
+}
+
+impl Channel {
+    /// positions are superseded by the next update, everything else has to arrive
+    pub fn of(data: &PeerPlayerData) -> Self {
+        match data {
+            PeerPlayerData::RemotePeerPositionUpdate { .. } => Channel::Unreliable,
+            _ => Channel::Reliable,
+        }
+    }
+}
+
+/// One UDP datagram
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet<'a> {
+    pub kind: PacketKind,
+    pub channel: Channel,
+    pub sequence: u16,
+    /// newest sequence the sender received, `None` before it received any
+    pub ack: Option<u16>,
+    /// bit `n` is set when the sender received `ack - 1 - n`
+    pub ack_bits: u32,
+    pub payload: &'a [u8],
+}
+
+impl<'a> Packet<'a> {
+    /// a packet outside the reliability layer
+    pub fn control(kind: PacketKind, payload: &'a [u8]) -> Self {
+        Self { kind, channel: Channel::Unreliable, sequence: 0, ack: None, ack_bits: 0, payload }
+    }
+
+    /// `None` for anything too short or with an unknown kind
+    pub fn read(buf: &'a [u8]) -> Option<Self> {
+        if buf.len() < HEADER_LEN {
+            return None;
+        }
+        Some(Self {
+            kind: PacketKind::from_u8(buf[0])?,
+            channel: if buf[1] & RELIABLE_FLAG != 0 { Channel::Reliable } else { Channel::Unreliable },
+            sequence: u16::from_le_bytes([buf[2], buf[3]]),
+            ack: (buf[1] & ACK_FLAG != 0).then(|| u16::from_le_bytes([buf[4], buf[5]])),
+            ack_bits: u32::from_le_bytes([buf[6], buf[7], buf[8], buf[9]]),
+            payload: &buf[HEADER_LEN..],
+        })
+    }
+
+    pub fn write(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(HEADER_LEN + self.payload.len());
+        buf.push(self.kind as u8);
+        let mut flags = 0;
+        if self.channel == Channel::Reliable {
+            flags |= RELIABLE_FLAG;
+        }
+        if self.ack.is_some() {
+            flags |= ACK_FLAG;
+        }
+        buf.push(flags);
+        buf.extend_from_slice(&self.sequence.to_le_bytes());
+        buf.extend_from_slice(&self.ack.unwrap_or_default().to_le_bytes());
+        buf.extend_from_slice(&self.ack_bits.to_le_bytes());
+        buf.extend_from_slice(self.payload);
+        buf
+    }
+}
+
+/// a reliable packet gave up on after `max_resends`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketLost {
+    pub sequence: u16,
+}
+
+#[derive(Debug)]
+struct Unacked {
+    sequence: u16,
+    payload: Vec<u8>,
+    sent_at: Instant,
+    resends: u32,
+}
+
+/// Sequence numbers, acks and resends for one side of a UDP connection. Every data packet gets the next sequence
+/// number and acknowledges the 33 newest packets received; reliable packets are kept and resent with their original
+/// sequence until one of those acks covers them.
+#[derive(Debug)]
+pub struct ReliableEndpoint {
+    resend_after: Duration,
+    max_resends: u32,
+    next_sequence: u16,
+    // newest sequence received, `None` before the first data packet
+    remote_sequence: Option<u16>,
+    received_bits: u32,
+    // a data packet arrived since the last packet sent
+    ack_pending: bool,
+    unacked: Vec<Unacked>,
+    delivered: VecDeque<u16>,
+}
+
+impl ReliableEndpoint {
+    pub fn new(resend_after: Duration, max_resends: u32) -> Self {
+        Self {
+            resend_after,
+            max_resends,
+            next_sequence: 0,
+            remote_sequence: None,
+            received_bits: 0,
+            ack_pending: false,
+            unacked: Vec::new(),
+            delivered: VecDeque::new(),
+        }
+    }
+
+    /// the datagram carrying `payload`
+    pub fn send(&mut self, channel: Channel, payload: Vec<u8>, now: Instant) -> Vec<u8> {
+        let sequence = self.next_sequence;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        let bytes = self.packet(PacketKind::Data, channel, sequence, &payload);
+        if channel == Channel::Reliable {
+            self.unacked.push(Unacked { sequence, payload, sent_at: now, resends: 0 });
+        }
+        bytes
+    }
+
+    /// Takes in the acks of a received packet, returns the payload to deliver. Stale unreliable packets and reliable
+    /// ones already delivered return `None`.
+    pub fn receive<'a>(&mut self, packet: &Packet<'a>) -> Option<&'a [u8]> {
+        if let Some(ack) = packet.ack {
+            self.unacked.retain(|unacked| !is_acked(unacked.sequence, ack, packet.ack_bits));
+        }
+        if packet.kind != PacketKind::Data {
+            return None;
+        }
+        self.ack_pending = true;
+
+        let newest = self.mark_received(packet.sequence);
+        match packet.channel {
+            Channel::Unreliable => newest.then_some(packet.payload),
+            Channel::Reliable => {
+                if self.delivered.contains(&packet.sequence) {
+                    return None;
+                }
+                if self.delivered.len() == DELIVERED_HISTORY {
+                    self.delivered.pop_front();
+                }
+                self.delivered.push_back(packet.sequence);
+                Some(packet.payload)
+            }
+        }
+    }
+
+    /// Reliable packets due for another try. Fails once one of them has been resent `max_resends` times.
+    pub fn resend(&mut self, now: Instant) -> Result<Vec<Vec<u8>>, PacketLost> {
+        let mut due = Vec::new();
+        for index in 0..self.unacked.len() {
+            let unacked = &self.unacked[index];
+            if now.duration_since(unacked.sent_at) < self.resend_after {
+                continue;
+            }
+            if unacked.resends >= self.max_resends {
+                return Err(PacketLost { sequence: unacked.sequence });
+            }
+            let (sequence, payload) = (unacked.sequence, unacked.payload.clone());
+            due.push(self.packet(PacketKind::Data, Channel::Reliable, sequence, &payload));
+            let unacked = &mut self.unacked[index];
+            unacked.sent_at = now;
+            unacked.resends += 1;
+        }
+        Ok(due)
+    }
+
+    /// an ack-only packet, if anything arrived since the last packet sent
+    pub fn ack(&mut self) -> Option<Vec<u8>> {
+        self.ack_pending.then(|| self.packet(PacketKind::Ack, Channel::Unreliable, 0, &[]))
+    }
+
+    /// reliable packets sent and not acknowledged yet
+    pub fn unacked_len(&self) -> usize {
+        self.unacked.len()
+    }
+
+    fn packet(&mut self, kind: PacketKind, channel: Channel, sequence: u16, payload: &[u8]) -> Vec<u8> {
+        self.ack_pending = false;
+        Packet {
+            kind,
+            channel,
+            sequence,
+            ack: self.remote_sequence,
+            ack_bits: self.received_bits,
+            payload,
+        }
+        .write()
+    }
+
+    /// records `sequence` in the ack window, true if it is the newest one so far
+    fn mark_received(&mut self, sequence: u16) -> bool {
+        let Some(remote_sequence) = self.remote_sequence else {
+            self.remote_sequence = Some(sequence);
+            return true;
+        };
+        if is_newer(sequence, remote_sequence) {
+            let shift = sequence.wrapping_sub(remote_sequence) as u32;
+            self.received_bits = self.received_bits.checked_shl(shift).unwrap_or(0) | 1u32.checked_shl(shift - 1).unwrap_or(0);
+            self.remote_sequence = Some(sequence);
+            return true;
+        }
+        let age = remote_sequence.wrapping_sub(sequence) as u32;
+        if (1..=32).contains(&age) {
+            self.received_bits |= 1 << (age - 1);
+        }
+        false
+    }
+}
+
+/// `a` was sent after `b`, allowing for the sequence wrapping around
+fn is_newer(a: u16, b: u16) -> bool {
+    a != b && a.wrapping_sub(b) < 0x8000
+}
+
+fn is_acked(sequence: u16, ack: u16, ack_bits: u32) -> bool {
+    let age = ack.wrapping_sub(sequence) as u32;
+    age == 0 || ((1..=32).contains(&age) && ack_bits & (1 << (age - 1)) != 0)
+}
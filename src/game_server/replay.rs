@@ -0,0 +1,364 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::Deserialize;
+use tracing::warn;
+use crate::game_server::chat::ChatChannel;
+use crate::game_server::flatbuffers_utils::create_player_data_bytes;
+use crate::game_server::message_types::PeerPlayerData;
+use crate::game_server::peer::{ClientControls, ClientData, ClientPosition};
+use crate::game_server::simulation::SimTime;
+
+// File layout, all integers little endian:
+//   header: "GSRP", version u8, room id u64, world seed u64 (since version 2), start time u64 (unix ms),
+//           map id (u16 length + utf8)
+//   record: offset from start u32 (ms), tag u8, player id u64, payload length u32, payload
+const MAGIC: &[u8; 4] = b"GSRP";
+const VERSION: u8 = 2;
+
+const TAG_INPUT_UNKNOWN: u8 = 0;
+const TAG_INPUT_PLAYER_MOVED: u8 = 1;
+const TAG_INPUT_WEAPON_FIRED: u8 = 2;
+const TAG_INPUT_CHAT_SEND: u8 = 3;
+/// payload is a `GameReponseEvent` sent to the whole room, the player id is unused
+const TAG_OUTPUT_BROADCAST: u8 = 16;
+/// payload is a `GameReponseEvent` sent to a single player
+const TAG_OUTPUT_DIRECT: u8 = 17;
+
+/// records are handed to the writer thread once this much is buffered, or on `ReplayRecorder::flush`
+const WRITE_CHUNK: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReplayConfig {
+    /// write a replay file for every match
+    pub record: bool,
+    /// where replay files are written to and served from
+    pub directory: String,
+    /// larger files are not played back
+    pub max_playback_bytes: u64,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            record: false,
+            directory: "replays".to_string(),
+            max_playback_bytes: 32 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ReplayEvent {
+    /// decoded message a player sent
+    Input { player_id: usize, data: ClientData },
+    /// encoded message the server sent, `recipient` is `None` for room broadcasts
+    Output { recipient: Option<usize>, bytes: Vec<u8> },
+}
+
+#[derive(Debug)]
+pub struct Replay {
+    pub room_id: usize,
+    /// world seed, missing in version 1 files
+    pub seed: Option<u64>,
+    pub map_id: String,
+    pub started_at_ms: u64,
+    /// events with their offset from the start of the recording, in order
+    pub events: Vec<(Duration, ReplayEvent)>,
+}
+
+/// Appends the events of one match to a replay file
+///
+/// Records are encoded into a buffer, the file is written on a thread of its own so the game loop never waits for the disk.
+/// Whatever is still buffered when the recorder is dropped without a `flush` is lost.
+#[derive(Debug)]
+pub struct ReplayRecorder {
+    path: PathBuf,
+    buffer: Vec<u8>,
+    started: SimTime,
+    chunks: Sender<Vec<u8>>,
+    writer: JoinHandle<io::Result<()>>,
+}
+
+impl ReplayRecorder {
+    /// record offsets are taken from the simulation clock, `now` is the start of the match
+    pub fn create(directory: &str, room_id: usize, seed: u64, map_id: &str, now: SimTime) -> io::Result<Self> {
+        let started_at_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        let path = Path::new(directory).join(format!("{}-{}.replay", room_id, started_at_ms));
+
+        let mut buffer = Vec::with_capacity(WRITE_CHUNK);
+        buffer.extend_from_slice(MAGIC);
+        buffer.push(VERSION);
+        buffer.extend_from_slice(&(room_id as u64).to_le_bytes());
+        buffer.extend_from_slice(&seed.to_le_bytes());
+        buffer.extend_from_slice(&started_at_ms.to_le_bytes());
+        let map_id = &map_id.as_bytes()[..map_id.len().min(u16::MAX as usize)];
+        buffer.extend_from_slice(&(map_id.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(map_id);
+
+        let (chunks, received) = mpsc::channel();
+        let writer_path = path.clone();
+        let writer = thread::Builder::new()
+            .name("replay-writer".to_string())
+            .spawn(move || {
+                let result = write_chunks(&writer_path, received);
+                if let Err(error) = &result {
+                    warn!(path = %writer_path.display(), %error, "could not write match recording");
+                }
+                result
+            })?;
+
+        Ok(Self {
+            path,
+            buffer,
+            started: now,
+            chunks,
+            writer,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record_input(&mut self, now: SimTime, player_id: usize, data: &ClientData) -> io::Result<()> {
+        let (tag, payload) = encode_client_data(data);
+        self.write_record(now, tag, player_id, &payload)
+    }
+
+    pub fn record_output(&mut self, now: SimTime, recipient: Option<usize>, data: &PeerPlayerData) -> io::Result<()> {
+        let Some(bytes) = create_player_data_bytes(data) else {
+            return Ok(());
+        };
+        match recipient {
+            Some(player_id) => self.write_record(now, TAG_OUTPUT_DIRECT, player_id, &bytes),
+            None => self.write_record(now, TAG_OUTPUT_BROADCAST, 0, &bytes),
+        }
+    }
+
+    /// hands what is buffered to the writer thread, fails once that thread gave up
+    pub fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(WRITE_CHUNK));
+        self.chunks
+            .send(chunk)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "replay writer stopped"))
+    }
+
+    /// flushes and waits until everything is on disk, this blocks
+    pub fn finish(mut self) -> io::Result<()> {
+        self.flush()?;
+        drop(self.chunks);
+        self.writer.join().unwrap_or_else(|_| Err(io::Error::other("replay writer panicked")))
+    }
+
+    fn write_record(&mut self, now: SimTime, tag: u8, player_id: usize, payload: &[u8]) -> io::Result<()> {
+        let offset_ms = now.saturating_sub(self.started).as_millis().min(u32::MAX as u128) as u32;
+        self.buffer.extend_from_slice(&offset_ms.to_le_bytes());
+        self.buffer.push(tag);
+        self.buffer.extend_from_slice(&(player_id as u64).to_le_bytes());
+        self.buffer.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.buffer.extend_from_slice(payload);
+
+        if self.buffer.len() >= WRITE_CHUNK {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// runs on the writer thread until the recorder is dropped
+fn write_chunks(path: &Path, chunks: Receiver<Vec<u8>>) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let mut file = File::create(path)?;
+    for chunk in chunks {
+        file.write_all(&chunk)?;
+    }
+    file.flush()
+}
+
+impl Replay {
+    /// `name` is a file name inside `directory`, anything that could point elsewhere is rejected
+    ///
+    /// Files larger than `max_bytes` are rejected too. This reads the whole file, call it off the async executor.
+    pub fn open(directory: &str, name: &str, max_bytes: u64) -> io::Result<Self> {
+        let is_plain_name = !name.is_empty()
+            && !name.starts_with('.')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !is_plain_name {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid replay name"));
+        }
+
+        let file = File::open(Path::new(directory).join(name))?;
+        if file.metadata()?.len() > max_bytes {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "replay file too large"));
+        }
+        // the file may still be growing while its match is recorded
+        let mut bytes = Vec::new();
+        file.take(max_bytes).read_to_end(&mut bytes)?;
+        Self::decode(&bytes).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed replay file"))
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let mut reader = ByteReader { bytes };
+        if reader.take(4)? != MAGIC {
+            return None;
+        }
+        let version = reader.u8()?;
+        if version == 0 || version > VERSION {
+            return None;
+        }
+        let room_id = reader.u64()? as usize;
+        let seed = if version >= 2 { Some(reader.u64()?) } else { None };
+        let started_at_ms = reader.u64()?;
+        let map_id_len = reader.u16()? as usize;
+        let map_id = String::from_utf8(reader.take(map_id_len)?.to_vec()).ok()?;
+
+        let mut events = Vec::new();
+        // a recording cut short by a crash ends with a partial record, keep what is complete
+        while let Some((offset, event)) = reader.record() {
+            if let Some(event) = event {
+                events.push((offset, event));
+            }
+        }
+
+        Some(Self {
+            room_id,
+            seed,
+            map_id,
+            started_at_ms,
+            events,
+        })
+    }
+}
+
+fn encode_client_data(data: &ClientData) -> (u8, Vec<u8>) {
+    let mut payload = Vec::new();
+    let tag = match data {
+        ClientData::PlayerMoved { player_controls, player_position } => {
+            let controls = player_controls.up as u8
+                | (player_controls.down as u8) << 1
+                | (player_controls.left as u8) << 2
+                | (player_controls.right as u8) << 3;
+            payload.push(controls);
+            payload.extend_from_slice(&player_position.x.to_le_bytes());
+            payload.extend_from_slice(&player_position.y.to_le_bytes());
+            TAG_INPUT_PLAYER_MOVED
+        }
+        ClientData::WeaponFired { angle, power } => {
+            payload.extend_from_slice(&angle.to_le_bytes());
+            payload.extend_from_slice(&power.to_le_bytes());
+            TAG_INPUT_WEAPON_FIRED
+        }
+        ClientData::ChatSend { channel, target_player_id, text } => {
+            payload.push(match channel {
+                ChatChannel::Room => 0,
+                ChatChannel::Direct => 1,
+                ChatChannel::Team => 2,
+            });
+            payload.extend_from_slice(&(*target_player_id as u64).to_le_bytes());
+            payload.extend_from_slice(text.as_bytes());
+            TAG_INPUT_CHAT_SEND
+        }
+        // lobby and party input doesn't change the world and isn't recorded
+        ClientData::LobbySetup { .. } | ClientData::LobbyReady { .. } | ClientData::PartyInvite { .. } | ClientData::PartyRespond { .. } | ClientData::PartyLeave | ClientData::Unknown => TAG_INPUT_UNKNOWN,
+    };
+    (tag, payload)
+}
+
+fn decode_client_data(tag: u8, payload: &[u8]) -> Option<ClientData> {
+    let mut reader = ByteReader { bytes: payload };
+    let data = match tag {
+        TAG_INPUT_PLAYER_MOVED => {
+            let controls = reader.u8()?;
+            ClientData::PlayerMoved {
+                player_controls: ClientControls {
+                    up: controls & 1 != 0,
+                    down: controls & 2 != 0,
+                    left: controls & 4 != 0,
+                    right: controls & 8 != 0,
+                },
+                player_position: ClientPosition {
+                    x: reader.f32()?,
+                    y: reader.f32()?,
+                },
+            }
+        }
+        // shots recorded before they carried an angle have an empty payload
+        TAG_INPUT_WEAPON_FIRED => ClientData::WeaponFired {
+            angle: reader.f32().unwrap_or_default(),
+            power: reader.f32().unwrap_or_default(),
+        },
+        TAG_INPUT_CHAT_SEND => ClientData::ChatSend {
+            channel: match reader.u8()? {
+                1 => ChatChannel::Direct,
+                2 => ChatChannel::Team,
+                _ => ChatChannel::Room,
+            },
+            target_player_id: reader.u64()? as usize,
+            text: String::from_utf8_lossy(reader.bytes).into_owned(),
+        },
+        _ => ClientData::Unknown,
+    };
+    Some(data)
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < len {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        Some(f32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    /// the next complete record, the event is `None` for tags this version doesn't know
+    fn record(&mut self) -> Option<(Duration, Option<ReplayEvent>)> {
+        let offset = Duration::from_millis(self.u32()? as u64);
+        let tag = self.u8()?;
+        let player_id = self.u64()? as usize;
+        let payload_len = self.u32()? as usize;
+        let payload = self.take(payload_len)?;
+
+        let event = match tag {
+            TAG_OUTPUT_BROADCAST => Some(ReplayEvent::Output { recipient: None, bytes: payload.to_vec() }),
+            TAG_OUTPUT_DIRECT => Some(ReplayEvent::Output { recipient: Some(player_id), bytes: payload.to_vec() }),
+            TAG_INPUT_UNKNOWN..=TAG_INPUT_CHAT_SEND => decode_client_data(tag, payload).map(|data| ReplayEvent::Input { player_id, data }),
+            _ => None,
+        };
+        Some((offset, event))
+    }
+}
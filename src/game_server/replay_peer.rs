@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use actix::{Actor, ActorContext, AsyncContext, StreamHandler};
+use actix_web_actors::ws;
+use tracing::{debug, info, info_span, trace, Span};
+use crate::game_server::flatbuffers_utils::create_world_update_bytes;
+use crate::game_server::replay::{Replay, ReplayEvent};
+
+/// Plays a recorded match back to a client, with the original timing
+pub struct ReplayPeer {
+    map_id: String,
+    events: VecDeque<(Duration, ReplayEvent)>,
+    started: Instant,
+    span: Span,
+}
+
+impl ReplayPeer {
+    pub fn create(replay_name: &str, replay: Replay, remote_addr: String) -> Self {
+        let span = info_span!(
+            "replay",
+            replay = %replay_name,
+            room_id = replay.room_id,
+            remote_addr = %remote_addr,
+        );
+        info!(
+            parent: &span,
+            event = "replay_started",
+            recorded_at_ms = replay.started_at_ms,
+            seed = replay.seed,
+            events = replay.events.len(),
+            "streaming replay"
+        );
+        Self {
+            map_id: replay.map_id,
+            events: replay.events.into(),
+            started: Instant::now(),
+            span,
+        }
+    }
+
+    /// sends room broadcasts as they were recorded, direct messages belonged to a single player and are skipped
+    fn schedule_next(&mut self, ctx: &mut ws::WebsocketContext<Self>) {
+        while let Some((offset, event)) = self.events.pop_front() {
+            match event {
+                ReplayEvent::Input { player_id, data } => {
+                    trace!(offset_ms = offset.as_millis() as u64, player_id, ?data, "recorded input");
+                }
+                ReplayEvent::Output { recipient: Some(_), .. } => {}
+                ReplayEvent::Output { recipient: None, bytes } => {
+                    let wait = offset.saturating_sub(self.started.elapsed());
+                    ctx.run_later(wait, move |act, ctx| {
+                        let _entered = act.span.clone().entered();
+                        ctx.binary(bytes);
+                        act.schedule_next(ctx);
+                    });
+                    return;
+                }
+            }
+        }
+
+        info!(event = "replay_finished", "replay finished");
+        ctx.close(Some(ws::CloseCode::Normal.into()));
+        ctx.stop();
+    }
+}
+
+impl Actor for ReplayPeer {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let _entered = self.span.clone().entered();
+
+        // clients expect a world update first, players show up through the recorded join events
+        // the mode isn't recorded, the recorded broadcasts carry what it did
+        ctx.binary(create_world_update_bytes(&self.map_id, "", Vec::new()));
+        self.started = Instant::now();
+        self.schedule_next(ctx);
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for ReplayPeer {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let _entered = self.span.clone().entered();
+        match msg {
+            Ok(ws::Message::Ping(msg)) => ctx.pong(&msg),
+            Ok(ws::Message::Close(reason)) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            Ok(_) => {
+                debug!("ignoring input during replay");
+            }
+            Err(_) => ctx.stop(),
+        }
+    }
+}
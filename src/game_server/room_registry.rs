@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use actix::{Actor, Addr};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tracing::info;
+use crate::game_server::bot::BotConfig;
+use crate::game_server::chat::{ChatConfig, ChatModerator, WordListFilter};
+use crate::game_server::compression::FrameEncoder;
+use crate::game_server::game_server::GameServer;
+use crate::game_server::game_world::WorldSettings;
+use crate::game_server::lobby::LobbyConfig;
+use crate::game_server::party::PartyRegistry;
+use crate::game_server::profile::{Profile, ProfileStore};
+use crate::game_server::rating::RatingConfig;
+use crate::game_server::replay::ReplayConfig;
+use crate::game_server::simulation::{seeded_rng, SimRng, SystemClock};
+
+/// characters of a join code, without the ones that are easy to mix up
+const CODE_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PrivateRoomConfig {
+    /// private rooms open at the same time
+    pub max_rooms: usize,
+    pub code_length: usize,
+    /// a private room closes after being empty for this long
+    pub idle_close_secs: u64,
+}
+
+impl Default for PrivateRoomConfig {
+    fn default() -> Self {
+        Self {
+            max_rooms: 100,
+            code_length: 6,
+            idle_close_secs: 300,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MatchmakingConfig {
+    /// public rooms open at the same time
+    pub max_public_rooms: usize,
+    /// players in one public room
+    pub max_players: u8,
+    /// largest gap between a player's rating and a room's average rating, right after the room took a player
+    pub rating_tolerance: f64,
+    /// the gap a room accepts grows by this much for every second it goes without a new player
+    pub tolerance_growth_per_sec: f64,
+    /// a public room closes after being empty for this long
+    pub idle_close_secs: u64,
+}
+
+impl Default for MatchmakingConfig {
+    fn default() -> Self {
+        Self {
+            max_public_rooms: 50,
+            max_players: 2,
+            rating_tolerance: 100.0,
+            tolerance_growth_per_sec: 10.0,
+            idle_close_secs: 60,
+        }
+    }
+}
+
+/// What a public room tells the matchmaker, kept up to date by its game server
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RoomLoad {
+    /// players in the lobby or the world, bots make way for them and don't count
+    pub players: usize,
+    pub max_players: usize,
+    /// mean rating of the players, anonymous ones count as `ratings.initial`
+    pub rating: f64,
+}
+
+/// Everything needed to start a room, shared by every public and private room
+#[derive(Debug, Clone)]
+pub struct RoomTemplate {
+    pub players_online_count: Arc<AtomicUsize>,
+    pub world: WorldSettings,
+    pub chat: ChatConfig,
+    pub replay: ReplayConfig,
+    pub bots: BotConfig,
+    pub lobby: LobbyConfig,
+    pub parties: Arc<PartyRegistry>,
+    pub profile_store: Arc<dyn ProfileStore>,
+    pub ratings: RatingConfig,
+    pub frame_encoder: FrameEncoder,
+}
+
+impl RoomTemplate {
+    /// creates the room's game server, ids and the world seed come from `rng`
+    pub fn create(&self, rng: SimRng) -> GameServer {
+        let chat_filter = WordListFilter::new(&self.chat.blocked_words, self.chat.blocked_word_action);
+        GameServer::new(
+            self.players_online_count.clone(),
+            self.world.clone(),
+            ChatModerator::new(self.chat.clone(), Box::new(chat_filter)),
+            self.replay.clone(),
+            self.bots.clone(),
+            self.lobby.clone(),
+            self.parties.config().clone(),
+            rng,
+            Box::new(SystemClock::new()),
+        )
+        .with_profile_store(self.profile_store.clone(), self.ratings.clone())
+        .with_frame_encoder(self.frame_encoder.clone())
+        .with_parties(self.parties.clone())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomError {
+    NotFound,
+    WrongPassword,
+    NotOwner,
+    TooManyRooms,
+    /// no public room has a free seat and no more can be opened
+    NoSeats,
+    /// the party has more members than a public room has seats
+    PartyTooLarge,
+}
+
+impl fmt::Display for RoomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomError::NotFound => write!(f, "room not found"),
+            RoomError::WrongPassword => write!(f, "wrong room password"),
+            RoomError::NotOwner => write!(f, "not the room owner"),
+            RoomError::TooManyRooms => write!(f, "too many private rooms"),
+            RoomError::NoSeats => write!(f, "no public room has a free seat"),
+            RoomError::PartyTooLarge => write!(f, "party doesn't fit in a public room"),
+        }
+    }
+}
+
+impl std::error::Error for RoomError {}
+
+/// A newly created private room
+#[derive(Debug, Clone)]
+pub struct CreatedRoom {
+    pub code: String,
+    /// proves ownership when kicking players or changing settings
+    pub owner_token: String,
+}
+
+/// Salted hash of a room password, the password itself is not kept
+#[derive(Debug)]
+struct RoomPassword {
+    salt: [u8; 16],
+    hash: [u8; 32],
+}
+
+impl RoomPassword {
+    /// `None` for an empty password
+    fn new(password: Option<String>) -> Option<Self> {
+        let password = password.filter(|password| !password.is_empty())?;
+        let salt = rand::thread_rng().gen::<[u8; 16]>();
+        Some(Self { salt, hash: hash_password(&salt, &password) })
+    }
+
+    fn matches(&self, password: &str) -> bool {
+        hash_password(&self.salt, password).ct_eq(&self.hash).into()
+    }
+}
+
+fn hash_password(salt: &[u8], password: &str) -> [u8; 32] {
+    Sha256::new().chain_update(salt).chain_update(password.as_bytes()).finalize().into()
+}
+
+#[derive(Debug)]
+struct PrivateRoom {
+    addr: Addr<GameServer>,
+    password: Option<RoomPassword>,
+    owner_token: String,
+}
+
+impl PrivateRoom {
+    /// compared in constant time, like the password
+    fn is_owner(&self, owner_token: &str) -> bool {
+        self.owner_token.as_bytes().ct_eq(owner_token.as_bytes()).into()
+    }
+}
+
+#[derive(Debug)]
+struct PublicRoom {
+    addr: Addr<GameServer>,
+    load: Arc<Mutex<RoomLoad>>,
+    // seats held for peers that are still connecting
+    reserved: Arc<AtomicUsize>,
+    // the rating tolerance widens from here
+    last_placed: Instant,
+}
+
+impl PublicRoom {
+    /// players and reserved seats
+    fn taken(&self) -> usize {
+        self.load.lock().unwrap().players + self.reserved.load(Ordering::SeqCst)
+    }
+
+    fn free_seats(&self) -> usize {
+        let max_players = self.load.lock().unwrap().max_players;
+        max_players.saturating_sub(self.taken())
+    }
+
+    /// how far the room's average rating is from `rating`
+    fn rating_gap(&self, rating: f64) -> f64 {
+        (self.load.lock().unwrap().rating - rating).abs()
+    }
+
+    fn tolerance(&self, config: &MatchmakingConfig, now: Instant) -> f64 {
+        config.rating_tolerance + config.tolerance_growth_per_sec * now.duration_since(self.last_placed).as_secs_f64()
+    }
+
+    fn reserve(&self, seats: usize) -> Vec<Reservation> {
+        self.reserved.fetch_add(seats, Ordering::SeqCst);
+        (0..seats).map(|_| Reservation { reserved: self.reserved.clone() }).collect()
+    }
+}
+
+/// A seat held in a public room for a peer until its game server has counted it, given back when dropped
+#[derive(Debug)]
+pub struct Reservation {
+    reserved: Arc<AtomicUsize>,
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.reserved.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A seat kept for a party member who hasn't connected yet
+#[derive(Debug)]
+struct HeldSeat {
+    addr: Addr<GameServer>,
+    reservation: Reservation,
+    until: Instant,
+}
+
+/// The public rooms players are matched into and the private rooms by join code. Each room is its own `GameServer`,
+/// which stops itself once it has been empty for a while.
+#[derive(Debug)]
+pub struct RoomRegistry {
+    config: PrivateRoomConfig,
+    matchmaking: MatchmakingConfig,
+    template: RoomTemplate,
+    rooms: Mutex<HashMap<String, PrivateRoom>>,
+    public_rooms: Mutex<Vec<PublicRoom>>,
+    // by the profile id of the party member the seat is held for
+    held_seats: Mutex<HashMap<String, HeldSeat>>,
+    // room seeds, so runs with a fixed seed stay reproducible
+    seed_rng: Mutex<SimRng>,
+}
+
+impl RoomRegistry {
+    pub fn new(config: PrivateRoomConfig, matchmaking: MatchmakingConfig, template: RoomTemplate, seed: u64) -> Self {
+        Self {
+            config,
+            matchmaking,
+            template,
+            rooms: Mutex::new(HashMap::new()),
+            public_rooms: Mutex::new(Vec::new()),
+            held_seats: Mutex::new(HashMap::new()),
+            seed_rng: Mutex::new(seeded_rng(seed)),
+        }
+    }
+
+    /// Picks the public room for a player joining without a code and holds a seat in it. A party member goes to the
+    /// room a mate plays in while it has a free seat; otherwise the members who aren't playing in a public room are
+    /// placed together, and seats are held for the others for `party.rejoinSecs`. A party with more of them than a
+    /// public room has seats is refused. Has to be called from within the actix system.
+    pub fn matchmake(&self, profile: Option<&Profile>) -> Result<(Addr<GameServer>, Reservation), RoomError> {
+        let rating = profile.map_or(self.template.ratings.initial, |profile| self.template.ratings.rating_of(profile));
+        let now = Instant::now();
+        let mut rooms = self.public_rooms.lock().unwrap();
+        rooms.retain(|room| room.addr.connected());
+        let mut held_seats = self.held_seats.lock().unwrap();
+        held_seats.retain(|_, seat| seat.until > now && seat.addr.connected());
+
+        let parties = &self.template.parties;
+        let Some((profile, party)) = profile.and_then(|profile| Some((profile, parties.party_of(&profile.id)?))) else {
+            let (addr, mut reservations) = self.place(&mut rooms, rating, 1, now)?;
+            return Ok((addr, reservations.remove(0)));
+        };
+        if let Some(seat) = held_seats.remove(&profile.id) {
+            return Ok((seat.addr, seat.reservation));
+        }
+
+        let playing = |member: &str| parties.room_of(member).and_then(|addr| rooms.iter().position(|room| room.addr == addr));
+        let mates = party.members.iter().filter(|member| **member != profile.id);
+        if let Some(index) = mates.filter_map(|member| playing(member)).find(|&index| rooms[index].free_seats() > 0) {
+            let room = &rooms[index];
+            return Ok((room.addr.clone(), room.reserve(1).remove(0)));
+        }
+
+        let coming = party.members
+            .iter()
+            .filter(|member| **member == profile.id || playing(member).is_none())
+            .collect::<Vec<&String>>();
+        if coming.len() > self.matchmaking.max_players as usize {
+            return Err(RoomError::PartyTooLarge);
+        }
+        let (addr, mut reservations) = self.place(&mut rooms, rating, coming.len(), now)?;
+        let until = now + Duration::from_secs(parties.config().rejoin_secs);
+        for member in coming.into_iter().filter(|member| **member != profile.id) {
+            let reservation = reservations.pop().expect("a seat for every member");
+            held_seats.insert(member.clone(), HeldSeat { addr: addr.clone(), reservation, until });
+        }
+        Ok((addr, reservations.remove(0)))
+    }
+
+    /// Reserves `seats` in one public room. Of the rooms with enough free seats, the one whose average rating is
+    /// closest to `rating` wins, as long as the gap is within the room's tolerance, which widens the longer the room
+    /// goes without a new player. Otherwise the seats are taken in an empty room, a new one if there is none, or the
+    /// closest room once no more can be opened.
+    fn place(&self, rooms: &mut Vec<PublicRoom>, rating: f64, seats: usize, now: Instant) -> Result<(Addr<GameServer>, Vec<Reservation>), RoomError> {
+        let fitting = || rooms.iter().enumerate().filter(|(_, room)| room.free_seats() >= seats);
+        let closest = |rooms: &mut dyn Iterator<Item = (usize, &PublicRoom)>| {
+            rooms.min_by(|(_, a), (_, b)| a.rating_gap(rating).total_cmp(&b.rating_gap(rating))).map(|(index, _)| index)
+        };
+        let within_tolerance = closest(&mut fitting().filter(|(_, room)| room.taken() > 0 && room.rating_gap(rating) <= room.tolerance(&self.matchmaking, now)));
+        let empty = fitting().find(|(_, room)| room.taken() == 0).map(|(index, _)| index);
+        let index = match within_tolerance.or(empty) {
+            Some(index) => index,
+            None if rooms.len() < self.matchmaking.max_public_rooms && seats <= self.matchmaking.max_players as usize => {
+                rooms.push(self.open_public_room(now));
+                rooms.len() - 1
+            }
+            None => closest(&mut fitting()).ok_or(RoomError::NoSeats)?,
+        };
+
+        let room = &mut rooms[index];
+        room.last_placed = now;
+        Ok((room.addr.clone(), room.reserve(seats)))
+    }
+
+    /// the public room with the most players for a spectator joining without a code
+    pub fn spectate(&self) -> Addr<GameServer> {
+        let mut rooms = self.public_rooms.lock().unwrap();
+        rooms.retain(|room| room.addr.connected());
+        if rooms.is_empty() {
+            rooms.push(self.open_public_room(Instant::now()));
+        }
+        rooms.iter().max_by_key(|room| room.taken()).map(|room| room.addr.clone()).expect("a public room")
+    }
+
+    /// number of public rooms still running
+    pub fn public_rooms(&self) -> usize {
+        let mut rooms = self.public_rooms.lock().unwrap();
+        rooms.retain(|room| room.addr.connected());
+        rooms.len()
+    }
+
+    fn open_public_room(&self, now: Instant) -> PublicRoom {
+        let load = Arc::new(Mutex::new(RoomLoad::default()));
+        let room_rng = seeded_rng(self.seed_rng.lock().unwrap().gen());
+        let addr = self.template
+            .create(room_rng)
+            .with_max_players(self.matchmaking.max_players)
+            .reporting_load(load.clone())
+            .close_when_idle(Duration::from_secs(self.matchmaking.idle_close_secs))
+            .start();
+        info!(event = "room_created", public = true, "public room opened");
+        PublicRoom { addr, load, reserved: Arc::new(AtomicUsize::new(0)), last_placed: now }
+    }
+
+    /// number of private rooms still running
+    pub fn len(&self) -> usize {
+        let mut rooms = self.rooms.lock().unwrap();
+        prune(&mut rooms);
+        rooms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Starts a private room. Has to be called from within the actix system.
+    pub fn create(&self, password: Option<String>) -> Result<CreatedRoom, RoomError> {
+        let mut rooms = self.rooms.lock().unwrap();
+        prune(&mut rooms);
+        if rooms.len() >= self.config.max_rooms {
+            return Err(RoomError::TooManyRooms);
+        }
+
+        // codes and tokens are not drawn from the seeded RNG, they have to stay unguessable
+        let mut rng = rand::thread_rng();
+        let code = loop {
+            let code = (0..self.config.code_length.max(1))
+                .map(|_| CODE_CHARSET[rng.gen_range(0..CODE_CHARSET.len())] as char)
+                .collect::<String>();
+            if !rooms.contains_key(&code) {
+                break code;
+            }
+        };
+        let owner_token = (&mut rng).sample_iter(Alphanumeric).take(32).map(char::from).collect::<String>();
+
+        let room_rng = seeded_rng(self.seed_rng.lock().unwrap().gen());
+        let addr = self.template
+            .create(room_rng)
+            .close_when_idle(Duration::from_secs(self.config.idle_close_secs))
+            .start();
+        info!(event = "room_created", code = %code, password = password.is_some(), "private room created");
+
+        rooms.insert(code.clone(), PrivateRoom {
+            addr,
+            password: RoomPassword::new(password),
+            owner_token: owner_token.clone(),
+        });
+        Ok(CreatedRoom { code, owner_token })
+    }
+
+    /// the room to connect a peer to, codes are not case sensitive
+    pub fn join(&self, code: &str, password: Option<&str>) -> Result<Addr<GameServer>, RoomError> {
+        let mut rooms = self.rooms.lock().unwrap();
+        prune(&mut rooms);
+        let room = rooms.get(&code.to_uppercase()).ok_or(RoomError::NotFound)?;
+        match &room.password {
+            Some(expected) if !password.is_some_and(|password| expected.matches(password)) => Err(RoomError::WrongPassword),
+            _ => Ok(room.addr.clone()),
+        }
+    }
+
+    /// the room and whether it is password protected
+    pub fn lookup(&self, code: &str) -> Result<(Addr<GameServer>, bool), RoomError> {
+        let mut rooms = self.rooms.lock().unwrap();
+        prune(&mut rooms);
+        let room = rooms.get(&code.to_uppercase()).ok_or(RoomError::NotFound)?;
+        Ok((room.addr.clone(), room.password.is_some()))
+    }
+
+    /// the room, if `owner_token` is the one handed out when it was created
+    pub fn owned(&self, code: &str, owner_token: &str) -> Result<Addr<GameServer>, RoomError> {
+        let mut rooms = self.rooms.lock().unwrap();
+        prune(&mut rooms);
+        let room = rooms.get(&code.to_uppercase()).ok_or(RoomError::NotFound)?;
+        if !room.is_owner(owner_token) {
+            return Err(RoomError::NotOwner);
+        }
+        Ok(room.addr.clone())
+    }
+
+    /// an empty password removes it, only applies to new connections
+    pub fn set_password(&self, code: &str, owner_token: &str, password: String) -> Result<(), RoomError> {
+        let mut rooms = self.rooms.lock().unwrap();
+        let room = rooms.get_mut(&code.to_uppercase()).ok_or(RoomError::NotFound)?;
+        if !room.is_owner(owner_token) {
+            return Err(RoomError::NotOwner);
+        }
+        room.password = RoomPassword::new(Some(password));
+        Ok(())
+    }
+}
+
+/// drops rooms whose game server has stopped
+fn prune(rooms: &mut HashMap<String, PrivateRoom>) {
+    rooms.retain(|_, room| room.addr.connected());
+}
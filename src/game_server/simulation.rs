@@ -0,0 +1,78 @@
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::Deserialize;
+
+/// Time since the clock started, the only notion of time the simulation sees
+pub type SimTime = Duration;
+
+/// Seedable RNG with a stable output across platforms and releases
+pub type SimRng = ChaCha8Rng;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SimulationConfig {
+    /// fixed seed for reproducible runs, a random one is picked (and logged) when not set
+    pub seed: Option<u64>,
+}
+
+impl SimulationConfig {
+    pub fn seed_or_random(&self) -> u64 {
+        self.seed.unwrap_or_else(rand::random)
+    }
+}
+
+pub fn seeded_rng(seed: u64) -> SimRng {
+    SimRng::seed_from_u64(seed)
+}
+
+pub trait Clock: Debug {
+    fn now(&self) -> SimTime;
+}
+
+/// Wall clock time
+#[derive(Debug)]
+pub struct SystemClock {
+    started: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> SimTime {
+        self.started.elapsed()
+    }
+}
+
+/// Clock that only moves when told to, clones share the same time
+#[derive(Debug, Clone, Default)]
+pub struct VirtualClock {
+    now: Rc<Cell<SimTime>>,
+}
+
+impl VirtualClock {
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> SimTime {
+        self.now.get()
+    }
+}
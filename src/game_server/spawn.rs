@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use rand::Rng;
+use serde::Deserialize;
+use crate::game_server::peer::ClientPosition;
+use crate::game_server::simulation::SimRng;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SpawnPoint {
+    pub x: f32,
+    pub y: f32,
+    /// only used by `SpawnStrategy::Team`, untagged points are shared by every team
+    #[serde(default)]
+    pub team: Option<u8>,
+}
+
+impl SpawnPoint {
+    pub fn position(&self) -> ClientPosition {
+        ClientPosition { x: self.x, y: self.y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SpawnStrategy {
+    /// cycle through the spawn points in map order
+    #[default]
+    RoundRobin,
+    /// the spawn point whose nearest enemy is the farthest away, a random one while there are no enemies
+    FarthestFromEnemies,
+    /// round robin over the points tagged with the player's team
+    Team,
+}
+
+/// A player already in the world, as seen by the spawn selection
+#[derive(Debug, Clone, Copy)]
+pub struct Occupant {
+    pub position: ClientPosition,
+    pub team: Option<u8>,
+}
+
+#[derive(Debug)]
+pub struct SpawnSelector {
+    strategy: SpawnStrategy,
+    /// round robin position, kept per team for `SpawnStrategy::Team`
+    next_index: HashMap<Option<u8>, usize>,
+}
+
+impl SpawnSelector {
+    pub fn new(strategy: SpawnStrategy) -> Self {
+        Self {
+            strategy,
+            next_index: HashMap::new(),
+        }
+    }
+
+    /// returns `None` only when `spawn_points` is empty
+    pub fn pick(&mut self, spawn_points: &[SpawnPoint], team: Option<u8>, occupants: &[Occupant], rng: &mut SimRng) -> Option<ClientPosition> {
+        let candidates = match self.strategy {
+            SpawnStrategy::Team => {
+                let team_points = spawn_points.iter()
+                    .filter(|point| point.team.is_none() || point.team == team)
+                    .copied()
+                    .collect::<Vec<SpawnPoint>>();
+                if team_points.is_empty() { spawn_points.to_vec() } else { team_points }
+            }
+            _ => spawn_points.to_vec(),
+        };
+
+        if self.strategy == SpawnStrategy::FarthestFromEnemies {
+            let enemies = occupants.iter()
+                .filter(|occupant| team.is_none() || occupant.team != team)
+                .collect::<Vec<&Occupant>>();
+            if enemies.is_empty() {
+                if candidates.is_empty() {
+                    return None;
+                }
+                return Some(candidates[rng.gen_range(0..candidates.len())].position());
+            }
+
+            return candidates.iter()
+                .map(|point| {
+                    let nearest = enemies.iter()
+                        .map(|enemy| distance_squared(point.position(), enemy.position))
+                        .fold(f32::INFINITY, f32::min);
+                    (nearest, point.position())
+                })
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, position)| position);
+        }
+
+        let key = if self.strategy == SpawnStrategy::Team { team } else { None };
+        let next_index = self.next_index.entry(key).or_insert(0);
+        let point = candidates.get(*next_index % candidates.len().max(1))?;
+        *next_index = next_index.wrapping_add(1);
+        Some(point.position())
+    }
+}
+
+fn distance_squared(a: ClientPosition, b: ClientPosition) -> f32 {
+    let (dx, dy) = (a.x - b.x, a.y - b.y);
+    dx * dx + dy * dy
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TeamConfig {
+    /// number of teams per room, teams are numbered from 0
+    pub count: u8,
+    /// whether weapons damage players of the shooter's own team
+    pub friendly_fire: bool,
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        Self {
+            count: 2,
+            friendly_fire: false,
+        }
+    }
+}
+
+impl TeamConfig {
+    pub fn can_damage(&self, attacker_team: u8, victim_team: u8) -> bool {
+        self.friendly_fire || attacker_team != victim_team
+    }
+
+    /// Picks the team for a joining player. A requested team is honoured as long as it doesn't leave that team with
+    /// more than one player over the smallest one, otherwise (and without a request) the smallest team is used.
+    pub fn assign(&self, requested: Option<u8>, team_sizes: &[usize]) -> u8 {
+        let smallest = (0..self.count.max(1))
+            .min_by_key(|&team| (team_sizes.get(team as usize).copied().unwrap_or(0), team))
+            .unwrap_or(0);
+        let smallest_size = team_sizes.get(smallest as usize).copied().unwrap_or(0);
+
+        match requested {
+            // joining leaves the team at most one player ahead of the smallest
+            Some(team) if team < self.count.max(1) && team_sizes.get(team as usize).copied().unwrap_or(0) <= smallest_size => team,
+            _ => smallest,
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use actix::{Actor, ActorContext, ActorFutureExt, Addr, AsyncContext, Context, ContextFutureSpawner, fut, Handler, Message, Recipient, Running, WrapFuture};
+use tokio::net::UdpSocket;
+use tracing::{debug, field, info, info_span, warn, Span};
+use crate::game_server::flatbuffers_utils::{create_player_data_bytes, read_gameplay_data};
+use crate::game_server::game_server::GameServer;
+use crate::game_server::message_types::{Connect, Disconnect, PeerAddr, PeerBroadcast, PeerPlayerData, PeerRole};
+use crate::game_server::peer::{accept_frame, handle_client_data, ClientDataPeer, InboundLimits};
+use crate::game_server::rate_limiter::InboundRateLimiter;
+use crate::game_server::reliability::{Channel, Packet, PacketKind, PacketLost, ReliableEndpoint};
+use crate::game_server::udp_server::UdpConfig;
+use crate::game_server::peer_setup::PeerSetup;
+use crate::game_server::room_registry::Reservation;
+
+/// A datagram the UDP server received from this peer's client
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct UdpDatagram(pub Vec<u8>);
+
+/// Tells the UDP server a peer has stopped, so the client's next `Connect` starts a new one
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct UdpSessionClosed {
+    pub remote_addr: SocketAddr,
+}
+
+/// The UDP counterpart of `Peer`, one per client address
+pub struct UdpPeer {
+    id: usize,
+    game_server_addr: Addr<GameServer>,
+    span: Span,
+    role: PeerRole,
+    requested_team: Option<u8>,
+    profile_id: Option<String>,
+    broadcast_delay: Option<Duration>,
+    inbound: InboundLimits,
+    socket: Arc<UdpSocket>,
+    remote_addr: SocketAddr,
+    endpoint: ReliableEndpoint,
+    // resends, acks and the timeout are checked this often
+    tick: Duration,
+    timeout: Duration,
+    last_received: Instant,
+    udp_server: Recipient<UdpSessionClosed>,
+    // seat held in a public room until the game server has taken the player in
+    reservation: Option<Reservation>,
+}
+
+impl UdpPeer {
+    pub fn create(setup: PeerSetup, socket: Arc<UdpSocket>, remote_addr: SocketAddr, rate_limiter: InboundRateLimiter, config: &UdpConfig, udp_server: Recipient<UdpSessionClosed>) -> Self {
+        let span = info_span!(
+            "peer",
+            player_id = field::Empty,
+            room_id = field::Empty,
+            remote_addr = %remote_addr,
+            profile_id = setup.profile_id.as_deref(),
+            spectator = setup.role == PeerRole::Spectator,
+            transport = "udp",
+        );
+        let tick = Duration::from_millis(config.resend_ms);
+        Self {
+            // id is re-assigned when connection is established
+            id: 0,
+            game_server_addr: setup.game_server_addr,
+            span,
+            role: setup.role,
+            requested_team: setup.team,
+            profile_id: setup.profile_id,
+            broadcast_delay: setup.broadcast_delay,
+            inbound: InboundLimits::new(rate_limiter),
+            socket,
+            remote_addr,
+            endpoint: ReliableEndpoint::new(tick, config.max_resends),
+            tick,
+            timeout: Duration::from_secs(config.timeout_secs),
+            last_received: Instant::now(),
+            udp_server,
+            reservation: setup.reservation,
+        }
+    }
+
+    /// resends what is due, acks what arrived and gives up on clients gone silent
+    fn tick(&mut self, ctx: &mut Context<Self>) {
+        let _entered = self.span.clone().entered();
+        let now = Instant::now();
+        if now.duration_since(self.last_received) > self.timeout {
+            warn!(event = "kick", reason = "timeout", "closing connection");
+            self.disconnect("timeout", ctx);
+            return;
+        }
+
+        match self.endpoint.resend(now) {
+            Ok(packets) => {
+                for packet in packets {
+                    self.send_datagram(&packet);
+                }
+            }
+            Err(PacketLost { sequence }) => {
+                warn!(event = "kick", reason = "packet_lost", sequence, "closing connection");
+                self.disconnect("packet_lost", ctx);
+                return;
+            }
+        }
+        if let Some(ack) = self.endpoint.ack() {
+            self.send_datagram(&ack);
+        }
+    }
+
+    fn send_player_data(&mut self, msg: PeerPlayerData, ctx: &mut Context<Self>) {
+        if let PeerPlayerData::Kicked { reason } = msg {
+            let _entered = self.span.clone().entered();
+            warn!(event = "kick", reason, "closing connection");
+            self.disconnect(reason, ctx);
+            return;
+        }
+
+        if let Some(bytes) = create_player_data_bytes(&msg) {
+            self.send_payload(Channel::of(&msg), bytes);
+        }
+    }
+
+    fn send_payload(&mut self, channel: Channel, payload: Vec<u8>) {
+        let packet = self.endpoint.send(channel, payload, Instant::now());
+        self.send_datagram(&packet);
+    }
+
+    /// tells the client why it is dropped, then stops
+    fn disconnect(&mut self, reason: &str, ctx: &mut Context<Self>) {
+        self.send_datagram(&Packet::control(PacketKind::Disconnect, reason.as_bytes()).write());
+        ctx.stop();
+    }
+
+    /// a full socket buffer loses the datagram like the network would, reliable ones are resent
+    fn send_datagram(&self, bytes: &[u8]) {
+        if let Err(error) = self.socket.try_send_to(bytes, self.remote_addr) {
+            debug!(parent: &self.span, %error, "datagram not sent");
+        }
+    }
+}
+
+/// same rate limits as websocket peers
+impl ClientDataPeer for UdpPeer {
+    fn role(&self) -> PeerRole {
+        self.role
+    }
+
+    fn span(&self) -> &Span {
+        &self.span
+    }
+
+    fn inbound(&mut self) -> &mut InboundLimits {
+        &mut self.inbound
+    }
+
+    fn session(&self) -> (&Addr<GameServer>, usize) {
+        (&self.game_server_addr, self.id)
+    }
+
+    fn close(&mut self, reason: &'static str, ctx: &mut Self::Context) {
+        self.disconnect(reason, ctx);
+    }
+}
+
+impl Actor for UdpPeer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let _entered = self.span.clone().entered();
+        info!(event = "connect", "peer connected");
+
+        ctx.run_interval(self.tick, |act, ctx| act.tick(ctx));
+        let addr = ctx.address();
+        // datagrams are never compressed
+        let peer_addr = PeerAddr { data: addr.clone().recipient(), broadcast: addr.recipient(), compress: false };
+        self.game_server_addr
+            .send(Connect { peer_addr, role: self.role, team: self.requested_team, profile_id: self.profile_id.clone() })
+            .into_actor(self)
+            .then(|res, act, ctx| {
+                match res {
+                    Ok(session) => {
+                        act.id = session.player_id;
+                        act.span.record("player_id", session.player_id);
+                        act.span.record("room_id", session.room_id);
+                        act.reservation = None;
+                    },
+                    Err(error) => {
+                        warn!(parent: &act.span, event = "kick", reason = "join_failed", %error, "could not join game server");
+                        act.disconnect("join_failed", ctx);
+                    }
+                }
+                fut::ready(())
+            })
+            .wait(ctx);
+    }
+
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        let _entered = self.span.clone().entered();
+        info!(event = "disconnect", "peer disconnected");
+
+        self.game_server_addr.do_send(Disconnect { id: self.id });
+        self.udp_server.do_send(UdpSessionClosed { remote_addr: self.remote_addr });
+        Running::Stop
+    }
+}
+
+impl Handler<PeerPlayerData> for UdpPeer {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerPlayerData, ctx: &mut Self::Context) {
+        match self.broadcast_delay {
+            Some(delay) if !matches!(msg, PeerPlayerData::Kicked { .. }) => {
+                ctx.run_later(delay, move |act, ctx| act.send_player_data(msg, ctx));
+            }
+            _ => self.send_player_data(msg, ctx),
+        }
+    }
+}
+
+impl Handler<PeerBroadcast> for UdpPeer {
+    type Result = ();
+
+    /// the payload the game server already encoded goes out as it is
+    fn handle(&mut self, msg: PeerBroadcast, ctx: &mut Self::Context) {
+        let channel = Channel::of(&msg.data);
+        let payload = msg.frames.raw.to_vec();
+        match self.broadcast_delay {
+            Some(delay) => {
+                ctx.run_later(delay, move |act, _| act.send_payload(channel, payload));
+            }
+            None => self.send_payload(channel, payload),
+        }
+    }
+}
+
+impl Handler<UdpDatagram> for UdpPeer {
+    type Result = ();
+
+    fn handle(&mut self, msg: UdpDatagram, ctx: &mut Self::Context) {
+        let _entered = self.span.clone().entered();
+        let Some(packet) = Packet::read(&msg.0) else {
+            warn!(event = "decode_error", frame_len = msg.0.len(), "dropping malformed datagram");
+            return;
+        };
+        self.last_received = Instant::now();
+
+        match packet.kind {
+            PacketKind::Data => {
+                if !accept_frame(self, packet.payload.len(), ctx) {
+                    return;
+                }
+
+                let Some(payload) = self.endpoint.receive(&packet) else {
+                    return;
+                };
+                match read_gameplay_data(payload) {
+                    Ok(data) => handle_client_data(self, data, ctx),
+                    Err(error) => {
+                        warn!(event = "decode_error", frame_len = payload.len(), %error, "dropping malformed frame");
+                    }
+                }
+            }
+            PacketKind::Ack => {
+                self.endpoint.receive(&packet);
+            }
+            PacketKind::Disconnect => ctx.stop(),
+            // a client resends `Connect` until it hears from the server
+            PacketKind::Connect | PacketKind::Rejected | PacketKind::Challenge => {}
+        }
+    }
+}
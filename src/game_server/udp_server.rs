@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use actix::{Actor, Addr, AsyncContext, Context, Handler, StreamHandler};
+use futures_util::stream;
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tokio::net::UdpSocket;
+use tracing::{debug, info, warn};
+use crate::config::SpectatorConfig;
+use crate::game_server::profile::ProfileStore;
+use crate::game_server::peer_setup::{ConnectQuery, PeerSetup};
+use crate::game_server::rate_limiter::{BucketConfig, InboundRateLimiter, RateLimitAction, RateLimitConfig, RateLimitMetrics, TokenBucket};
+use crate::game_server::reliability::{Packet, PacketKind, COOKIE_LEN};
+use crate::game_server::room_registry::RoomRegistry;
+use crate::game_server::udp_peer::{UdpDatagram, UdpPeer, UdpSessionClosed};
+
+/// largest datagram read, anything longer is cut and fails to decode
+const MAX_DATAGRAM_LEN: usize = 65_507;
+
+/// a cookie is issued for the current period and accepted during the next one as well
+const COOKIE_PERIOD: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UdpConfig {
+    /// address to listen on for UDP clients, e.g. `0.0.0.0:8091`, only websockets are served without one
+    pub bind: Option<String>,
+    /// unacknowledged reliable packets are resent this often, acks go out at the same pace
+    pub resend_ms: u64,
+    /// a reliable packet still unacknowledged after this many resends drops the client
+    pub max_resends: u32,
+    /// clients silent for this long are dropped
+    pub timeout_secs: u64,
+    /// `Connect` packets handled per second across all clients, with bursts of as many, the rest are dropped
+    pub connects_per_second: f64,
+    /// clients connected at once, further ones are rejected
+    pub max_sessions: usize,
+}
+
+impl Default for UdpConfig {
+    fn default() -> Self {
+        Self {
+            bind: None,
+            resend_ms: 100,
+            max_resends: 20,
+            timeout_secs: 10,
+            connects_per_second: 50.0,
+            max_sessions: 1024,
+        }
+    }
+}
+
+/// Owns the UDP socket and hands every client's datagrams to its `UdpPeer`, which joins rooms like a websocket peer
+pub struct UdpServer {
+    socket: Arc<UdpSocket>,
+    config: UdpConfig,
+    sessions: HashMap<SocketAddr, Addr<UdpPeer>>,
+    cookies: ConnectCookies,
+    connects: TokenBucket,
+    room_registry: Arc<RoomRegistry>,
+    profile_store: Arc<dyn ProfileStore>,
+    rate_limit_config: Arc<RateLimitConfig>,
+    rate_limit_metrics: Arc<RateLimitMetrics>,
+    spectator_config: Arc<SpectatorConfig>,
+}
+
+impl UdpServer {
+    /// has to be called from within the runtime the server runs on
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        socket: std::net::UdpSocket,
+        config: UdpConfig,
+        room_registry: Arc<RoomRegistry>,
+        profile_store: Arc<dyn ProfileStore>,
+        rate_limit_config: Arc<RateLimitConfig>,
+        rate_limit_metrics: Arc<RateLimitMetrics>,
+        spectator_config: Arc<SpectatorConfig>,
+    ) -> io::Result<Self> {
+        socket.set_nonblocking(true)?;
+        let connects = BucketConfig {
+            capacity: config.connects_per_second,
+            refill_per_second: config.connects_per_second,
+            action: RateLimitAction::Drop,
+        };
+        Ok(Self {
+            socket: Arc::new(UdpSocket::from_std(socket)?),
+            sessions: HashMap::new(),
+            cookies: ConnectCookies::new(),
+            connects: TokenBucket::new(connects, Instant::now()),
+            config,
+            room_registry,
+            profile_store,
+            rate_limit_config,
+            rate_limit_metrics,
+            spectator_config,
+        })
+    }
+
+    /// Answers a `Connect` from a client without a session. Only a client that echoes the cookie of a `Challenge`
+    /// receives datagrams at the address it claims, so rooms are only joined after that round trip.
+    fn connect(&mut self, payload: &[u8], remote_addr: SocketAddr, ctx: &mut Context<Self>) {
+        if self.connects.try_take(Instant::now()).is_err() {
+            debug!(event = "rate_limited", %remote_addr, "dropping udp connect");
+            return;
+        }
+        // the challenge is no larger than the request, so it can't amplify a spoofed one
+        let Some((cookie, query)) = payload.split_first_chunk::<COOKIE_LEN>() else {
+            debug!(%remote_addr, frame_len = payload.len(), "ignoring udp connect without a cookie");
+            return;
+        };
+        if !self.cookies.verify(cookie, remote_addr, Instant::now()) {
+            let cookie = self.cookies.issue(remote_addr, Instant::now());
+            self.send_control(PacketKind::Challenge, &cookie, remote_addr);
+            return;
+        }
+
+        self.sessions.retain(|_, peer| peer.connected());
+        let accepted = if self.sessions.len() >= self.config.max_sessions {
+            Err("server_full".to_string())
+        } else {
+            self.accept(query, remote_addr, ctx)
+        };
+        match accepted {
+            Ok(peer) => {
+                self.sessions.insert(remote_addr, peer);
+            }
+            Err(reason) => {
+                info!(event = "udp_rejected", %remote_addr, reason, "refusing udp client");
+                self.send_control(PacketKind::Rejected, reason.as_bytes(), remote_addr);
+            }
+        }
+    }
+
+    /// starts a peer for a client's `Connect`, the error is the reason sent back
+    fn accept(&self, query: &[u8], remote_addr: SocketAddr, ctx: &mut Context<Self>) -> Result<Addr<UdpPeer>, String> {
+        let query = std::str::from_utf8(query).map_err(|_| "invalid query".to_string())?;
+        let query = ConnectQuery::parse(query).map_err(|error| error.to_string())?;
+        if query.replay.is_some() {
+            return Err("replays are only streamed over websockets".to_string());
+        }
+        let setup = PeerSetup::resolve(&query, &self.room_registry, self.profile_store.as_ref(), &self.spectator_config)
+            .map_err(|error| error.to_string())?;
+
+        let rate_limiter = InboundRateLimiter::new(self.rate_limit_config.clone(), self.rate_limit_metrics.clone());
+        let udp_server = ctx.address().recipient();
+        Ok(UdpPeer::create(setup, self.socket.clone(), remote_addr, rate_limiter, &self.config, udp_server).start())
+    }
+
+    fn send_control(&self, kind: PacketKind, payload: &[u8], remote_addr: SocketAddr) {
+        if let Err(error) = self.socket.try_send_to(&Packet::control(kind, payload).write(), remote_addr) {
+            warn!(%remote_addr, %error, ?kind, "could not answer udp client");
+        }
+    }
+}
+
+/// Stateless `Connect` cookies, a keyed hash of the client's address and the current period
+struct ConnectCookies {
+    secret: [u8; 32],
+    started: Instant,
+}
+
+impl ConnectCookies {
+    fn new() -> Self {
+        let mut secret = [0; 32];
+        rand::thread_rng().fill_bytes(&mut secret);
+        Self { secret, started: Instant::now() }
+    }
+
+    fn issue(&self, remote_addr: SocketAddr, now: Instant) -> [u8; COOKIE_LEN] {
+        self.cookie(remote_addr, self.period(now))
+    }
+
+    /// cookies of the current and the previous period are accepted
+    fn verify(&self, cookie: &[u8; COOKIE_LEN], remote_addr: SocketAddr, now: Instant) -> bool {
+        let period = self.period(now);
+        let current = self.cookie(remote_addr, period).ct_eq(cookie);
+        let previous = self.cookie(remote_addr, period.wrapping_sub(1)).ct_eq(cookie);
+        bool::from(current | previous)
+    }
+
+    fn period(&self, now: Instant) -> u64 {
+        now.duration_since(self.started).as_secs() / COOKIE_PERIOD.as_secs()
+    }
+
+    fn cookie(&self, remote_addr: SocketAddr, period: u64) -> [u8; COOKIE_LEN] {
+        let hash = Sha256::new()
+            .chain_update(self.secret)
+            .chain_update(period.to_le_bytes())
+            .chain_update(remote_addr.to_string())
+            .finalize();
+        let mut cookie = [0; COOKIE_LEN];
+        cookie.copy_from_slice(&hash[..COOKIE_LEN]);
+        cookie
+    }
+}
+
+impl Actor for UdpServer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let buf = vec![0; MAX_DATAGRAM_LEN];
+        let datagrams = stream::unfold((self.socket.clone(), buf), |(socket, mut buf)| async move {
+            let datagram = socket.recv_from(&mut buf).await.map(|(len, remote_addr)| (buf[..len].to_vec(), remote_addr));
+            Some((datagram, (socket, buf)))
+        });
+        ctx.add_stream(datagrams);
+    }
+}
+
+impl StreamHandler<io::Result<(Vec<u8>, SocketAddr)>> for UdpServer {
+    fn handle(&mut self, datagram: io::Result<(Vec<u8>, SocketAddr)>, ctx: &mut Self::Context) {
+        let (bytes, remote_addr) = match datagram {
+            Ok(datagram) => datagram,
+            Err(error) => {
+                // e.g. an ICMP port unreachable for a client that has gone away
+                debug!(%error, "udp receive failed");
+                return;
+            }
+        };
+        // a stopped peer may not have told us yet, its client starts over
+        if let Some(peer) = self.sessions.get(&remote_addr).filter(|peer| peer.connected()) {
+            peer.do_send(UdpDatagram(bytes));
+            return;
+        }
+
+        match Packet::read(&bytes) {
+            Some(packet) if packet.kind == PacketKind::Connect => self.connect(packet.payload, remote_addr, ctx),
+            _ => debug!(%remote_addr, frame_len = bytes.len(), "ignoring datagram from unknown client"),
+        }
+    }
+}
+
+impl Handler<UdpSessionClosed> for UdpServer {
+    type Result = ();
+
+    fn handle(&mut self, msg: UdpSessionClosed, _: &mut Self::Context) {
+        // the client may have connected again already
+        if self.sessions.get(&msg.remote_addr).is_some_and(|peer| !peer.connected()) {
+            self.sessions.remove(&msg.remote_addr);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod config;
+pub mod game_client;
+#[allow(clippy::all, warnings)]
+pub mod game_schema_generated;
+pub mod game_server;
+pub mod route_handlers;
+pub mod server;
+pub mod telemetry;
+pub mod udp_client;
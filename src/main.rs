@@ -1,35 +1,13 @@
-mod game_schema_generated;
-mod game_server;
-mod route_handlers;
-
-use crate::game_server::game_server::GameServer;
-use crate::route_handlers::create_ws::create_ws;
-use crate::route_handlers::stats::get_stats;
-use actix::{Actor};
-use actix_web::{web, App, HttpServer};
-use log::info;
-use std::sync::atomic::AtomicUsize;
-use std::sync::Arc;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::server;
+use demo_game_server::telemetry::init_tracing;
+use std::net::TcpListener;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    env_logger::init();
-
-    // set up applications state
-    // keep a count of the number of visitors
-    let players_online_count = Arc::new(AtomicUsize::new(0));
-
-    let game_server = GameServer::new(players_online_count.clone()).start();
+    init_tracing();
 
-    info!("running server in port 8090");
-    HttpServer::new(move || {
-        App::new()
-            .app_data(web::Data::from(players_online_count.clone()))
-            .app_data(web::Data::new(game_server.clone()))
-            .route("/stats", web::get().to(get_stats))
-            .route("/ws", web::get().to(create_ws))
-    })
-    .bind(("127.0.0.1", 8090))?
-    .run()
-    .await
+    let config = ServerConfig::load()?;
+    let listener = TcpListener::bind(("127.0.0.1", 8090))?;
+    server::run(config, listener)?.await
 }
@@ -1,18 +1,72 @@
-use crate::game_server::game_server::GameServer;
+use std::io;
+use std::sync::atomic::Ordering;
+use crate::config::SpectatorConfig;
+use crate::game_server::compression::FrameEncoder;
+use crate::game_server::outbox::{OutboundConfig, Outbox};
 use crate::game_server::peer::Peer;
-use actix::Addr;
-use actix_web::{web, Error, HttpRequest, HttpResponse};
+use crate::game_server::peer_setup::{ConnectQuery, PeerSetup, SetupError};
+use crate::game_server::profile::ProfileStore;
+use crate::game_server::replay::{Replay, ReplayConfig};
+use crate::game_server::replay_peer::ReplayPeer;
+use crate::game_server::room_registry::RoomRegistry;
+use crate::game_server::rate_limiter::{InboundRateLimiter, RateLimitConfig, RateLimitMetrics};
+use crate::route_handlers::rooms::room_error;
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
 use actix_web_actors::ws;
+use futures_util::StreamExt;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn create_ws(
     req: HttpRequest,
     stream: web::Payload,
-    srv: web::Data<Addr<GameServer>>,
+    query: web::Query<ConnectQuery>,
+    room_registry: web::Data<RoomRegistry>,
+    profile_store: web::Data<dyn ProfileStore>,
+    rate_limit_config: web::Data<RateLimitConfig>,
+    rate_limit_metrics: web::Data<RateLimitMetrics>,
+    spectator_config: web::Data<SpectatorConfig>,
+    replay_config: web::Data<ReplayConfig>,
+    outbound_config: web::Data<OutboundConfig>,
+    frame_encoder: web::Data<FrameEncoder>,
 ) -> Result<HttpResponse, Error> {
-    let game_server_addr = srv.get_ref().clone();
-    ws::start(
-        Peer::create(game_server_addr),
-        &req,
-        stream,
-    )
+    let remote_addr = req
+        .peer_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    if let Some(replay_name) = &query.replay {
+        let (directory, name, max_bytes) = (replay_config.directory.clone(), replay_name.clone(), replay_config.max_playback_bytes);
+        let replay = web::block(move || Replay::open(&directory, &name, max_bytes))
+            .await?
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => error::ErrorNotFound(e),
+                io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => error::ErrorBadRequest(e),
+                _ => error::ErrorInternalServerError(e),
+            })?;
+        return ws::start(ReplayPeer::create(replay_name, replay, remote_addr), &req, stream);
+    }
+    let setup = PeerSetup::resolve(&query, &room_registry, profile_store.get_ref(), &spectator_config).map_err(setup_error)?;
+    let rate_limiter = InboundRateLimiter::new(rate_limit_config.into_inner(), rate_limit_metrics.into_inner());
+
+    let outbox = Outbox::new(outbound_config.into_inner());
+    let compression = query.compress.then(|| frame_encoder.get_ref().clone());
+
+    // the peer sees how far the connection lags behind by what it has taken from the websocket context
+    let drained = outbox.drained_counter();
+    let peer = Peer::create(setup, remote_addr, rate_limiter, outbox, compression);
+    let frames = ws::WebsocketContext::create(peer, stream).inspect(move |chunk| {
+        if let Ok(bytes) = chunk {
+            drained.fetch_add(bytes.len(), Ordering::Relaxed);
+        }
+    });
+    Ok(ws::handshake(&req)?.streaming(frames))
+}
+
+/// the HTTP status of a connection that can't join
+fn setup_error(error: SetupError) -> Error {
+    match error {
+        SetupError::Room(error) => room_error(error),
+        SetupError::UnknownToken => error::ErrorUnauthorized(error),
+        SetupError::Store(error) => error::ErrorInternalServerError(error),
+    }
 }
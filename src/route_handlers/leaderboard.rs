@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+use crate::game_server::game_mode::GameModeKind;
+use crate::game_server::leaderboard::{LeaderboardCache, LeaderboardEntry, LeaderboardPeriod};
+use crate::game_server::profile::{unix_ms, ProfileStore};
+use crate::route_handlers::profiles::authenticate;
+
+/// most entries returned at once
+const MAX_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LeaderboardQuery {
+    period: LeaderboardPeriod,
+    /// only count matches of this game mode
+    mode: Option<GameModeKind>,
+    offset: usize,
+    limit: usize,
+}
+
+impl Default for LeaderboardQuery {
+    fn default() -> Self {
+        Self {
+            period: LeaderboardPeriod::default(),
+            mode: None,
+            offset: 0,
+            limit: 20,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LeaderboardResponse {
+    period: LeaderboardPeriod,
+    mode: Option<&'static str>,
+    /// ranked profiles in total, for paging
+    total: usize,
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// One page of the leaderboard
+pub async fn get_leaderboard(query: web::Query<LeaderboardQuery>, store: web::Data<dyn ProfileStore>, cache: web::Data<LeaderboardCache>) -> Result<HttpResponse, Error> {
+    let entries = ranked(&query, store, cache).await?;
+    let total = entries.len();
+    let page = entries.iter().skip(query.offset).take(query.limit.min(MAX_LIMIT)).cloned().collect();
+    Ok(HttpResponse::Ok().json(response(&query, total, page)))
+}
+
+/// The `limit` entries around the token owner's own rank
+pub async fn get_leaderboard_around_me(req: HttpRequest, query: web::Query<LeaderboardQuery>, store: web::Data<dyn ProfileStore>, cache: web::Data<LeaderboardCache>) -> Result<HttpResponse, Error> {
+    let profile = authenticate(&req, store.get_ref())?;
+    let entries = ranked(&query, store, cache).await?;
+    let index = entries
+        .iter()
+        .position(|entry| entry.profile_id == profile.id)
+        .ok_or_else(|| error::ErrorNotFound("profile not ranked"))?;
+
+    let limit = query.limit.clamp(1, MAX_LIMIT);
+    let start = index.saturating_sub(limit / 2).min(entries.len().saturating_sub(limit));
+    let total = entries.len();
+    let window = entries.iter().skip(start).take(limit).cloned().collect();
+    Ok(HttpResponse::Ok().json(response(&query, total, window)))
+}
+
+/// ranking reads every match of the period, keep that off the async workers
+async fn ranked(query: &LeaderboardQuery, store: web::Data<dyn ProfileStore>, cache: web::Data<LeaderboardCache>) -> Result<Arc<Vec<LeaderboardEntry>>, Error> {
+    let (period, mode) = (query.period, query.mode);
+    let store = store.into_inner();
+    let cache = cache.into_inner();
+    web::block(move || cache.rank(store.as_ref(), period, mode.map(|mode| mode.name()), unix_ms()))
+        .await?
+        .map_err(error::ErrorInternalServerError)
+}
+
+fn response(query: &LeaderboardQuery, total: usize, entries: Vec<LeaderboardEntry>) -> LeaderboardResponse {
+    LeaderboardResponse {
+        period: query.period,
+        mode: query.mode.map(|mode| mode.name()),
+        total,
+        entries,
+    }
+}
@@ -1,2 +1,16 @@
+use actix_web::http::header;
+use actix_web::HttpRequest;
+
 pub mod create_ws;
+pub mod leaderboard;
+pub mod profiles;
+pub mod rooms;
 pub mod stats;
+
+/// the token from `Authorization: Bearer <token>`
+pub fn bearer_token(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
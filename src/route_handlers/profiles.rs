@@ -0,0 +1,102 @@
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::game_server::profile::{create_profile, Profile, ProfileConfig, ProfileStore};
+use crate::route_handlers::bearer_token;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateProfileRequest {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateProfileResponse {
+    profile: Profile,
+    /// connect with `/ws?token=<token>` to play as this profile
+    token: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UpdateProfileRequest {
+    name: Option<String>,
+    skin: Option<String>,
+    /// `null` leaves the team up to the server
+    #[serde(deserialize_with = "present")]
+    team: Option<Option<u8>>,
+}
+
+/// Creates a profile, the response holds the token to play and manage it with
+pub async fn register_profile(body: web::Json<CreateProfileRequest>, store: web::Data<dyn ProfileStore>, config: web::Data<ProfileConfig>) -> Result<HttpResponse, Error> {
+    let name = profile_name(&body.name, &config)?;
+    // stores write to disk, keep that off the async workers
+    let store = store.into_inner();
+    let (profile, token) = web::block(move || create_profile(store.as_ref(), &name))
+        .await?
+        .map_err(error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Created().json(CreateProfileResponse { profile, token }))
+}
+
+/// Profile of the token's owner
+pub async fn get_own_profile(req: HttpRequest, store: web::Data<dyn ProfileStore>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse::Ok().json(authenticate(&req, store.get_ref())?))
+}
+
+/// Changes the name and settings of the token's owner, the next match they join uses them
+pub async fn update_own_profile(req: HttpRequest, body: web::Json<UpdateProfileRequest>, store: web::Data<dyn ProfileStore>, config: web::Data<ProfileConfig>) -> Result<HttpResponse, Error> {
+    let profile = authenticate(&req, store.get_ref())?;
+    let body = body.into_inner();
+    let name = body.name.map(|name| profile_name(&name, &config)).transpose()?;
+
+    let store = store.into_inner();
+    let profile = web::block(move || {
+        store.update(&profile.id, &mut |profile| {
+            if let Some(name) = &name {
+                profile.name = name.clone();
+            }
+            if let Some(skin) = &body.skin {
+                profile.settings.skin = skin.trim().to_string();
+            }
+            if let Some(team) = body.team {
+                profile.settings.team = team;
+            }
+        })
+    })
+    .await?
+    .map_err(error::ErrorInternalServerError)?
+    .ok_or_else(|| error::ErrorNotFound("profile not found"))?;
+    Ok(HttpResponse::Ok().json(profile))
+}
+
+pub async fn get_profile(profile_id: web::Path<String>, store: web::Data<dyn ProfileStore>) -> Result<HttpResponse, Error> {
+    match store.load(&profile_id).map_err(error::ErrorInternalServerError)? {
+        Some(profile) => Ok(HttpResponse::Ok().json(profile)),
+        None => Err(error::ErrorNotFound("profile not found")),
+    }
+}
+
+/// the profile behind the request's bearer token
+pub fn authenticate(req: &HttpRequest, store: &dyn ProfileStore) -> Result<Profile, Error> {
+    let token = bearer_token(req).ok_or_else(|| error::ErrorUnauthorized("profile token required"))?;
+    store
+        .authenticate(token)
+        .map_err(error::ErrorInternalServerError)?
+        .ok_or_else(|| error::ErrorUnauthorized("unknown profile token"))
+}
+
+fn profile_name(name: &str, config: &ProfileConfig) -> Result<String, Error> {
+    let name = name.trim().chars().take(config.max_name_length).collect::<String>();
+    if name.is_empty() {
+        return Err(error::ErrorBadRequest("name required"));
+    }
+    Ok(name)
+}
+
+/// tells a field sent as `null` apart from a missing one
+fn present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
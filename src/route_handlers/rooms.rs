@@ -0,0 +1,109 @@
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+use crate::game_server::game_mode::GameModeKind;
+use crate::game_server::message_types::{GetRoomInfo, KickPlayer, UpdateRoomSettings};
+use crate::game_server::room_registry::{RoomError, RoomRegistry};
+use crate::route_handlers::bearer_token;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CreateRoomRequest {
+    password: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateRoomResponse {
+    code: String,
+    owner_token: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RoomResponse {
+    code: String,
+    password_protected: bool,
+    players: usize,
+    max_players: usize,
+    game_mode: &'static str,
+    average_rating: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UpdateRoomRequest {
+    /// an empty password removes it
+    password: Option<String>,
+    max_players: Option<u8>,
+    game_mode: Option<GameModeKind>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KickRequest {
+    player_id: usize,
+}
+
+/// Creates a private room, the body is optional
+pub async fn create_room(body: Option<web::Json<CreateRoomRequest>>, room_registry: web::Data<RoomRegistry>) -> Result<HttpResponse, Error> {
+    let password = body.and_then(|body| body.into_inner().password);
+    let room = room_registry.create(password).map_err(room_error)?;
+    Ok(HttpResponse::Created().json(CreateRoomResponse {
+        code: room.code,
+        owner_token: room.owner_token,
+    }))
+}
+
+pub async fn get_room(code: web::Path<String>, room_registry: web::Data<RoomRegistry>) -> Result<HttpResponse, Error> {
+    let (addr, password_protected) = room_registry.lookup(&code).map_err(room_error)?;
+    let info = addr.send(GetRoomInfo).await.map_err(error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Ok().json(RoomResponse {
+        code: code.to_uppercase(),
+        password_protected,
+        players: info.players,
+        max_players: info.max_players,
+        game_mode: info.game_mode.name(),
+        average_rating: info.average_rating,
+    }))
+}
+
+/// Changes the settings of a room, only for its owner
+pub async fn update_room(req: HttpRequest, code: web::Path<String>, body: web::Json<UpdateRoomRequest>, room_registry: web::Data<RoomRegistry>) -> Result<HttpResponse, Error> {
+    let owner_token = owner_token(&req)?;
+    let addr = room_registry.owned(&code, owner_token).map_err(room_error)?;
+    let body = body.into_inner();
+
+    addr.send(UpdateRoomSettings { max_players: body.max_players, game_mode: body.game_mode })
+        .await
+        .map_err(error::ErrorInternalServerError)?
+        .map_err(error::ErrorConflict)?;
+    if let Some(password) = body.password {
+        room_registry.set_password(&code, owner_token, password).map_err(room_error)?;
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Removes a player or bot from a room, only for its owner
+pub async fn kick_player(req: HttpRequest, code: web::Path<String>, body: web::Json<KickRequest>, room_registry: web::Data<RoomRegistry>) -> Result<HttpResponse, Error> {
+    let addr = room_registry.owned(&code, owner_token(&req)?).map_err(room_error)?;
+    let kicked = addr.send(KickPlayer { player_id: body.player_id })
+        .await
+        .map_err(error::ErrorInternalServerError)?;
+    if !kicked {
+        return Err(error::ErrorNotFound("player not in room"));
+    }
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub fn room_error(error: RoomError) -> Error {
+    match error {
+        RoomError::NotFound => error::ErrorNotFound(error),
+        RoomError::WrongPassword | RoomError::NotOwner => error::ErrorForbidden(error),
+        RoomError::TooManyRooms | RoomError::NoSeats => error::ErrorServiceUnavailable(error),
+        RoomError::PartyTooLarge => error::ErrorConflict(error),
+    }
+}
+
+fn owner_token(req: &HttpRequest) -> Result<&str, Error> {
+    bearer_token(req).ok_or_else(|| error::ErrorUnauthorized("owner token required"))
+}
@@ -1,16 +1,30 @@
 use actix_web::{web, Responder};
 use serde::Serialize;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::game_server::compression::{CompressionMetrics, CompressionMetricsSnapshot};
+use crate::game_server::rate_limiter::{RateLimitMetrics, RateLimitMetricsSnapshot};
+use crate::game_server::room_registry::RoomRegistry;
 
 #[derive(Debug, Serialize)]
 struct StatsResponseData {
     #[serde(rename = "playersOnline")]
     players_online: usize,
+    #[serde(rename = "publicRooms")]
+    public_rooms: usize,
+    #[serde(rename = "privateRooms")]
+    private_rooms: usize,
+    #[serde(rename = "rateLimit")]
+    rate_limit: RateLimitMetricsSnapshot,
+    compression: CompressionMetricsSnapshot,
 }
 
 /// Displays state
-pub async fn get_stats(count: web::Data<AtomicUsize>) -> impl Responder {
+pub async fn get_stats(count: web::Data<AtomicUsize>, rate_limit_metrics: web::Data<RateLimitMetrics>, compression_metrics: web::Data<CompressionMetrics>, room_registry: web::Data<RoomRegistry>) -> impl Responder {
     let players_online = count.load(Ordering::SeqCst);
-    let response_data = StatsResponseData { players_online };
-    return web::Json(response_data);
+    let public_rooms = room_registry.public_rooms();
+    let private_rooms = room_registry.len();
+    let rate_limit = rate_limit_metrics.snapshot();
+    let compression = compression_metrics.snapshot();
+    let response_data = StatsResponseData { players_online, public_rooms, private_rooms, rate_limit, compression };
+    web::Json(response_data)
 }
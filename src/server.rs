@@ -0,0 +1,122 @@
+use std::io;
+use std::net::TcpListener;
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use actix::Actor;
+use actix_web::dev::Server;
+use actix_web::{web, App, HttpServer};
+use tracing::info;
+use crate::config::ServerConfig;
+use crate::game_server::compression::{CompressionMetrics, FrameEncoder};
+use crate::game_server::game_map::GameMap;
+use crate::game_server::game_world::WorldSettings;
+use crate::game_server::leaderboard::LeaderboardCache;
+use crate::game_server::party::PartyRegistry;
+use crate::game_server::profile::{open_store, ProfileStore};
+use crate::game_server::rate_limiter::RateLimitMetrics;
+use crate::game_server::room_registry::{RoomRegistry, RoomTemplate};
+use crate::game_server::udp_server::UdpServer;
+use crate::route_handlers::create_ws::create_ws;
+use crate::route_handlers::leaderboard::{get_leaderboard, get_leaderboard_around_me};
+use crate::route_handlers::profiles::{get_own_profile, get_profile, register_profile, update_own_profile};
+use crate::route_handlers::rooms::{create_room, get_room, kick_player, update_room};
+use crate::route_handlers::stats::get_stats;
+
+/// Starts the game server actor and serves the HTTP routes on `listener`, and UDP clients too when `udp.bind` is set.
+///
+/// Has to be called from within a running actix system, the returned `Server` runs once awaited or spawned.
+pub fn run(config: ServerConfig, listener: TcpListener) -> io::Result<Server> {
+    let game_map = Arc::new(match &config.map_file {
+        Some(path) => GameMap::load(path)?,
+        None => GameMap::default(),
+    });
+    info!(map_id = %game_map.id, obstacles = game_map.obstacles.len(), "map loaded");
+
+    // set up applications state
+    // keep a count of the number of visitors
+    let players_online_count = Arc::new(AtomicUsize::new(0));
+    let rate_limit_config = Arc::new(config.rate_limit);
+    let rate_limit_metrics = Arc::new(RateLimitMetrics::default());
+    let spectator_config = Arc::new(config.spectator);
+    let replay_config = Arc::new(config.replay.clone());
+    let outbound_config = Arc::new(config.outbound);
+    let compression_metrics = Arc::new(CompressionMetrics::default());
+    let frame_encoder = Arc::new(FrameEncoder::new(Arc::new(config.compression), compression_metrics.clone()));
+
+    let profile_store: Arc<dyn ProfileStore> = Arc::from(open_store(&config.profiles)?);
+    let profile_config = Arc::new(config.profiles);
+    let leaderboard_cache = Arc::new(LeaderboardCache::default());
+
+    let seed = config.simulation.seed_or_random();
+    info!(seed, "simulation seed");
+    let world_settings = WorldSettings {
+        map: game_map,
+        movement: config.movement,
+        spawn_strategy: config.spawn_strategy,
+        teams: config.teams,
+        match_config: config.match_config,
+    };
+
+    let room_template = RoomTemplate {
+        players_online_count: players_online_count.clone(),
+        world: world_settings,
+        chat: config.chat,
+        replay: config.replay,
+        bots: config.bots,
+        lobby: config.lobby,
+        parties: Arc::new(PartyRegistry::new(config.party)),
+        profile_store: profile_store.clone(),
+        ratings: config.ratings,
+        frame_encoder: frame_encoder.as_ref().clone(),
+    };
+
+    let room_registry = Arc::new(RoomRegistry::new(config.private_rooms, config.matchmaking, room_template, seed));
+
+    if let Some(bind) = &config.udp.bind {
+        let socket = std::net::UdpSocket::bind(bind)?;
+        info!(addr = %socket.local_addr()?, "listening for udp clients");
+        UdpServer::new(
+            socket,
+            config.udp.clone(),
+            room_registry.clone(),
+            profile_store.clone(),
+            rate_limit_config.clone(),
+            rate_limit_metrics.clone(),
+            spectator_config.clone(),
+        )?
+        .start();
+    }
+
+    info!(addr = %listener.local_addr()?, "running server");
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(web::Data::from(players_online_count.clone()))
+            .app_data(web::Data::from(rate_limit_config.clone()))
+            .app_data(web::Data::from(rate_limit_metrics.clone()))
+            .app_data(web::Data::from(spectator_config.clone()))
+            .app_data(web::Data::from(replay_config.clone()))
+            .app_data(web::Data::from(outbound_config.clone()))
+            .app_data(web::Data::from(frame_encoder.clone()))
+            .app_data(web::Data::from(compression_metrics.clone()))
+            .app_data(web::Data::from(room_registry.clone()))
+            .app_data(web::Data::from(profile_store.clone()))
+            .app_data(web::Data::from(profile_config.clone()))
+            .app_data(web::Data::from(leaderboard_cache.clone()))
+            .route("/stats", web::get().to(get_stats))
+            .route("/leaderboard", web::get().to(get_leaderboard))
+            .route("/leaderboard/me", web::get().to(get_leaderboard_around_me))
+            .route("/rooms", web::post().to(create_room))
+            .route("/rooms/{code}", web::get().to(get_room))
+            .route("/rooms/{code}", web::patch().to(update_room))
+            .route("/rooms/{code}/kick", web::post().to(kick_player))
+            .route("/profiles", web::post().to(register_profile))
+            .route("/profiles/me", web::get().to(get_own_profile))
+            .route("/profiles/me", web::patch().to(update_own_profile))
+            .route("/profiles/{profile_id}", web::get().to(get_profile))
+            .route("/ws", web::get().to(create_ws))
+    })
+    .listen(listener)?
+    .run();
+
+    Ok(server)
+}
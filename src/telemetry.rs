@@ -0,0 +1,18 @@
+use std::env;
+use tracing_subscriber::EnvFilter;
+
+/// Installs the global tracing subscriber.
+///
+/// Verbosity is controlled with `RUST_LOG` (defaults to `info`), and setting
+/// `LOG_FORMAT=json` switches the output to one JSON object per line.
+pub fn init_tracing() {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(env_filter)
+        .with_target(false);
+
+    match env::var("LOG_FORMAT").as_deref() {
+        Ok("json") => builder.json().with_current_span(true).with_span_list(false).init(),
+        _ => builder.init(),
+    }
+}
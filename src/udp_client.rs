@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use crate::game_client::ClientError;
+use crate::game_server::chat::ChatChannel;
+use crate::game_server::flatbuffers_utils::{create_chat_send_bytes, create_lobby_ready_bytes, create_lobby_setup_bytes, create_player_moved_bytes, create_weapon_fired_bytes, read_player_data};
+use crate::game_server::message_types::PeerPlayerData;
+use crate::game_server::peer::{ClientControls, ClientPosition};
+use crate::game_server::reliability::{Channel, Packet, PacketKind, ReliableEndpoint, COOKIE_LEN};
+
+/// how often `Connect` and unacknowledged reliable packets are resent
+const RESEND_AFTER: Duration = Duration::from_millis(100);
+const MAX_RESENDS: u32 = 20;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_DATAGRAM_LEN: usize = 65_507;
+
+/// Headless client speaking the game protocol over the UDP transport. Resends and acks only go out while `recv` or
+/// `wait_for` is awaited.
+pub struct UdpGameClient {
+    socket: UdpSocket,
+    endpoint: ReliableEndpoint,
+    // data that arrived while connecting or waiting for something else
+    received: VecDeque<PeerPlayerData>,
+    disconnect_reason: Option<String>,
+    // cookie of the server's last `Challenge`, sent back with `Connect`
+    cookie: [u8; COOKIE_LEN],
+}
+
+impl UdpGameClient {
+    /// `query` takes the same parameters as `/ws`, e.g. `room=ABCDEF&token=...`. Returns once the server sent its
+    /// first message.
+    pub async fn connect(server_addr: SocketAddr, query: &str) -> Result<Self, ClientError> {
+        let local_addr = if server_addr.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+        let socket = UdpSocket::bind(local_addr).await?;
+        socket.connect(server_addr).await?;
+        let mut client = Self {
+            socket,
+            endpoint: ReliableEndpoint::new(RESEND_AFTER, MAX_RESENDS),
+            received: VecDeque::new(),
+            disconnect_reason: None,
+            cookie: [0; COOKIE_LEN],
+        };
+
+        let deadline = Instant::now() + CONNECT_TIMEOUT;
+        while client.received.is_empty() {
+            if let Some(reason) = client.disconnect_reason.take() {
+                return Err(ClientError::Rejected(reason));
+            }
+            if Instant::now() >= deadline {
+                return Err(ClientError::Timeout);
+            }
+            let payload = [&client.cookie[..], query.as_bytes()].concat();
+            client.socket.send(&Packet::control(PacketKind::Connect, &payload).write()).await?;
+            if let Ok(result) = tokio::time::timeout(RESEND_AFTER, client.receive_datagram()).await {
+                result?;
+            }
+        }
+        client.flush().await?;
+        Ok(client)
+    }
+
+    /// unreliable, a lost move is superseded by the next one
+    pub async fn send_player_moved(&mut self, player_controls: ClientControls, player_position: ClientPosition) -> Result<(), ClientError> {
+        self.send(Channel::Unreliable, create_player_moved_bytes(player_controls, player_position)).await
+    }
+
+    pub async fn send_weapon_fired(&mut self, angle: f32, power: f32) -> Result<(), ClientError> {
+        self.send(Channel::Reliable, create_weapon_fired_bytes(angle, power)).await
+    }
+
+    /// `target_player_id` is only used for `ChatChannel::Direct`
+    pub async fn send_chat(&mut self, channel: ChatChannel, target_player_id: usize, text: &str) -> Result<(), ClientError> {
+        self.send(Channel::Reliable, create_chat_send_bytes(channel, target_player_id, text)).await
+    }
+
+    /// `team` of `None` leaves the team up to the server
+    pub async fn send_lobby_setup(&mut self, name: &str, skin: &str, team: Option<u8>) -> Result<(), ClientError> {
+        self.send(Channel::Reliable, create_lobby_setup_bytes(name, skin, team)).await
+    }
+
+    pub async fn send_lobby_ready(&mut self, ready: bool) -> Result<(), ClientError> {
+        self.send(Channel::Reliable, create_lobby_ready_bytes(ready)).await
+    }
+
+    pub async fn send_raw(&mut self, channel: Channel, bytes: Vec<u8>) -> Result<(), ClientError> {
+        self.send(channel, bytes).await
+    }
+
+    async fn send(&mut self, channel: Channel, bytes: Vec<u8>) -> Result<(), ClientError> {
+        let packet = self.endpoint.send(channel, bytes, Instant::now());
+        self.socket.send(&packet).await?;
+        Ok(())
+    }
+
+    /// next message from the server, `None` once the server disconnected the client
+    pub async fn recv(&mut self) -> Result<Option<PeerPlayerData>, ClientError> {
+        loop {
+            if let Some(data) = self.received.pop_front() {
+                return Ok(Some(data));
+            }
+            if self.disconnect_reason.is_some() {
+                return Ok(None);
+            }
+            if let Ok(result) = tokio::time::timeout(RESEND_AFTER, self.receive_datagram()).await {
+                result?;
+            }
+            self.flush().await?;
+        }
+    }
+
+    /// skips messages until one matches `predicate`
+    pub async fn wait_for<F>(&mut self, timeout: Duration, mut predicate: F) -> Result<PeerPlayerData, ClientError>
+    where
+        F: FnMut(&PeerPlayerData) -> bool,
+    {
+        let wait = async {
+            loop {
+                match self.recv().await? {
+                    Some(data) if predicate(&data) => return Ok(data),
+                    Some(_) => {}
+                    None => return Err(ClientError::Closed),
+                }
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.unwrap_or(Err(ClientError::Timeout))
+    }
+
+    /// reason sent by the server, once `recv` has seen it disconnect the client
+    pub fn disconnect_reason(&self) -> Option<&str> {
+        self.disconnect_reason.as_deref()
+    }
+
+    pub async fn close(self) -> Result<(), ClientError> {
+        self.socket.send(&Packet::control(PacketKind::Disconnect, &[]).write()).await?;
+        Ok(())
+    }
+
+    async fn receive_datagram(&mut self) -> Result<(), ClientError> {
+        let mut buf = vec![0; MAX_DATAGRAM_LEN];
+        let len = self.socket.recv(&mut buf).await?;
+        let Some(packet) = Packet::read(&buf[..len]) else {
+            return Ok(());
+        };
+        match packet.kind {
+            PacketKind::Data | PacketKind::Ack => {
+                if let Some(payload) = self.endpoint.receive(&packet) {
+                    if let Some(data) = read_player_data(payload).map_err(ClientError::InvalidMessage)? {
+                        self.received.push_back(data);
+                    }
+                }
+            }
+            PacketKind::Rejected | PacketKind::Disconnect => {
+                self.disconnect_reason = Some(String::from_utf8_lossy(packet.payload).into_owned());
+            }
+            PacketKind::Challenge => {
+                if let Ok(cookie) = packet.payload.try_into() {
+                    self.cookie = cookie;
+                }
+            }
+            PacketKind::Connect => {}
+        }
+        Ok(())
+    }
+
+    /// resends what is due and acks what arrived
+    async fn flush(&mut self) -> Result<(), ClientError> {
+        let resends = self.endpoint.resend(Instant::now()).map_err(|_| ClientError::Timeout)?;
+        for packet in resends {
+            self.socket.send(&packet).await?;
+        }
+        if let Some(ack) = self.endpoint.ack() {
+            self.socket.send(&ack).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_server::bot::{BotBehaviour, BotConfig};
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use common::{join, joined_id, spawn_server, TIMEOUT};
+
+fn bot_config() -> ServerConfig {
+    ServerConfig {
+        bots: BotConfig {
+            enabled: true,
+            behaviours: vec![BotBehaviour::Wander],
+            ..BotConfig::default()
+        },
+        ..ServerConfig::default()
+    }
+}
+
+#[actix_web::test]
+async fn bot_fills_the_empty_slot_and_moves() {
+    let url = spawn_server(bot_config());
+
+    let (mut human, others) = join(&url).await;
+    assert!(others.is_empty());
+    let bot_id = joined_id(&mut human).await;
+
+    let moved = human.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerPositionUpdate { .. })).await.unwrap();
+    assert!(matches!(moved, PeerPlayerData::RemotePeerPositionUpdate { player_id, .. } if player_id == bot_id));
+}
+
+#[actix_web::test]
+async fn bot_makes_room_for_a_human() {
+    let url = spawn_server(bot_config());
+
+    let (mut first, _) = join(&url).await;
+    let bot_id = joined_id(&mut first).await;
+
+    let (_second, others) = join(&url).await;
+    assert!(!others.contains(&bot_id));
+
+    let left = first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerLeft { .. })).await.unwrap();
+    assert!(matches!(left, PeerPlayerData::RemotePeerLeft { player_id } if player_id == bot_id));
+    let second_id = joined_id(&mut first).await;
+    assert_ne!(second_id, bot_id);
+}
@@ -0,0 +1,40 @@
+use demo_game_server::game_server::chat::{BlockedWordAction, ChatConfig, ChatFilter, ChatFilterResult, ChatModerator, WordListFilter};
+
+fn filter(action: BlockedWordAction) -> WordListFilter {
+    WordListFilter::new(&["Darn".to_string(), "heck".to_string()], action)
+}
+
+fn delivered(result: ChatFilterResult) -> String {
+    match result {
+        ChatFilterResult::Deliver(text) => text,
+        ChatFilterResult::Block(reason) => panic!("blocked: {}", reason),
+    }
+}
+
+#[test]
+fn blocked_words_are_masked_whatever_their_case() {
+    let filter = filter(BlockedWordAction::Mask);
+
+    assert_eq!(delivered(filter.filter(1, "oh DARN, what the Heck!")), "oh ****, what the ****!");
+    assert_eq!(delivered(filter.filter(1, "heck")), "****");
+}
+
+#[test]
+fn message_with_a_blocked_word_is_dropped() {
+    let filter = filter(BlockedWordAction::Drop);
+
+    assert!(matches!(filter.filter(1, "well darn it"), ChatFilterResult::Block("blocked_word")));
+    assert_eq!(delivered(filter.filter(1, "well done")), "well done");
+
+    let moderator = ChatModerator::new(ChatConfig::default(), Box::new(filter));
+    assert_eq!(moderator.moderate(1, "  darn  "), Err("blocked_word"));
+}
+
+#[test]
+fn words_that_only_contain_a_blocked_word_pass_through() {
+    let filter = filter(BlockedWordAction::Mask);
+
+    // whole words only, a blocked word inside a longer one is left alone
+    assert_eq!(delivered(filter.filter(1, "darned hecklers, checkmate")), "darned hecklers, checkmate");
+    assert_eq!(delivered(filter.filter(1, "dar n")), "dar n");
+}
@@ -0,0 +1,79 @@
+// each test binary uses a different subset of these helpers
+#![allow(dead_code)]
+
+use std::net::TcpListener;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::server;
+
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Starts the real server on an ephemeral port, it stops with the test's actix system
+pub fn spawn_server(mut config: ServerConfig) -> String {
+    config.simulation.seed.get_or_insert(7);
+    let listener = TcpListener::bind(("127.0.0.1", 0)).expect("bind ephemeral port");
+    let addr = listener.local_addr().unwrap();
+    let server = server::run(config, listener).expect("start server");
+    actix_web::rt::spawn(server);
+    format!("ws://{}/ws", addr)
+}
+
+/// Connects a player and waits for the world update every peer gets first
+pub async fn join(url: &str) -> (GameClient, Vec<usize>) {
+    let mut client = GameClient::connect(url).await.expect("connect");
+    let world = client
+        .wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. }))
+        .await
+        .expect("world update");
+    let PeerPlayerData::WorldUpdate { world_data, .. } = world else { unreachable!() };
+    (client, world_data.iter().map(|info| info.player_id).collect())
+}
+
+/// Waits for `client` to hear about a new player and returns its id
+pub async fn joined_id(client: &mut GameClient) -> usize {
+    match client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await {
+        Ok(PeerPlayerData::RemotePeerJoined { player_id, .. }) => player_id,
+        other => panic!("expected a join, got {:?}", other),
+    }
+}
+
+/// Sends a plain HTTP/1.1 request to the server behind `url` and returns the status code and body
+pub async fn http(url: &str, method: &str, path: &str, owner_token: Option<&str>, body: Option<&str>) -> (u16, String) {
+    let host = url.trim_start_matches("ws://").trim_end_matches("/ws");
+    let mut request = format!("{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n", method, path, host);
+    if let Some(owner_token) = owner_token {
+        request.push_str(&format!("Authorization: Bearer {}\r\n", owner_token));
+    }
+    let body = body.unwrap_or_default();
+    if !body.is_empty() {
+        request.push_str("Content-Type: application/json\r\n");
+    }
+    request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+
+    let mut stream = TcpStream::connect(host).await.expect("connect");
+    stream.write_all(request.as_bytes()).await.expect("send request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.expect("read response");
+
+    let status = response.split(' ').nth(1).and_then(|status| status.parse().ok()).expect("status code");
+    let body = response.split_once("\r\n\r\n").map(|(_, body)| body.to_string()).unwrap_or_default();
+    (status, body)
+}
+
+/// Repeats a `GET` of `path` until its JSON body satisfies `predicate`, for state the server stores in the background
+pub async fn get_until(url: &str, path: &str, token: Option<&str>, predicate: impl Fn(&serde_json::Value) -> bool) -> serde_json::Value {
+    let deadline = std::time::Instant::now() + TIMEOUT;
+    loop {
+        let (status, body) = http(url, "GET", path, token, None).await;
+        let value = serde_json::from_str(&body).unwrap_or(serde_json::Value::Null);
+        if status == 200 && predicate(&value) {
+            return value;
+        }
+        assert!(std::time::Instant::now() < deadline, "GET {} never matched, last answer {} {}", path, status, body);
+        actix_web::rt::time::sleep(Duration::from_millis(20)).await;
+    }
+}
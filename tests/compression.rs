@@ -0,0 +1,154 @@
+mod common;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use actix::{Actor, Context, Handler};
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::chat::{BlockedWordAction, ChatChannel, ChatModerator, WordListFilter};
+use demo_game_server::game_server::compression::{decode_frame, CompressionConfig, CompressionMetrics, FrameEncoder, COMPRESSED_FLAG};
+use demo_game_server::game_server::game_map::GameMap;
+use demo_game_server::game_server::game_server::GameServer;
+use demo_game_server::game_server::game_world::WorldSettings;
+use demo_game_server::game_server::message_types::{Connect, PeerAddr, PeerBroadcast, PeerPlayerData, PeerRole};
+use demo_game_server::game_server::simulation::{seeded_rng, SystemClock};
+use common::{http, join, joined_id, spawn_server, TIMEOUT};
+
+fn encoder(threshold_bytes: usize) -> (FrameEncoder, Arc<CompressionMetrics>) {
+    let metrics = Arc::new(CompressionMetrics::default());
+    let config = CompressionConfig { threshold_bytes, ..CompressionConfig::default() };
+    (FrameEncoder::new(Arc::new(config), metrics.clone()), metrics)
+}
+
+#[test]
+fn only_large_payloads_are_deflated() {
+    let (encoder, metrics) = encoder(64);
+
+    let small = vec![7; 63];
+    let frame = encoder.encode(small.clone());
+    assert_eq!(frame[0], 0);
+    assert_eq!(decode_frame(&frame).unwrap(), small);
+
+    let large = b"player position ".repeat(64);
+    let frame = encoder.encode(large.clone());
+    assert_eq!(frame[0], COMPRESSED_FLAG);
+    assert!(frame.len() < large.len() / 4);
+    assert_eq!(decode_frame(&frame).unwrap(), large);
+
+    let metrics = metrics.snapshot();
+    assert_eq!((metrics.frames, metrics.compressed_frames), (2, 1));
+    assert_eq!(metrics.raw_bytes, small.len() + large.len());
+    assert!(metrics.sent_bytes < metrics.raw_bytes);
+}
+
+#[test]
+fn payloads_that_dont_shrink_are_sent_as_they_are() {
+    let (encoder, metrics) = encoder(0);
+
+    let incompressible = (0..=255u8).collect::<Vec<u8>>();
+    let frame = encoder.encode(incompressible.clone());
+    assert_eq!(frame[0], 0);
+    assert_eq!(&frame[1..], &incompressible[..]);
+    assert_eq!(metrics.snapshot().compressed_frames, 0);
+}
+
+/// a peer that keeps the broadcasts it gets
+struct Collector {
+    broadcasts: mpsc::UnboundedSender<PeerBroadcast>,
+}
+
+impl Actor for Collector {
+    type Context = Context<Self>;
+}
+
+impl Handler<PeerPlayerData> for Collector {
+    type Result = ();
+
+    fn handle(&mut self, _: PeerPlayerData, _: &mut Self::Context) {}
+}
+
+impl Handler<PeerBroadcast> for Collector {
+    type Result = ();
+
+    fn handle(&mut self, msg: PeerBroadcast, _: &mut Self::Context) {
+        let _ = self.broadcasts.send(msg);
+    }
+}
+
+fn collector(compress: bool) -> (PeerAddr, mpsc::UnboundedReceiver<PeerBroadcast>) {
+    let (broadcasts, received) = mpsc::unbounded_channel();
+    let addr = Collector { broadcasts }.start();
+    (PeerAddr { data: addr.clone().recipient(), broadcast: addr.recipient(), compress }, received)
+}
+
+#[actix_web::test]
+async fn peers_share_the_frames_of_a_broadcast() {
+    let config = ServerConfig::default();
+    let world = WorldSettings {
+        map: Arc::new(GameMap::default()),
+        movement: config.movement,
+        spawn_strategy: config.spawn_strategy,
+        teams: config.teams,
+        match_config: config.match_config,
+    };
+    let chat = ChatModerator::new(config.chat.clone(), Box::new(WordListFilter::new(&[], BlockedWordAction::default())));
+    let (encoder, metrics) = encoder(0);
+    let game_server = GameServer::new(Arc::new(AtomicUsize::new(0)), world, chat, config.replay, config.bots, config.lobby, config.party, seeded_rng(1), Box::new(SystemClock::new()))
+        .with_frame_encoder(encoder)
+        .start();
+
+    let (plain, mut plain_received) = collector(false);
+    let (compressed, mut compressed_received) = collector(true);
+    game_server.send(Connect { peer_addr: plain, role: PeerRole::Player, team: None, profile_id: None }).await.unwrap();
+    game_server.send(Connect { peer_addr: compressed, role: PeerRole::Spectator, team: None, profile_id: None }).await.unwrap();
+    let (joining, _) = collector(false);
+    game_server.send(Connect { peer_addr: joining, role: PeerRole::Player, team: None, profile_id: None }).await.unwrap();
+
+    let first = timeout(TIMEOUT, plain_received.recv()).await.unwrap().unwrap();
+    let second = timeout(TIMEOUT, compressed_received.recv()).await.unwrap().unwrap();
+    assert!(matches!(first.data, PeerPlayerData::RemotePeerJoined { .. }));
+    assert!(matches!(second.data, PeerPlayerData::RemotePeerJoined { .. }));
+    // encoded and framed once, both peers got the same bytes
+    assert_eq!(first.frames.raw.as_ptr(), second.frames.raw.as_ptr());
+    let frame = second.frames.compressed.unwrap();
+    assert_eq!(first.frames.compressed.unwrap().as_ptr(), frame.as_ptr());
+    assert_eq!(decode_frame(&frame).unwrap(), &first.frames.raw[..]);
+    // peers count the frames as they send them
+    assert_eq!(metrics.snapshot().frames, 0);
+}
+
+#[actix_web::test]
+async fn clients_opting_in_get_compressed_frames() {
+    let url = spawn_server(ServerConfig {
+        compression: CompressionConfig { threshold_bytes: 128, ..CompressionConfig::default() },
+        ..ServerConfig::default()
+    });
+
+    let (mut plain, _) = join(&url).await;
+    let (_, body) = http(&url, "GET", "/stats", None, None).await;
+    let stats: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(stats["compression"]["frames"], 0);
+
+    let mut compressed = GameClient::connect(&format!("{}?compress=true", url)).await.unwrap();
+    match compressed.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap() {
+        PeerPlayerData::WorldUpdate { world_data, .. } => assert_eq!(world_data.len(), 1),
+        _ => unreachable!(),
+    }
+    joined_id(&mut plain).await;
+
+    // long enough to pass the threshold and repetitive enough to shrink
+    let text = "ha".repeat(90);
+    plain.send_chat(ChatChannel::Room, 0, &text).await.unwrap();
+    match compressed.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap() {
+        PeerPlayerData::ChatMessage { text: received, .. } => assert_eq!(received, text),
+        _ => unreachable!(),
+    }
+
+    let (_, body) = http(&url, "GET", "/stats", None, None).await;
+    let stats: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let compression = &stats["compression"];
+    assert!(compression["compressedFrames"].as_u64().unwrap() >= 1, "{}", compression);
+    assert!(compression["sentBytes"].as_u64().unwrap() < compression["rawBytes"].as_u64().unwrap(), "{}", compression);
+}
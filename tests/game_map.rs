@@ -0,0 +1,79 @@
+use demo_game_server::game_server::game_map::GameMap;
+use demo_game_server::game_server::peer::ClientPosition;
+
+fn map(tiles: &[&str], obstacles: &str) -> GameMap {
+    let json = format!(
+        r#"{{ "id": "test", "tileSize": 64, "tiles": {}, "obstacles": [{}], "spawnPoints": [{{ "x": 32, "y": 32 }}] }}"#,
+        serde_json::to_string(tiles).unwrap(),
+        obstacles,
+    );
+    GameMap::from_json(&json).unwrap()
+}
+
+#[test]
+fn sample_map_loads_tiles_and_obstacles() {
+    let map = GameMap::load("maps/arena.json").unwrap();
+
+    assert_eq!(map.id, "arena");
+    assert_eq!(map.spawn_points.len(), 2);
+    // four pairs of adjacent tiles become one obstacle each, plus the explicit one
+    assert_eq!(map.obstacles.len(), 5);
+    assert!(map.is_blocked(ClientPosition { x: 512.0, y: 300.0 }));
+    assert!(!map.is_blocked(ClientPosition { x: 128.0, y: 300.0 }));
+}
+
+#[test]
+fn adjacent_tiles_merge_into_rectangles() {
+    let map = map(&["..##.#", "..##..", "#....."], "");
+    let rectangles = map.obstacles
+        .iter()
+        .map(|o| (o.x, o.y, o.width, o.height))
+        .collect::<Vec<(f32, f32, f32, f32)>>();
+
+    assert_eq!(rectangles, vec![(128.0, 0.0, 128.0, 128.0), (320.0, 0.0, 64.0, 64.0), (0.0, 128.0, 64.0, 64.0)]);
+}
+
+#[test]
+fn invalid_maps_are_rejected() {
+    let no_spawn = r#"{ "id": "test", "spawnPoints": [] }"#;
+    assert!(GameMap::from_json(no_spawn).is_err());
+
+    let blocked_spawn = r##"{ "id": "test", "tileSize": 64, "tiles": ["#"], "spawnPoints": [{ "x": 32, "y": 32 }] }"##;
+    assert!(GameMap::from_json(blocked_spawn).is_err());
+
+    let no_tile_size = r##"{ "id": "test", "tiles": ["#"], "spawnPoints": [{ "x": 300, "y": 300 }] }"##;
+    assert!(GameMap::from_json(no_tile_size).is_err());
+
+    assert!(GameMap::load("maps/missing.json").is_err());
+}
+
+#[test]
+fn player_between_two_adjacent_tiles_ends_up_outside_both() {
+    let map = GameMap::load("maps/arena.json").unwrap();
+    // the tiles at x 256..320 and 320..384, y 128..192
+    let position = ClientPosition { x: 330.0, y: 160.0 };
+    assert!(map.is_blocked(position));
+
+    let resolved = map.resolve_collisions(position).unwrap();
+    assert!(!map.is_blocked(resolved), "{:?} is still blocked", resolved);
+    assert!((resolved.x - 330.0).abs() < 1.0);
+}
+
+#[test]
+fn overlapping_obstacles_are_resolved_until_stable() {
+    // explicit obstacles are not merged, the way out of one leads into the other
+    let map = map(&[], r#"{ "x": 256, "y": 128, "width": 64, "height": 64 }, { "x": 320, "y": 128, "width": 64, "height": 64 }"#);
+    let position = ClientPosition { x: 330.0, y: 160.0 };
+
+    let resolved = map.resolve_collisions(position).unwrap();
+    assert!(!map.is_blocked(resolved), "{:?} is still blocked", resolved);
+}
+
+#[test]
+fn free_positions_are_left_alone() {
+    let map = GameMap::load("maps/arena.json").unwrap();
+    let position = ClientPosition { x: 128.0, y: 300.0 };
+
+    assert_eq!(map.resolve_collisions(position), Some(position));
+}
+
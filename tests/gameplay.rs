@@ -0,0 +1,185 @@
+mod common;
+
+use std::time::{Duration, Instant};
+use demo_game_server::config::{ServerConfig, SpectatorConfig};
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::match_state::MatchPhase;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::peer::{ClientControls, ClientPosition};
+use common::{join, joined_id, spawn_server, TIMEOUT};
+
+const NO_CONTROLS: ClientControls = ClientControls { up: false, down: false, left: false, right: false };
+
+#[actix_web::test]
+async fn first_player_gets_empty_world_of_default_map() {
+    let url = spawn_server(ServerConfig::default());
+
+    let mut client = GameClient::connect(&url).await.unwrap();
+    match client.recv().await.unwrap() {
+        Some(PeerPlayerData::WorldUpdate { map_id, game_mode, world_data }) => {
+            assert_eq!(map_id, "default");
+            assert_eq!(game_mode, "deathmatch");
+            assert!(world_data.is_empty());
+        }
+        other => panic!("expected a world update, got {:?}", other),
+    }
+    client.close().await.unwrap();
+}
+
+#[actix_web::test]
+async fn second_player_sees_first_and_is_announced() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (_second, others) = join(&url).await;
+    let second_id = joined_id(&mut first).await;
+
+    assert_eq!(others.len(), 1);
+    assert_ne!(others[0], second_id);
+}
+
+#[actix_web::test]
+async fn movement_is_broadcast_to_other_players() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (mut second, others) = join(&url).await;
+    joined_id(&mut first).await;
+
+    // spawn of the default map is 0,0, a small step is within the speed limit
+    let target = ClientPosition { x: 5.0, y: 0.0 };
+    first.send_player_moved(ClientControls { right: true, ..NO_CONTROLS }, target).await.unwrap();
+
+    match second.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerPositionUpdate { .. })).await.unwrap() {
+        PeerPlayerData::RemotePeerPositionUpdate { player_id, player_position, .. } => {
+            assert_eq!(player_id, others[0]);
+            assert_eq!(player_position, target);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[actix_web::test]
+async fn teleport_is_clamped_to_the_speed_limit() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (mut second, _) = join(&url).await;
+    joined_id(&mut first).await;
+
+    first.send_player_moved(ClientControls { right: true, ..NO_CONTROLS }, ClientPosition { x: 500.0, y: 0.0 }).await.unwrap();
+
+    match second.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerPositionUpdate { .. })).await.unwrap() {
+        PeerPlayerData::RemotePeerPositionUpdate { player_position, .. } => {
+            assert!(player_position.x > 0.0 && player_position.x < 500.0, "x = {}", player_position.x);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[actix_web::test]
+async fn leaving_player_is_announced() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (second, _) = join(&url).await;
+    let second_id = joined_id(&mut first).await;
+
+    second.close().await.unwrap();
+    let left = first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerLeft { .. })).await.unwrap();
+    assert!(matches!(left, PeerPlayerData::RemotePeerLeft { player_id } if player_id == second_id));
+}
+
+#[actix_web::test]
+async fn room_and_direct_chat_are_delivered() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (mut second, others) = join(&url).await;
+    let first_id = others[0];
+    let second_id = joined_id(&mut first).await;
+
+    first.send_chat(ChatChannel::Room, 0, "  hello  ").await.unwrap();
+    match second.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap() {
+        PeerPlayerData::ChatMessage { sender_id, channel, text } => {
+            assert_eq!(sender_id, first_id);
+            assert_eq!(channel, ChatChannel::Room);
+            assert_eq!(text, "hello");
+        }
+        _ => unreachable!(),
+    }
+
+    second.send_chat(ChatChannel::Direct, first_id, "psst").await.unwrap();
+    match first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { channel: ChatChannel::Direct, .. })).await.unwrap() {
+        PeerPlayerData::ChatMessage { sender_id, text, .. } => {
+            assert_eq!(sender_id, second_id);
+            assert_eq!(text, "psst");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[actix_web::test]
+async fn weapon_fired_is_accepted() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut client, _) = join(&url).await;
+    client.send_weapon_fired(0.5, 1.0).await.unwrap();
+    // the connection stays open, the server doesn't act on shots yet
+    client.send_chat(ChatChannel::Room, 0, "still here").await.unwrap();
+    let echo = client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await;
+    assert!(echo.is_ok(), "{:?}", echo);
+}
+
+#[actix_web::test]
+async fn spectator_watches_without_taking_a_slot() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let mut spectator = GameClient::connect(&format!("{}?spectate=true", url)).await.unwrap();
+    spectator.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap();
+
+    // room holds two players, the spectator must not count towards it
+    let (_second, _) = join(&url).await;
+    let second_id = joined_id(&mut first).await;
+    let joined = spectator.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await.unwrap();
+    assert!(matches!(joined, PeerPlayerData::RemotePeerJoined { player_id, .. } if player_id == second_id));
+}
+
+#[actix_web::test]
+async fn spectators_see_broadcasts_after_the_delay_and_players_right_away() {
+    let delay = Duration::from_millis(500);
+    let url = spawn_server(ServerConfig {
+        spectator: SpectatorConfig { broadcast_delay_ms: delay.as_millis() as u64 },
+        ..ServerConfig::default()
+    });
+
+    let (mut first, _) = join(&url).await;
+    let (mut second, _) = join(&url).await;
+    joined_id(&mut first).await;
+    let mut spectator = GameClient::connect(&format!("{}?spectate=true", url)).await.unwrap();
+    spectator.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap();
+
+    let sent = Instant::now();
+    first.send_chat(ChatChannel::Room, 0, "now").await.unwrap();
+    let is_chat = |data: &PeerPlayerData| matches!(data, PeerPlayerData::ChatMessage { .. });
+    let (player, watcher) = tokio::join!(
+        async { second.wait_for(TIMEOUT, is_chat).await.map(|_| sent.elapsed()) },
+        async { spectator.wait_for(TIMEOUT, is_chat).await.map(|_| sent.elapsed()) },
+    );
+    let (player, watcher) = (player.unwrap(), watcher.unwrap());
+    assert!(player < delay, "player waited {:?}", player);
+    assert!(watcher >= delay, "spectator waited only {:?}", watcher);
+}
+
+#[actix_web::test]
+async fn joining_player_learns_the_match_phase() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut client, _) = join(&url).await;
+    match client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::MatchPhaseChanged { .. })).await.unwrap() {
+        PeerPlayerData::MatchPhaseChanged { phase, .. } => assert_eq!(phase, MatchPhase::Warmup),
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use std::sync::Arc;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::leaderboard::{rank, LeaderboardCache, LeaderboardPeriod};
+use demo_game_server::game_server::match_state::MatchConfig;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::profile::{create_profile, MatchRecord, MatchResult, MemoryProfileStore, ProfileStore};
+use common::{get_until, http, spawn_server, TIMEOUT};
+
+// Wednesday 2024-01-03 12:00 UTC
+const NOW_MS: u64 = 1_704_283_200_000;
+// Monday 2024-01-01 00:00 UTC
+const WEEK_START_MS: u64 = 1_704_067_200_000;
+
+fn result(profile_id: &str, points: i32, won: bool) -> MatchResult {
+    MatchResult { profile_id: profile_id.to_string(), team: 0, kills: points.max(0) as u32, deaths: 0, points, won }
+}
+
+fn record(game_mode: &str, ended_at_ms: u64, results: Vec<MatchResult>) -> MatchRecord {
+    MatchRecord { game_mode: game_mode.to_string(), ended_at_ms, duration_ms: 60_000, results }
+}
+
+#[test]
+fn ranks_by_points_within_the_period_and_mode() {
+    let store = MemoryProfileStore::default();
+    let (alice, _) = create_profile(&store, "alice").unwrap();
+    let (bob, _) = create_profile(&store, "bob").unwrap();
+
+    // last week bob was far ahead, this week alice is
+    store.record_match(&record("deathmatch", WEEK_START_MS - 1, vec![result(&bob.id, 20, true)])).unwrap();
+    store.record_match(&record("deathmatch", WEEK_START_MS, vec![result(&alice.id, 5, true), result(&bob.id, 3, false)])).unwrap();
+    store.record_match(&record("captureTheFlag", NOW_MS, vec![result(&bob.id, 4, true)])).unwrap();
+
+    assert_eq!(LeaderboardPeriod::Weekly.start_ms(NOW_MS), WEEK_START_MS);
+
+    let all_time = rank(&store, LeaderboardPeriod::AllTime, None, NOW_MS).unwrap();
+    assert_eq!(all_time.iter().map(|entry| entry.name.as_str()).collect::<Vec<&str>>(), vec!["bob", "alice"]);
+    assert_eq!(all_time[0].stats.points, 27);
+    assert_eq!(all_time[0].stats.matches, 3);
+    assert_eq!(all_time[1].rank, 2);
+
+    let weekly_deathmatch = rank(&store, LeaderboardPeriod::Weekly, Some("deathmatch"), NOW_MS).unwrap();
+    assert_eq!(weekly_deathmatch.iter().map(|entry| (entry.name.as_str(), entry.stats.points)).collect::<Vec<_>>(), vec![("alice", 5), ("bob", 3)]);
+    assert_eq!(weekly_deathmatch[0].stats.wins, 1);
+}
+
+#[test]
+fn rankings_are_cached_until_the_next_match() {
+    let store = MemoryProfileStore::default();
+    let cache = LeaderboardCache::default();
+    let (alice, _) = create_profile(&store, "alice").unwrap();
+    store.record_match(&record("deathmatch", NOW_MS, vec![result(&alice.id, 5, true)])).unwrap();
+
+    let first = cache.rank(&store, LeaderboardPeriod::AllTime, None, NOW_MS).unwrap();
+    let second = cache.rank(&store, LeaderboardPeriod::AllTime, None, NOW_MS).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    let deathmatch = cache.rank(&store, LeaderboardPeriod::AllTime, Some("deathmatch"), NOW_MS).unwrap();
+    assert!(!Arc::ptr_eq(&first, &deathmatch));
+
+    store.record_match(&record("deathmatch", NOW_MS, vec![result(&alice.id, 2, false)])).unwrap();
+    let updated = cache.rank(&store, LeaderboardPeriod::AllTime, None, NOW_MS).unwrap();
+    assert!(!Arc::ptr_eq(&first, &updated));
+    assert_eq!(updated[0].stats.points, 7);
+}
+
+#[actix_web::test]
+async fn leaderboard_pages_and_finds_the_player() {
+    let url = spawn_server(ServerConfig {
+        match_config: MatchConfig { min_players: 1, warmup_secs: 0, time_limit_secs: 1, ..MatchConfig::default() },
+        ..ServerConfig::default()
+    });
+
+    let mut tokens = Vec::new();
+    let mut clients = Vec::new();
+    for name in ["alice", "bob"] {
+        let (_, body) = http(&url, "POST", "/profiles", None, Some(&format!(r#"{{"name":"{}"}}"#, name))).await;
+        let created: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let token = created["token"].as_str().unwrap().to_string();
+        clients.push(GameClient::connect(&format!("{}?token={}", url, token)).await.unwrap());
+        tokens.push(token);
+    }
+    for client in &mut clients {
+        client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::MatchSummary { .. })).await.unwrap();
+    }
+
+    // results are stored in the background once the match ended
+    let page = get_until(&url, "/leaderboard?period=weekly&limit=1", None, |page| page["total"] != 0).await;
+    assert_eq!(page["total"], 2);
+    assert_eq!(page["entries"].as_array().unwrap().len(), 1);
+    assert_eq!(page["entries"][0]["rank"], 1);
+
+    let (_, body) = http(&url, "GET", "/leaderboard?offset=1", None, None).await;
+    let page: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(page["entries"][0]["rank"], 2);
+
+    let (status, body) = http(&url, "GET", "/leaderboard/me?limit=1", Some(&tokens[1]), None).await;
+    assert_eq!(status, 200, "{}", body);
+    let around: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(around["entries"][0]["name"], "bob");
+
+    let (_, body) = http(&url, "GET", "/leaderboard?mode=captureTheFlag", None, None).await;
+    let page: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(page["total"], 0);
+    assert_eq!(http(&url, "GET", "/leaderboard/me?mode=captureTheFlag", Some(&tokens[0]), None).await.0, 404);
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use std::time::Duration;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::lobby::{Lobby, LobbyConfig, LobbyMember};
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use common::{http, spawn_server, TIMEOUT};
+
+fn lobby_config(countdown_secs: u64) -> ServerConfig {
+    ServerConfig {
+        lobby: LobbyConfig { enabled: true, countdown_secs, ..LobbyConfig::default() },
+        ..ServerConfig::default()
+    }
+}
+
+/// waits for a lobby update matching `predicate` and returns its members
+async fn lobby_members(client: &mut GameClient, predicate: impl Fn(&[LobbyMember]) -> bool) -> Vec<LobbyMember> {
+    match client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::LobbyUpdate { members, .. } if predicate(members))).await {
+        Ok(PeerPlayerData::LobbyUpdate { members, .. }) => members,
+        other => panic!("expected a lobby update, got {:?}", other),
+    }
+}
+
+#[actix_web::test]
+async fn match_starts_when_everyone_is_ready() {
+    let url = spawn_server(lobby_config(0));
+
+    let mut first = GameClient::connect(&url).await.unwrap();
+    let mut second = GameClient::connect(&url).await.unwrap();
+    lobby_members(&mut first, |members| members.len() == 2).await;
+
+    first.send_lobby_setup("  alice  ", "red", Some(1)).await.unwrap();
+    let members = lobby_members(&mut second, |members| members.iter().any(|member| member.name == "alice")).await;
+    let alice = members.iter().find(|member| member.name == "alice").unwrap();
+    assert_eq!(alice.skin, "red");
+    assert_eq!(alice.team, Some(1));
+
+    first.send_lobby_ready(true).await.unwrap();
+    lobby_members(&mut second, |members| members.iter().any(|member| member.ready)).await;
+    second.send_lobby_ready(true).await.unwrap();
+
+    for client in [&mut first, &mut second] {
+        match client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await {
+            Ok(PeerPlayerData::WorldUpdate { .. }) => {}
+            other => panic!("expected the match to start, got {:?}", other),
+        }
+    }
+}
+
+#[actix_web::test]
+async fn countdown_starts_the_match_without_everyone_ready() {
+    let url = spawn_server(lobby_config(1));
+
+    let mut client = GameClient::connect(&url).await.unwrap();
+    match client.recv().await.unwrap() {
+        Some(PeerPlayerData::LobbyUpdate { player_id, members, countdown }) => {
+            assert_eq!(members.len(), 1);
+            assert_eq!(members[0].player_id, player_id);
+            assert!(!members[0].ready);
+            assert!(countdown.is_some_and(|countdown| countdown > Duration::ZERO && countdown <= Duration::from_secs(1)));
+        }
+        other => panic!("expected a lobby update, got {:?}", other),
+    }
+
+    client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap();
+}
+
+#[actix_web::test]
+async fn connection_to_a_full_lobby_is_closed() {
+    let url = spawn_server(lobby_config(0));
+    // matchmaking would open another public room, a private one is joined whether it's full or not
+    let (_, body) = http(&url, "POST", "/rooms", None, None).await;
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let room_url = format!("{}?room={}", url, room["code"].as_str().unwrap());
+
+    // rooms hold two players by default
+    let mut first = GameClient::connect(&room_url).await.unwrap();
+    let _second = GameClient::connect(&room_url).await.unwrap();
+    lobby_members(&mut first, |members| members.len() == 2).await;
+
+    let mut third = GameClient::connect(&room_url).await.unwrap();
+    assert!(third.wait_for(TIMEOUT, |_| false).await.is_err());
+    assert_eq!(third.close_reason().map(|(_, reason)| reason.as_str()), Some("lobby_full"));
+
+    // the lobby is unchanged, the rejected player never showed up in it
+    first.send_lobby_ready(true).await.unwrap();
+    let members = lobby_members(&mut first, |members| members.iter().any(|member| member.ready)).await;
+    assert_eq!(members.len(), 2);
+}
+
+#[test]
+fn lobby_starts_once_the_minimum_is_ready() {
+    let mut lobby = Lobby::new(LobbyConfig { enabled: true, countdown_secs: 0, min_players: 2, ..LobbyConfig::default() });
+    let now = Duration::from_secs(1);
+    assert!(!lobby.should_start(now));
+
+    lobby.join(1, None, now);
+    lobby.set_ready(1, true);
+    assert!(!lobby.should_start(now), "one ready player is below the minimum");
+
+    lobby.join(2, None, now);
+    assert!(!lobby.should_start(now));
+    lobby.set_ready(2, true);
+    assert!(lobby.should_start(now));
+
+    // without a countdown there is no time left to report, and waiting alone never starts the match
+    assert_eq!(lobby.remaining(now), None);
+    lobby.set_ready(2, false);
+    assert!(!lobby.should_start(now + Duration::from_secs(3600)));
+
+    assert!(!lobby.set_ready(3, true));
+    assert_eq!(lobby.start().len(), 2);
+    assert!(!lobby.should_start(now));
+}
+
+#[test]
+fn lobby_countdown_runs_from_the_first_member() {
+    let mut lobby = Lobby::new(LobbyConfig { enabled: true, countdown_secs: 10, ..LobbyConfig::default() });
+    let start = Duration::from_secs(5);
+    assert_eq!(lobby.remaining(start), None);
+
+    lobby.join(1, None, start);
+    lobby.join(2, None, start + Duration::from_secs(4));
+    assert_eq!(lobby.remaining(start + Duration::from_secs(4)), Some(Duration::from_secs(6)));
+    assert!(!lobby.should_start(start + Duration::from_secs(9)));
+    assert!(lobby.should_start(start + Duration::from_secs(10)));
+    assert_eq!(lobby.remaining(start + Duration::from_secs(60)), Some(Duration::ZERO));
+
+    // an empty lobby stops the countdown, the next member starts a new one
+    lobby.leave(1);
+    lobby.leave(2);
+    assert_eq!(lobby.remaining(start + Duration::from_secs(60)), None);
+    assert!(!lobby.should_start(start + Duration::from_secs(60)));
+    lobby.join(3, None, start + Duration::from_secs(60));
+    assert_eq!(lobby.remaining(start + Duration::from_secs(61)), Some(Duration::from_secs(9)));
+}
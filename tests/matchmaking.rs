@@ -0,0 +1,69 @@
+mod common;
+
+use std::path::Path;
+use std::time::Duration;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::profile::{create_profile, FileProfileStore, ProfileConfig, ProfileStore};
+use demo_game_server::game_server::room_registry::MatchmakingConfig;
+use common::{http, join, joined_id, spawn_server};
+
+/// creates a profile with `rating` in the store at `directory` and returns its token
+fn rated_profile(directory: &Path, name: &str, rating: f64) -> String {
+    let store = FileProfileStore::open(directory).unwrap();
+    let (profile, token) = create_profile(&store, name).unwrap();
+    store.update(&profile.id, &mut |profile| profile.rating = Some(rating)).unwrap();
+    token
+}
+
+async fn join_as(url: &str, token: &str) -> (GameClient, Vec<usize>) {
+    join(&format!("{}?token={}", url, token)).await
+}
+
+#[actix_web::test]
+async fn players_join_the_room_closest_in_rating() {
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().subsec_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-matchmaking-{}-{}", std::process::id(), nanos));
+    let alice = rated_profile(&directory, "alice", 1500.0);
+    let bob = rated_profile(&directory, "bob", 2000.0);
+    let carol = rated_profile(&directory, "carol", 1550.0);
+    let dave = rated_profile(&directory, "dave", 1500.0);
+
+    let url = spawn_server(ServerConfig {
+        profiles: ProfileConfig { directory: Some(directory.clone()), ..ProfileConfig::default() },
+        matchmaking: MatchmakingConfig { rating_tolerance: 100.0, tolerance_growth_per_sec: 400.0, ..MatchmakingConfig::default() },
+        ..ServerConfig::default()
+    });
+
+    let (mut alice, others) = join_as(&url, &alice).await;
+    assert!(others.is_empty());
+    // 500 apart is too far for a room that just took a player
+    let (mut bob, others) = join_as(&url, &bob).await;
+    assert!(others.is_empty());
+
+    let (_carol, others) = join_as(&url, &carol).await;
+    assert_eq!(others.len(), 1);
+    joined_id(&mut alice).await;
+
+    // bob's room has been waiting long enough to take a player 500 below him
+    actix_web::rt::time::sleep(Duration::from_millis(1200)).await;
+    let (_dave, others) = join_as(&url, &dave).await;
+    assert_eq!(others.len(), 1);
+    joined_id(&mut bob).await;
+
+    let (_, body) = http(&url, "GET", "/stats", None, None).await;
+    let stats: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(stats["publicRooms"], 2);
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[actix_web::test]
+async fn joins_are_refused_once_every_public_room_is_full() {
+    let url = spawn_server(ServerConfig {
+        matchmaking: MatchmakingConfig { max_public_rooms: 1, max_players: 1, ..MatchmakingConfig::default() },
+        ..ServerConfig::default()
+    });
+
+    let (_first, _) = join(&url).await;
+    assert!(GameClient::connect(&url).await.is_err());
+}
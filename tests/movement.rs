@@ -0,0 +1,59 @@
+use std::time::Duration;
+use demo_game_server::game_server::movement_validator::{MovementConfig, MovementFlag, MovementValidator, WorldBounds};
+use demo_game_server::game_server::peer::{ClientControls, ClientPosition};
+
+const NO_CONTROLS: ClientControls = ClientControls { up: false, down: false, left: false, right: false };
+
+fn validator() -> MovementValidator {
+    let mut validator = MovementValidator::new(MovementConfig::default(), WorldBounds::default());
+    validator.track(1, ClientPosition { x: 100.0, y: 100.0 }, Duration::ZERO);
+    validator
+}
+
+#[test]
+fn non_finite_positions_keep_the_previous_position() {
+    let mut validator = validator();
+    let claims = [
+        ClientPosition { x: f32::NAN, y: 100.0 },
+        ClientPosition { x: 100.0, y: f32::NAN },
+        ClientPosition { x: f32::INFINITY, y: 100.0 },
+        ClientPosition { x: 100.0, y: f32::NEG_INFINITY },
+    ];
+
+    for (step, claimed) in claims.into_iter().enumerate() {
+        let now = Duration::from_millis(16 * (step as u64 + 1));
+        let check = validator.validate(1, claimed, &NO_CONTROLS, now).unwrap();
+        assert_eq!(check.position, ClientPosition { x: 100.0, y: 100.0 });
+        assert_eq!(check.flags, vec![MovementFlag::InvalidPosition]);
+    }
+
+    // the track is intact, a normal move afterwards is accepted as is
+    let check = validator.validate(1, ClientPosition { x: 105.0, y: 100.0 }, &NO_CONTROLS, Duration::from_millis(100)).unwrap();
+    assert_eq!(check.position, ClientPosition { x: 105.0, y: 100.0 });
+    assert!(check.flags.is_empty());
+}
+
+#[test]
+fn repeated_non_finite_positions_get_the_player_kicked() {
+    let mut validator = validator();
+    let claimed = ClientPosition { x: f32::NAN, y: f32::NAN };
+
+    let first = validator.validate(1, claimed, &NO_CONTROLS, Duration::ZERO).unwrap();
+    assert!(!first.kick);
+    let second = validator.validate(1, claimed, &NO_CONTROLS, Duration::ZERO).unwrap();
+    assert!(second.kick);
+    assert!(second.suspicion.is_finite());
+}
+
+#[test]
+fn out_of_bounds_and_fast_moves_are_corrected() {
+    let mut validator = validator();
+
+    let check = validator.validate(1, ClientPosition { x: 100.0, y: 5000.0 }, &NO_CONTROLS, Duration::from_secs(10)).unwrap();
+    assert_eq!(check.position, ClientPosition { x: 100.0, y: WorldBounds::default().max_y });
+    assert_eq!(check.flags, vec![MovementFlag::OutOfBounds]);
+
+    let check = validator.validate(1, ClientPosition { x: 500.0, y: WorldBounds::default().max_y }, &NO_CONTROLS, Duration::from_secs(10)).unwrap();
+    assert_eq!(check.flags, vec![MovementFlag::SpeedViolation]);
+    assert!(check.position.x < 500.0);
+}
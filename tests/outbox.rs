@@ -0,0 +1,158 @@
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::match_state::{MatchPhase, PlayerScore};
+use demo_game_server::game_server::outbox::{OutboundConfig, Outbox, Overflow, Priority};
+use demo_game_server::game_server::peer::ClientPosition;
+
+fn position(player_id: usize, x: f32) -> PeerPlayerData {
+    PeerPlayerData::RemotePeerPositionUpdate { player_id, team: 0, player_position: ClientPosition { x, y: 0.0 } }
+}
+
+fn chat(text: &str) -> PeerPlayerData {
+    PeerPlayerData::ChatMessage { sender_id: 1, channel: ChatChannel::Room, text: text.to_string() }
+}
+
+/// an outbox whose connection has `backlog_bytes` waiting, so nothing goes out
+fn backed_up_outbox() -> Outbox {
+    let mut outbox = Outbox::new(Arc::new(OutboundConfig { backlog_bytes: 100, ..OutboundConfig::default() }));
+    outbox.record_written(100);
+    assert!(outbox.is_backed_up());
+    outbox
+}
+
+fn drain(outbox: &mut Outbox) -> Vec<PeerPlayerData> {
+    outbox.drained_counter().fetch_add(100, Ordering::Relaxed);
+    std::iter::from_fn(|| outbox.pop()).collect()
+}
+
+#[test]
+fn backed_up_peer_keeps_reliable_messages_and_only_the_latest_positions() {
+    let mut outbox = backed_up_outbox();
+
+    outbox.push(position(1, 1.0));
+    outbox.push(chat("first"));
+    outbox.push(position(2, 5.0));
+    outbox.push(position(1, 2.0));
+    outbox.push(PeerPlayerData::ScoreboardUpdate { scores: Vec::new() });
+    outbox.push(chat("second"));
+    outbox.push(PeerPlayerData::RemotePeerJoined { player_id: 3, team: 0, player_position: ClientPosition { x: 0.0, y: 0.0 } });
+    assert_eq!(outbox.pop().map(|_| ()), None);
+    assert_eq!(outbox.len(), 6);
+    assert_eq!(outbox.superseded(), 1);
+
+    let sent = drain(&mut outbox);
+    assert_eq!(sent.iter().map(Priority::of).collect::<Vec<Priority>>(), vec![
+        Priority::Membership,
+        Priority::Event,
+        Priority::Event,
+        Priority::State,
+        Priority::Position,
+        Priority::Position,
+    ]);
+    assert!(matches!(&sent[1], PeerPlayerData::ChatMessage { text, .. } if text == "first"));
+    assert!(matches!(&sent[2], PeerPlayerData::ChatMessage { text, .. } if text == "second"));
+    assert!(matches!(sent[4], PeerPlayerData::RemotePeerPositionUpdate { player_id: 1, player_position, .. } if player_position.x == 2.0));
+    assert!(matches!(sent[5], PeerPlayerData::RemotePeerPositionUpdate { player_id: 2, .. }));
+    assert!(outbox.is_empty());
+}
+
+#[test]
+fn leaving_player_takes_their_queued_position_along() {
+    let mut outbox = backed_up_outbox();
+
+    outbox.push(position(1, 1.0));
+    outbox.push(position(2, 1.0));
+    outbox.push(PeerPlayerData::RemotePeerLeft { player_id: 1 });
+
+    let sent = drain(&mut outbox);
+    assert_eq!(sent.len(), 2);
+    assert!(matches!(sent[0], PeerPlayerData::RemotePeerLeft { player_id: 1 }));
+    assert!(matches!(sent[1], PeerPlayerData::RemotePeerPositionUpdate { player_id: 2, .. }));
+}
+
+#[test]
+fn messages_go_straight_out_while_the_connection_keeps_up() {
+    let mut outbox = Outbox::new(Arc::new(OutboundConfig::default()));
+
+    outbox.push(position(1, 1.0));
+    assert!(matches!(outbox.pop(), Some(PeerPlayerData::RemotePeerPositionUpdate { .. })));
+    outbox.push(position(1, 2.0));
+    assert!(matches!(outbox.pop(), Some(PeerPlayerData::RemotePeerPositionUpdate { .. })));
+    assert_eq!(outbox.superseded(), 0);
+}
+
+#[test]
+fn only_the_latest_state_waits() {
+    let mut outbox = backed_up_outbox();
+
+    outbox.push(PeerPlayerData::ScoreboardUpdate { scores: Vec::new() });
+    outbox.push(PeerPlayerData::LobbyUpdate { player_id: 1, members: Vec::new(), countdown: None });
+    outbox.push(PeerPlayerData::ScoreboardUpdate { scores: vec![PlayerScore { player_id: 1, team: 0, kills: 1, deaths: 0, points: 1 }] });
+    outbox.push(PeerPlayerData::LobbyUpdate { player_id: 2, members: Vec::new(), countdown: None });
+
+    let sent = drain(&mut outbox);
+    assert_eq!(sent.len(), 2);
+    assert!(matches!(sent[0], PeerPlayerData::LobbyUpdate { player_id: 2, .. }));
+    assert!(matches!(&sent[1], PeerPlayerData::ScoreboardUpdate { scores } if scores.len() == 1));
+    assert_eq!(outbox.superseded(), 2);
+}
+
+#[test]
+fn world_update_drops_the_positions_queued_before_it() {
+    let mut outbox = backed_up_outbox();
+
+    outbox.push(position(1, 1.0));
+    outbox.push(position(2, 1.0));
+    outbox.push(PeerPlayerData::WorldUpdate { map_id: "arena".to_string(), game_mode: "deathmatch".to_string(), world_data: Vec::new() });
+    outbox.push(position(2, 2.0));
+
+    let sent = drain(&mut outbox);
+    assert_eq!(sent.len(), 2);
+    assert!(matches!(sent[0], PeerPlayerData::WorldUpdate { .. }));
+    assert!(matches!(sent[1], PeerPlayerData::RemotePeerPositionUpdate { player_id: 2, player_position, .. } if player_position.x == 2.0));
+    assert_eq!(outbox.superseded(), 2);
+}
+
+#[test]
+fn lobby_and_match_phase_keep_their_order() {
+    let mut outbox = backed_up_outbox();
+
+    outbox.push(PeerPlayerData::LobbyUpdate { player_id: 1, members: Vec::new(), countdown: None });
+    outbox.push(PeerPlayerData::MatchPhaseChanged { phase: MatchPhase::Warmup, duration: None });
+    outbox.push(PeerPlayerData::LobbyUpdate { player_id: 2, members: Vec::new(), countdown: None });
+
+    let sent = drain(&mut outbox);
+    assert_eq!(sent.len(), 2);
+    assert!(matches!(sent[0], PeerPlayerData::MatchPhaseChanged { .. }));
+    assert!(matches!(sent[1], PeerPlayerData::LobbyUpdate { player_id: 2, .. }));
+}
+
+#[test]
+fn peer_that_cant_keep_up_overflows() {
+    let start = Instant::now();
+    let config = Arc::new(OutboundConfig { backlog_bytes: 100, max_queued_messages: 2, max_lag_ms: 1000, ..OutboundConfig::default() });
+    let mut outbox = Outbox::new(config.clone());
+
+    // backed up, but not for long enough
+    outbox.record_written(100);
+    assert_eq!(outbox.check(start), Ok(()));
+    assert_eq!(outbox.check(start + Duration::from_millis(999)), Ok(()));
+    // catching up resets the clock
+    outbox.drained_counter().fetch_add(100, Ordering::Relaxed);
+    assert_eq!(outbox.check(start + Duration::from_millis(1000)), Ok(()));
+    outbox.record_written(100);
+    assert_eq!(outbox.check(start + Duration::from_millis(1500)), Ok(()));
+    assert_eq!(outbox.check(start + Duration::from_millis(2500)), Err(Overflow::Lag));
+
+    let mut outbox = Outbox::new(config);
+    outbox.record_written(100);
+    for text in ["one", "two"] {
+        outbox.push(chat(text));
+    }
+    assert_eq!(outbox.check(start), Ok(()));
+    outbox.push(chat("three"));
+    assert_eq!(outbox.check(start), Err(Overflow::Queue));
+}
@@ -0,0 +1,185 @@
+mod common;
+
+use std::path::Path;
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::lobby::LobbyConfig;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::profile::{create_profile, FileProfileStore, ProfileConfig, ProfileStore};
+use demo_game_server::game_server::room_registry::MatchmakingConfig;
+use common::{http, join, joined_id, spawn_server, TIMEOUT};
+
+/// waits for the next party update and returns its members
+async fn party_members(client: &mut GameClient) -> Vec<usize> {
+    match client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::PartyUpdate { .. })).await {
+        Ok(PeerPlayerData::PartyUpdate { members, .. }) => members,
+        other => panic!("expected a party update, got {:?}", other),
+    }
+}
+
+/// invites `invited` into `leader`'s party and accepts
+async fn form_party(leader: &mut GameClient, leader_id: usize, invited: &mut GameClient, invited_id: usize) {
+    leader.send_party_invite(invited_id).await.unwrap();
+    match invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::PartyInvitation { .. })).await {
+        Ok(PeerPlayerData::PartyInvitation { leader_id: invited_by }) => assert_eq!(invited_by, leader_id),
+        other => panic!("expected a party invitation, got {:?}", other),
+    }
+    invited.send_party_respond(leader_id, true).await.unwrap();
+
+    assert_eq!(party_members(leader).await, vec![leader_id, invited_id]);
+    assert_eq!(party_members(invited).await, vec![leader_id, invited_id]);
+}
+
+#[actix_web::test]
+async fn joining_a_party_mid_match_moves_the_player_to_the_leaders_team() {
+    let url = spawn_server(ServerConfig::default());
+    let (mut leader, _) = join(&url).await;
+    let mut invited = GameClient::connect(&url).await.unwrap();
+    let PeerPlayerData::WorldUpdate { world_data, .. } = invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap() else {
+        unreachable!()
+    };
+    let (leader_id, leader_team) = (world_data[0].player_id, world_data[0].team);
+    let invited_id = joined_id(&mut leader).await;
+
+    form_party(&mut leader, leader_id, &mut invited, invited_id).await;
+    match invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerPositionUpdate { player_id, .. } if *player_id == invited_id)).await {
+        Ok(PeerPlayerData::RemotePeerPositionUpdate { team, .. }) => assert_eq!(team, leader_team),
+        other => panic!("expected a team change, got {:?}", other),
+    }
+
+    // a party of two is disbanded once one of them leaves
+    leader.send_party_leave().await.unwrap();
+    assert!(party_members(&mut leader).await.is_empty());
+    assert!(party_members(&mut invited).await.is_empty());
+}
+
+#[actix_web::test]
+async fn party_enters_the_match_from_the_lobby_on_one_team() {
+    let url = spawn_server(ServerConfig {
+        lobby: LobbyConfig { enabled: true, countdown_secs: 0, ..LobbyConfig::default() },
+        ..ServerConfig::default()
+    });
+
+    let mut leader = GameClient::connect(&url).await.unwrap();
+    let mut invited = GameClient::connect(&url).await.unwrap();
+    let (invited_id, members) = match invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::LobbyUpdate { members, .. } if members.len() == 2)).await {
+        Ok(PeerPlayerData::LobbyUpdate { player_id, members, .. }) => (player_id, members),
+        other => panic!("expected a lobby update, got {:?}", other),
+    };
+    let leader_id = members.iter().map(|member| member.player_id).find(|&id| id != invited_id).unwrap();
+
+    form_party(&mut leader, leader_id, &mut invited, invited_id).await;
+    leader.send_lobby_ready(true).await.unwrap();
+    invited.send_lobby_ready(true).await.unwrap();
+
+    // without the party the two would be balanced onto different teams
+    let leader_team = match invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await {
+        Ok(PeerPlayerData::WorldUpdate { world_data, .. }) => world_data.iter().find(|info| info.player_id == leader_id).unwrap().team,
+        other => panic!("expected the match to start, got {:?}", other),
+    };
+    match leader.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerJoined { player_id, .. } if *player_id == invited_id)).await {
+        Ok(PeerPlayerData::RemotePeerJoined { team, .. }) => assert_eq!(team, leader_team),
+        other => panic!("expected the invited player to join, got {:?}", other),
+    }
+}
+
+/// creates a profile with `rating` in the store at `directory` and returns its token
+fn rated_profile(directory: &Path, name: &str, rating: f64) -> String {
+    let store = FileProfileStore::open(directory).unwrap();
+    let (profile, token) = create_profile(&store, name).unwrap();
+    store.update(&profile.id, &mut |profile| profile.rating = Some(rating)).unwrap();
+    token
+}
+
+/// Connects the players with `tokens` to a private room of their own, the first one invites the others into a party,
+/// and everyone disconnects again. The party outlives the room.
+async fn party_from_private_room(url: &str, tokens: &[&str]) {
+    let (_, body) = http(url, "POST", "/rooms", None, None).await;
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let (code, owner_token) = (room["code"].as_str().unwrap(), room["ownerToken"].as_str().unwrap());
+    let patch = format!(r#"{{"maxPlayers":{}}}"#, tokens.len());
+    assert_eq!(http(url, "PATCH", &format!("/rooms/{}", code), Some(owner_token), Some(&patch)).await.0, 204);
+
+    let (mut leader, _) = join(&format!("{}?room={}&token={}", url, code, tokens[0])).await;
+    let mut leader_id = None;
+    // stay connected until the party is complete
+    let mut members = Vec::new();
+    for token in &tokens[1..] {
+        let (mut invited, others) = join(&format!("{}?room={}&token={}", url, code, token)).await;
+        let leader_id = *leader_id.get_or_insert(others[0]);
+        let invited_id = joined_id(&mut leader).await;
+        leader.send_party_invite(invited_id).await.unwrap();
+        invited.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::PartyInvitation { .. })).await.unwrap();
+        invited.send_party_respond(leader_id, true).await.unwrap();
+        let size = members.len() + 2;
+        leader.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::PartyUpdate { members, .. } if members.len() == size)).await.unwrap();
+        members.push(invited);
+    }
+}
+
+fn temp_directory(name: &str) -> std::path::PathBuf {
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().subsec_nanos();
+    std::env::temp_dir().join(format!("{}-{}-{}", name, std::process::id(), nanos))
+}
+
+#[actix_web::test]
+async fn party_members_are_matched_into_their_leaders_room_and_team() {
+    let directory = temp_directory("profiles-party");
+    let alice = rated_profile(&directory, "alice", 1500.0);
+    let bob = rated_profile(&directory, "bob", 2500.0);
+    let dave = rated_profile(&directory, "dave", 2500.0);
+    let carol = rated_profile(&directory, "carol", 2500.0);
+    let url = spawn_server(ServerConfig {
+        profiles: ProfileConfig { directory: Some(directory.clone()), ..ProfileConfig::default() },
+        matchmaking: MatchmakingConfig { max_players: 4, ..MatchmakingConfig::default() },
+        ..ServerConfig::default()
+    });
+    party_from_private_room(&url, &[&alice, &bob, &dave]).await;
+
+    // on their own bob and dave would be matched with carol, who is rated like them
+    let (_carol, others) = join(&format!("{}?token={}", url, carol)).await;
+    assert!(others.is_empty());
+    let (mut alice, others) = join(&format!("{}?token={}", url, alice)).await;
+    assert!(others.is_empty());
+
+    let mut ids = Vec::new();
+    let mut members = Vec::new();
+    for token in [&bob, &dave] {
+        let mut client = GameClient::connect(&format!("{}?token={}", url, token)).await.unwrap();
+        let PeerPlayerData::WorldUpdate { world_data, .. } = client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap() else {
+            unreachable!()
+        };
+        let joined = match alice.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await {
+            Ok(PeerPlayerData::RemotePeerJoined { player_id, team, .. }) => (player_id, team),
+            other => panic!("expected a join, got {:?}", other),
+        };
+        // everyone already in the room is alice or a party mate, all of them on one team
+        let mut present = world_data.iter().map(|info| info.player_id).collect::<Vec<usize>>();
+        if ids.is_empty() {
+            ids.extend(present.first());
+        }
+        present.sort_unstable();
+        let mut expected = ids.clone();
+        expected.sort_unstable();
+        assert_eq!(present, expected);
+        assert!(world_data.iter().all(|info| info.team == joined.1));
+        ids.push(joined.0);
+        members.push(client);
+    }
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[actix_web::test]
+async fn party_larger_than_a_public_room_is_refused() {
+    let directory = temp_directory("profiles-party-large");
+    let tokens = ["alice", "bob", "dave"].map(|name| rated_profile(&directory, name, 1500.0));
+    // public rooms hold two players by default
+    let url = spawn_server(ServerConfig {
+        profiles: ProfileConfig { directory: Some(directory.clone()), ..ProfileConfig::default() },
+        ..ServerConfig::default()
+    });
+    party_from_private_room(&url, &tokens.each_ref().map(String::as_str)).await;
+
+    assert!(GameClient::connect(&format!("{}?token={}", url, tokens[0])).await.is_err());
+    std::fs::remove_dir_all(directory).unwrap();
+}
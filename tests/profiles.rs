@@ -0,0 +1,224 @@
+mod common;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::match_state::MatchConfig;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::profile::{create_profile, FileProfileStore, MatchRecord, ProfileStore, SqliteProfileStore};
+use rusqlite::Connection;
+use common::{get_until, http, join, spawn_server, TIMEOUT};
+
+/// registers a profile and returns its id and token
+async fn register(url: &str, name: &str) -> (String, String) {
+    let (status, body) = http(url, "POST", "/profiles", None, Some(&format!(r#"{{"name":"{}"}}"#, name))).await;
+    assert_eq!(status, 201, "{}", body);
+    let created: serde_json::Value = serde_json::from_str(&body).unwrap();
+    (created["profile"]["id"].as_str().unwrap().to_string(), created["token"].as_str().unwrap().to_string())
+}
+
+#[actix_web::test]
+async fn profile_settings_are_kept_and_need_the_token() {
+    let url = spawn_server(ServerConfig::default());
+    let (profile_id, token) = register(&url, "  alice  ").await;
+
+    assert_eq!(http(&url, "GET", "/profiles/me", None, None).await.0, 401);
+    assert_eq!(http(&url, "GET", "/profiles/me", Some("wrong"), None).await.0, 401);
+    assert_eq!(http(&url, "POST", "/profiles", None, Some(r#"{"name":"   "}"#)).await.0, 400);
+
+    let (status, body) = http(&url, "PATCH", "/profiles/me", Some(&token), Some(r#"{"skin":"red","team":1}"#)).await;
+    assert_eq!(status, 200, "{}", body);
+
+    let (status, body) = http(&url, "GET", &format!("/profiles/{}", profile_id), None, None).await;
+    assert_eq!(status, 200);
+    let profile: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(profile["name"], "alice");
+    assert_eq!(profile["settings"]["skin"], "red");
+    assert_eq!(profile["settings"]["team"], 1);
+    assert!(profile.get("token").is_none());
+}
+
+#[actix_web::test]
+async fn finished_matches_count_towards_lifetime_stats() {
+    let url = spawn_server(ServerConfig {
+        match_config: MatchConfig { min_players: 1, warmup_secs: 0, time_limit_secs: 1, ..MatchConfig::default() },
+        ..ServerConfig::default()
+    });
+    let (profile_id, token) = register(&url, "alice").await;
+
+    assert!(GameClient::connect(&format!("{}?token=wrong", url)).await.is_err());
+
+    let mut client = GameClient::connect(&format!("{}?token={}", url, token)).await.unwrap();
+    client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::MatchSummary { .. })).await.unwrap();
+
+    let profile = get_until(&url, &format!("/profiles/{}", profile_id), None, |profile| profile["stats"]["matches"] != 0).await;
+    assert_eq!(profile["stats"]["matches"], 1);
+}
+
+#[test]
+fn file_store_keeps_profiles_across_restarts() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-{}-{}", std::process::id(), nanos));
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    let (profile, token) = create_profile(&store, "alice").unwrap();
+    store.update(&profile.id, &mut |profile| profile.stats.kills += 3).unwrap();
+    drop(store);
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    let loaded = store.authenticate(&token).unwrap().expect("profile survives a restart");
+    assert_eq!(loaded.id, profile.id);
+    assert_eq!(loaded.stats.kills, 3);
+    assert!(store.authenticate("wrong").unwrap().is_none());
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn file_store_keeps_only_a_hash_of_the_token() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-hashed-{}-{}", std::process::id(), nanos));
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    let (profile, token) = create_profile(&store, "alice").unwrap();
+    drop(store);
+    let path = directory.join(format!("{}.json", profile.id));
+    let mut stored: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    assert!(!stored.to_string().contains(&token));
+
+    // a profile written before tokens were hashed
+    let record = stored.as_object_mut().unwrap();
+    record.remove("token_hash");
+    record.insert("token".to_string(), serde_json::Value::String("legacy".to_string()));
+    std::fs::write(&path, stored.to_string()).unwrap();
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    assert_eq!(store.authenticate("legacy").unwrap().unwrap().id, profile.id);
+    assert!(store.authenticate(&token).unwrap().is_none());
+    assert!(!std::fs::read_to_string(&path).unwrap().contains("legacy"));
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn failed_write_leaves_the_profile_unchanged() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-failed-{}-{}", std::process::id(), nanos));
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    let (profile, _) = create_profile(&store, "alice").unwrap();
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    assert!(store.update(&profile.id, &mut |profile| profile.stats.kills += 3).is_err());
+    assert_eq!(store.load(&profile.id).unwrap().unwrap().stats.kills, 0);
+}
+
+#[actix_web::test]
+async fn profile_plays_only_once_per_room() {
+    let url = spawn_server(ServerConfig::default());
+    let (_, token) = register(&url, "alice").await;
+
+    let (mut first, _) = join(&format!("{}?token={}", url, token)).await;
+    let mut second = GameClient::connect(&format!("{}?token={}", url, token)).await.unwrap();
+    assert!(second.wait_for(TIMEOUT, |_| false).await.is_err());
+    assert_eq!(second.close_reason().map(|(_, reason)| reason.as_str()), Some("already_connected"));
+
+    // the first session is unaffected
+    first.send_chat(ChatChannel::Room, 0, "still here").await.unwrap();
+    first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap();
+}
+
+#[test]
+fn sqlite_store_keeps_profiles_and_matches_across_restarts() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-sqlite-{}-{}", std::process::id(), nanos));
+    let database = directory.join("profiles.db");
+
+    let store = SqliteProfileStore::open(&database).unwrap();
+    let (profile, token) = create_profile(&store, "alice").unwrap();
+    store.update(&profile.id, &mut |profile| profile.stats.kills += 3).unwrap();
+    let record = MatchRecord { game_mode: "deathmatch".to_string(), ended_at_ms: 10, duration_ms: 1000, results: Vec::new() };
+    store.record_match(&record).unwrap();
+    assert!(store.update("missing", &mut |profile| profile.stats.kills += 1).unwrap().is_none());
+    drop(store);
+
+    let store = SqliteProfileStore::open(&database).unwrap();
+    let loaded = store.authenticate(&token).unwrap().expect("profile survives a restart");
+    assert_eq!(loaded.id, profile.id);
+    assert_eq!(loaded.stats.kills, 3);
+    assert!(store.authenticate("wrong").unwrap().is_none());
+    assert_eq!(store.matches_since(0).unwrap(), vec![record]);
+    assert!(store.matches_since(11).unwrap().is_empty());
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn sqlite_store_hashes_tokens_of_older_databases() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-sqlite-legacy-{}-{}", std::process::id(), nanos));
+    let database = directory.join("profiles.db");
+
+    let store = SqliteProfileStore::open(&database).unwrap();
+    let (profile, token) = create_profile(&store, "alice").unwrap();
+    drop(store);
+    let connection = Connection::open(&database).unwrap();
+    let stored: String = connection.query_row("SELECT token_hash FROM profiles", [], |row| row.get(0)).unwrap();
+    assert_ne!(stored, token);
+
+    // the schema before tokens were hashed
+    connection
+        .execute_batch("ALTER TABLE profiles RENAME COLUMN token_hash TO token; UPDATE profiles SET token = 'legacy';")
+        .unwrap();
+    drop(connection);
+
+    let store = SqliteProfileStore::open(&database).unwrap();
+    assert_eq!(store.authenticate("legacy").unwrap().unwrap().id, profile.id);
+    drop(store);
+    let connection = Connection::open(&database).unwrap();
+    let stored: String = connection.query_row("SELECT token_hash FROM profiles", [], |row| row.get(0)).unwrap();
+    assert_ne!(stored, "legacy");
+    drop(connection);
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn file_store_drops_a_torn_last_match_record() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let directory = std::env::temp_dir().join(format!("profiles-torn-{}-{}", std::process::id(), nanos));
+    let record = |ended_at_ms| MatchRecord { game_mode: "deathmatch".to_string(), ended_at_ms, duration_ms: 1000, results: Vec::new() };
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    store.record_match(&record(1)).unwrap();
+    drop(store);
+    // the server died halfway through appending the second record
+    let matches = directory.join("matches.jsonl");
+    let mut contents = std::fs::read_to_string(&matches).unwrap();
+    contents.push_str(r#"{"gameMode":"deathm"#);
+    std::fs::write(&matches, contents).unwrap();
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    assert_eq!(store.matches_since(0).unwrap(), vec![record(1)]);
+    store.record_match(&record(2)).unwrap();
+    drop(store);
+
+    // the newline after the last complete record went missing too
+    let contents = std::fs::read_to_string(&matches).unwrap();
+    std::fs::write(&matches, contents.trim_end()).unwrap();
+    let store = FileProfileStore::open(&directory).unwrap();
+    store.record_match(&record(3)).unwrap();
+    drop(store);
+
+    let store = FileProfileStore::open(&directory).unwrap();
+    assert_eq!(store.matches_since(0).unwrap(), vec![record(1), record(2), record(3)]);
+    drop(store);
+
+    // a broken record followed by good ones is not a torn write
+    let contents = std::fs::read_to_string(&matches).unwrap();
+    std::fs::write(&matches, format!("{{broken\n{}", contents)).unwrap();
+    assert_eq!(FileProfileStore::open(&directory).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use demo_game_server::game_server::rate_limiter::{
+    BucketConfig, InboundRateLimiter, MessageKind, RateLimitAction, RateLimitConfig, RateLimitMetrics, Verdict,
+};
+
+fn limiter(bucket: BucketConfig) -> (InboundRateLimiter, Arc<RateLimitMetrics>) {
+    let config = RateLimitConfig { chat: bucket, ..RateLimitConfig::default() };
+    let metrics = Arc::new(RateLimitMetrics::default());
+    (InboundRateLimiter::new(Arc::new(config), metrics.clone()), metrics)
+}
+
+fn bucket(action: RateLimitAction) -> BucketConfig {
+    BucketConfig { capacity: 2.0, refill_per_second: 4.0, action }
+}
+
+#[test]
+fn bucket_allows_a_burst_then_refills_over_time() {
+    let (mut limiter, _) = limiter(bucket(RateLimitAction::Drop));
+    let start = Instant::now() + Duration::from_secs(1);
+
+    assert_eq!(limiter.check(MessageKind::Chat, start), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, start), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, start), Verdict::Drop);
+
+    // 4 tokens a second, one is back after 250ms
+    let later = start + Duration::from_millis(250);
+    assert_eq!(limiter.check(MessageKind::Chat, later), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, later), Verdict::Drop);
+
+    // refilling never goes past the capacity
+    let much_later = later + Duration::from_secs(60);
+    assert_eq!(limiter.check(MessageKind::Chat, much_later), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, much_later), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, much_later), Verdict::Drop);
+
+    // the other kinds have buckets of their own
+    assert_eq!(limiter.check(MessageKind::Other, much_later), Verdict::Allow);
+}
+
+#[test]
+fn tripped_limit_yields_the_configured_verdict() {
+    let start = Instant::now() + Duration::from_secs(1);
+    let exhausted = |action| {
+        let (mut limiter, metrics) = limiter(bucket(action));
+        limiter.check(MessageKind::Chat, start);
+        limiter.check(MessageKind::Chat, start);
+        (limiter.check(MessageKind::Chat, start), metrics.snapshot())
+    };
+
+    let (verdict, metrics) = exhausted(RateLimitAction::Throttle);
+    assert_eq!(verdict, Verdict::Throttle(Duration::from_millis(250)));
+    assert_eq!((metrics.throttled, metrics.dropped, metrics.disconnected), (1, 0, 0));
+
+    let (verdict, metrics) = exhausted(RateLimitAction::Drop);
+    assert_eq!(verdict, Verdict::Drop);
+    assert_eq!((metrics.throttled, metrics.dropped, metrics.disconnected), (0, 1, 0));
+
+    let (verdict, metrics) = exhausted(RateLimitAction::Disconnect);
+    assert_eq!(verdict, Verdict::Disconnect);
+    assert_eq!((metrics.throttled, metrics.dropped, metrics.disconnected), (0, 0, 1));
+}
+
+#[test]
+fn throttle_without_refill_drops_instead() {
+    let (mut limiter, _) = limiter(BucketConfig { capacity: 1.0, refill_per_second: 0.0, action: RateLimitAction::Throttle });
+    let now = Instant::now();
+
+    assert_eq!(limiter.check(MessageKind::Chat, now), Verdict::Allow);
+    assert_eq!(limiter.check(MessageKind::Chat, now), Verdict::Drop);
+}
+
+#[test]
+fn oversized_frames_are_dropped_or_disconnect() {
+    let metrics = Arc::new(RateLimitMetrics::default());
+    let config = RateLimitConfig { max_frame_size: 8, oversized_frame_action: RateLimitAction::Throttle, ..RateLimitConfig::default() };
+    let limiter = InboundRateLimiter::new(Arc::new(config), metrics.clone());
+    assert_eq!(limiter.check_frame_size(8), Verdict::Allow);
+    assert_eq!(limiter.check_frame_size(9), Verdict::Drop);
+
+    let limiter = InboundRateLimiter::new(Arc::new(RateLimitConfig { max_frame_size: 8, ..RateLimitConfig::default() }), metrics.clone());
+    assert_eq!(limiter.check_frame_size(9), Verdict::Disconnect);
+    assert_eq!(metrics.snapshot().oversized_frames, 2);
+}
+
+#[test]
+fn bucket_keys_left_out_of_the_config_use_defaults() {
+    let config: RateLimitConfig = serde_json::from_str(r#"{ "chat": { "capacity": 3 } }"#).unwrap();
+
+    assert_eq!(config.chat.capacity, 3.0);
+    assert_eq!(config.chat.refill_per_second, BucketConfig::default().refill_per_second);
+    assert_eq!(config.chat.action, BucketConfig::default().action);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::match_state::MatchConfig;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::profile::MatchResult;
+use demo_game_server::game_server::rating::RatingConfig;
+use common::{get_until, http, spawn_server, TIMEOUT};
+
+fn result(points: i32, won: bool) -> MatchResult {
+    MatchResult { profile_id: String::new(), team: 0, kills: 0, deaths: 0, points, won }
+}
+
+#[test]
+fn winners_take_rating_from_losers() {
+    let ratings = RatingConfig::default();
+    let (winner, runner_up, loser) = (result(5, true), result(3, false), result(1, false));
+
+    let changes = ratings.changes(&[(1500.0, &winner), (1500.0, &runner_up), (1500.0, &loser)]);
+    assert!(changes[0] > 0.0 && changes[1] == 0.0 && changes[2] < 0.0, "{:?}", changes);
+    assert!(changes.iter().sum::<f64>().abs() < 1e-9);
+    assert!(changes[0] <= ratings.k_factor);
+
+    // beating a much stronger player is worth more than beating an equal one
+    let upset = ratings.changes(&[(1300.0, &winner), (1700.0, &loser)]);
+    let even = ratings.changes(&[(1500.0, &winner), (1500.0, &loser)]);
+    assert!(upset[0] > even[0]);
+
+    assert!(ratings.changes(&[(1500.0, &winner)]).is_empty());
+}
+
+#[actix_web::test]
+async fn matches_update_profile_ratings_and_room_average() {
+    let url = spawn_server(ServerConfig {
+        match_config: MatchConfig { min_players: 1, warmup_secs: 0, time_limit_secs: 1, ..MatchConfig::default() },
+        ..ServerConfig::default()
+    });
+    let (_, body) = http(&url, "POST", "/rooms", None, None).await;
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let code = room["code"].as_str().unwrap().to_string();
+
+    let mut tokens = Vec::new();
+    let mut clients = Vec::new();
+    for name in ["alice", "bob"] {
+        let (_, body) = http(&url, "POST", "/profiles", None, Some(&format!(r#"{{"name":"{}"}}"#, name))).await;
+        let created: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let token = created["token"].as_str().unwrap().to_string();
+        assert!(created["profile"]["rating"].is_null());
+        clients.push(GameClient::connect(&format!("{}?room={}&token={}", url, code, token)).await.unwrap());
+        tokens.push(token);
+    }
+    for client in &mut clients {
+        client.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::MatchSummary { .. })).await.unwrap();
+    }
+
+    let mut total = 0.0;
+    for token in &tokens {
+        let profile = get_until(&url, "/profiles/me", Some(token), |profile| profile["rating"].is_number()).await;
+        let history = profile["ratingHistory"].as_array().unwrap();
+        assert!(!history.is_empty());
+        assert_eq!(history[0]["before"], 1500.0);
+        assert_eq!(history[history.len() - 1]["after"], profile["rating"]);
+        assert_eq!(history[0]["gameMode"], "deathmatch");
+        total += profile["rating"].as_f64().unwrap();
+    }
+
+    let (_, body) = http(&url, "GET", &format!("/rooms/{}", code), None, None).await;
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    // both players started at the initial rating and Elo moves rating between them, it never creates any
+    assert!((room["averageRating"].as_f64().unwrap() - 1500.0).abs() < 1e-6);
+    assert!((total - 3000.0).abs() < 1e-6);
+}
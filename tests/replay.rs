@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::flatbuffers_utils::create_player_data_bytes;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::peer::{ClientControls, ClientData, ClientPosition};
+use demo_game_server::game_server::replay::{Replay, ReplayEvent, ReplayRecorder};
+
+fn temp_directory(name: &str) -> PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("{}-{}-{}", name, std::process::id(), nanos))
+}
+
+#[test]
+fn recorded_match_reads_back_the_same() {
+    let directory = temp_directory("replay");
+    let directory_name = directory.to_str().unwrap();
+    let start = Duration::from_secs(3);
+    let chat = PeerPlayerData::ChatMessage { sender_id: 7, channel: ChatChannel::Room, text: "gg".to_string() };
+
+    let mut recorder = ReplayRecorder::create(directory_name, 5, 42, "arena", start).unwrap();
+    let file_name = recorder.path().file_name().unwrap().to_str().unwrap().to_string();
+    let controls = ClientControls { up: true, down: false, left: false, right: true };
+    let moved = ClientData::PlayerMoved { player_controls: controls, player_position: ClientPosition { x: 1.5, y: -2.0 } };
+    recorder.record_input(start + Duration::from_millis(10), 7, &moved).unwrap();
+    recorder.record_input(start + Duration::from_millis(20), 7, &ClientData::WeaponFired { angle: 0.5, power: 1.0 }).unwrap();
+    let text = ClientData::ChatSend { channel: ChatChannel::Team, target_player_id: 0, text: "gg".to_string() };
+    recorder.record_input(start + Duration::from_millis(30), 7, &text).unwrap();
+    recorder.record_output(start + Duration::from_millis(40), None, &chat).unwrap();
+    recorder.record_output(start + Duration::from_millis(50), Some(7), &chat).unwrap();
+    recorder.finish().unwrap();
+
+    let replay = Replay::open(directory_name, &file_name, 1024 * 1024).unwrap();
+    assert_eq!((replay.room_id, replay.seed, replay.map_id.as_str()), (5, Some(42), "arena"));
+
+    let offsets = replay.events.iter().map(|(offset, _)| offset.as_millis()).collect::<Vec<u128>>();
+    assert_eq!(offsets, vec![10, 20, 30, 40, 50]);
+    let chat_bytes = create_player_data_bytes(&chat).unwrap();
+    match &replay.events[..] {
+        [
+            (_, ReplayEvent::Input { player_id: 7, data: ClientData::PlayerMoved { player_controls, player_position } }),
+            (_, ReplayEvent::Input { player_id: 7, data: ClientData::WeaponFired { angle, power } }),
+            (_, ReplayEvent::Input { player_id: 7, data: ClientData::ChatSend { channel: ChatChannel::Team, target_player_id: 0, text } }),
+            (_, ReplayEvent::Output { recipient: None, bytes: broadcast }),
+            (_, ReplayEvent::Output { recipient: Some(7), bytes: direct }),
+        ] => {
+            assert!(player_controls.up && player_controls.right && !player_controls.down && !player_controls.left);
+            assert_eq!(*player_position, ClientPosition { x: 1.5, y: -2.0 });
+            assert_eq!((*angle, *power), (0.5, 1.0));
+            assert_eq!(text, "gg");
+            assert_eq!(broadcast, &chat_bytes);
+            assert_eq!(direct, &chat_bytes);
+        }
+        events => panic!("unexpected events {:?}", events),
+    }
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn oversized_and_misnamed_replays_are_rejected() {
+    let directory = temp_directory("replay-limits");
+    let directory_name = directory.to_str().unwrap();
+    let mut recorder = ReplayRecorder::create(directory_name, 1, 1, "arena", Duration::ZERO).unwrap();
+    let file_name = recorder.path().file_name().unwrap().to_str().unwrap().to_string();
+    recorder.record_input(Duration::ZERO, 1, &ClientData::WeaponFired { angle: 0.0, power: 1.0 }).unwrap();
+    recorder.finish().unwrap();
+
+    let error = Replay::open(directory_name, &file_name, 16).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(Replay::open(directory_name, &file_name, 1024).is_ok());
+
+    let error = Replay::open(directory_name, "../secrets", 1024).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
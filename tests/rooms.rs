@@ -0,0 +1,100 @@
+mod common;
+
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use common::{http, join, joined_id, spawn_server, TIMEOUT};
+
+/// creates a private room and returns its code and owner token
+async fn create_room(url: &str, body: Option<&str>) -> (String, String) {
+    let (status, body) = http(url, "POST", "/rooms", None, body).await;
+    assert_eq!(status, 201, "{}", body);
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    (room["code"].as_str().unwrap().to_string(), room["ownerToken"].as_str().unwrap().to_string())
+}
+
+#[actix_web::test]
+async fn players_join_a_private_room_by_code() {
+    let url = spawn_server(ServerConfig::default());
+    let (code, _) = create_room(&url, None).await;
+
+    let (mut public, _) = join(&url).await;
+    let (_first, _) = join(&format!("{}?room={}", url, code.to_lowercase())).await;
+    let (_second, players) = join(&format!("{}?room={}", url, code)).await;
+    assert_eq!(players.len(), 1);
+
+    let (status, body) = http(&url, "GET", &format!("/rooms/{}", code), None, None).await;
+    assert_eq!(status, 200);
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(room["players"], 2);
+    assert_eq!(room["passwordProtected"], false);
+
+    // nothing from the private room reaches the public one
+    assert!(public.wait_for(std::time::Duration::from_millis(200), |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await.is_err());
+
+    assert_eq!(http(&url, "GET", "/rooms/NOPE42", None, None).await.0, 404);
+}
+
+#[actix_web::test]
+async fn connection_to_a_full_room_is_closed() {
+    let url = spawn_server(ServerConfig::default());
+    let (code, _) = create_room(&url, None).await;
+    let room_url = format!("{}?room={}", url, code);
+
+    // rooms hold two players by default
+    let (mut first, _) = join(&room_url).await;
+    let (_second, _) = join(&room_url).await;
+    joined_id(&mut first).await;
+
+    let mut third = GameClient::connect(&room_url).await.unwrap();
+    assert!(third.wait_for(TIMEOUT, |_| false).await.is_err());
+    assert_eq!(third.close_reason().map(|(_, reason)| reason.as_str()), Some("room_full"));
+
+    // the rejected connection is neither announced nor counted
+    assert!(first.wait_for(std::time::Duration::from_millis(200), |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await.is_err());
+    let (_, body) = http(&url, "GET", &format!("/rooms/{}", code), None, None).await;
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(room["players"], 2);
+    let (_, body) = http(&url, "GET", "/stats", None, None).await;
+    let stats: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(stats["playersOnline"], 2);
+}
+
+#[actix_web::test]
+async fn password_protected_rooms_need_the_password() {
+    let url = spawn_server(ServerConfig::default());
+    let (code, owner_token) = create_room(&url, Some(r#"{"password":"hunter2"}"#)).await;
+
+    assert!(GameClient::connect(&format!("{}?room={}", url, code)).await.is_err());
+    assert!(GameClient::connect(&format!("{}?room={}&password=wrong", url, code)).await.is_err());
+    assert!(GameClient::connect(&format!("{}?room={}&password=hunter", url, code)).await.is_err());
+    join(&format!("{}?room={}&password=hunter2", url, code)).await;
+
+    let path = format!("/rooms/{}", code);
+    assert_eq!(http(&url, "PATCH", &path, Some(&owner_token), Some(r#"{"password":"swordfish"}"#)).await.0, 204);
+    assert!(GameClient::connect(&format!("{}?room={}&password=hunter2", url, code)).await.is_err());
+    join(&format!("{}?room={}&password=swordfish", url, code)).await;
+}
+
+#[actix_web::test]
+async fn owner_can_kick_players_and_change_settings() {
+    let url = spawn_server(ServerConfig::default());
+    let (code, owner_token) = create_room(&url, None).await;
+    let (mut first, _) = join(&format!("{}?room={}", url, code)).await;
+    let (mut client, _) = join(&format!("{}?room={}", url, code)).await;
+    let kick = format!(r#"{{"playerId":{}}}"#, joined_id(&mut first).await);
+
+    assert_eq!(http(&url, "POST", &format!("/rooms/{}/kick", code), None, Some(&kick)).await.0, 401);
+    assert_eq!(http(&url, "POST", &format!("/rooms/{}/kick", code), Some("not-the-owner"), Some(&kick)).await.0, 403);
+    assert_eq!(http(&url, "PATCH", &format!("/rooms/{}", code), Some(&owner_token), Some(r#"{"maxPlayers":4}"#)).await.0, 204);
+    assert_eq!(http(&url, "PATCH", &format!("/rooms/{}", code), Some(&owner_token), Some(r#"{"gameMode":"captureTheFlag"}"#)).await.0, 409);
+
+    assert_eq!(http(&url, "POST", &format!("/rooms/{}/kick", code), Some(&owner_token), Some(&kick)).await.0, 204);
+    assert!(client.wait_for(TIMEOUT, |_| false).await.is_err());
+    assert_eq!(client.close_reason().map(|(_, reason)| reason.as_str()), Some("kicked_by_owner"));
+
+    let (status, body) = http(&url, "GET", &format!("/rooms/{}", code), None, None).await;
+    assert_eq!(status, 200);
+    let room: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(room["maxPlayers"], 4);
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+use std::time::Duration;
+use demo_game_server::game_server::capture_the_flag::{CaptureTheFlagConfig, FlagEvent};
+use demo_game_server::game_server::game_map::GameMap;
+use demo_game_server::game_server::game_mode::GameModeKind;
+use demo_game_server::game_server::match_state::{MatchConfig, MatchPhase};
+use demo_game_server::game_server::game_world::{GameWorld, WorldCommand, WorldEvent, WorldSettings};
+use demo_game_server::game_server::movement_validator::MovementConfig;
+use demo_game_server::game_server::peer::{ClientControls, ClientPosition};
+use demo_game_server::game_server::simulation::{Clock, VirtualClock};
+use demo_game_server::game_server::spawn::{SpawnPoint, SpawnStrategy};
+use demo_game_server::game_server::team::TeamConfig;
+
+fn run(seed: u64) -> Vec<WorldEvent> {
+    let settings = WorldSettings {
+        map: Arc::new(GameMap::load("maps/arena.json").expect("sample map")),
+        movement: MovementConfig::default(),
+        spawn_strategy: SpawnStrategy::FarthestFromEnemies,
+        teams: TeamConfig::default(),
+        match_config: MatchConfig::default(),
+    };
+    let mut world = GameWorld::new(1, seed, &settings);
+    let clock = VirtualClock::default();
+    let controls = ClientControls { up: false, down: false, left: false, right: true };
+
+    let mut events = Vec::new();
+    events.extend(world.step(clock.now(), WorldCommand::Join { player_id: 1, team: None, party: Vec::new() }));
+    events.extend(world.step(clock.now(), WorldCommand::Join { player_id: 2, team: None, party: Vec::new() }));
+    for step in 1..=20 {
+        clock.advance(Duration::from_millis(16));
+        let position = ClientPosition { x: step as f32 * 50.0, y: 100.0 };
+        events.extend(world.step(clock.now(), WorldCommand::Move { player_id: 1, position, controls }));
+    }
+    events.extend(world.step(clock.now(), WorldCommand::Leave { player_id: 2 }));
+    events
+}
+
+#[test]
+fn same_seed_and_commands_produce_same_events() {
+    assert_eq!(run(42), run(42));
+}
+
+#[test]
+fn fast_moves_are_flagged() {
+    let flagged = run(42)
+        .iter()
+        .filter(|event| matches!(event, WorldEvent::Moved { flags, .. } if !flags.is_empty()))
+        .count();
+    assert!(flagged > 0);
+}
+
+#[test]
+fn kill_reaching_the_score_limit_ends_the_match() {
+    let settings = WorldSettings {
+        map: Arc::new(GameMap::default()),
+        movement: MovementConfig::default(),
+        spawn_strategy: SpawnStrategy::RoundRobin,
+        teams: TeamConfig::default(),
+        match_config: MatchConfig { warmup_secs: 1, score_limit: 1, ..MatchConfig::default() },
+    };
+    let mut world = GameWorld::new(1, 7, &settings);
+    let clock = VirtualClock::default();
+    let controls = ClientControls { up: false, down: false, left: false, right: true };
+
+    world.step(clock.now(), WorldCommand::Join { player_id: 1, team: None, party: Vec::new() });
+    world.step(clock.now(), WorldCommand::Join { player_id: 2, team: None, party: Vec::new() });
+    clock.advance(Duration::from_millis(200));
+    world.step(clock.now(), WorldCommand::Move { player_id: 2, position: ClientPosition { x: 60.0, y: 0.0 }, controls });
+
+    // kills during the warmup don't score
+    let warmup_kill = world.step(clock.now(), WorldCommand::Fire { player_id: 1, angle: 0.0 });
+    assert!(matches!(warmup_kill.as_slice(), [WorldEvent::Killed { killer_id: 1, victim_id: 2, .. }]));
+    assert_eq!(world.match_phase(), MatchPhase::Warmup);
+
+    clock.advance(Duration::from_secs(1));
+    let events = world.step(clock.now(), WorldCommand::Tick);
+    assert!(events.contains(&WorldEvent::PhaseChanged { phase: MatchPhase::Live, duration: Some(Duration::from_secs(300)) }));
+
+    // the victim respawned on the only spawn point, step away again
+    clock.advance(Duration::from_millis(200));
+    world.step(clock.now(), WorldCommand::Move { player_id: 2, position: ClientPosition { x: 60.0, y: 0.0 }, controls });
+    let events = world.step(clock.now(), WorldCommand::Fire { player_id: 1, angle: 0.0 });
+
+    assert_eq!(world.match_phase(), MatchPhase::Ended);
+    let summary = events.iter().find_map(|event| match event {
+        WorldEvent::MatchEnded { summary } => Some(summary),
+        _ => None,
+    }).expect("match summary");
+    assert_eq!(summary.winner_id, Some(1));
+    assert_eq!(summary.scores[0].kills, 1);
+    assert_eq!(summary.scores[1].deaths, 1);
+}
+
+#[test]
+fn missed_shot_does_nothing() {
+    let settings = WorldSettings {
+        map: Arc::new(GameMap::default()),
+        movement: MovementConfig::default(),
+        spawn_strategy: SpawnStrategy::RoundRobin,
+        teams: TeamConfig::default(),
+        match_config: MatchConfig::default(),
+    };
+    let mut world = GameWorld::new(1, 7, &settings);
+    let clock = VirtualClock::default();
+    let controls = ClientControls { up: false, down: false, left: false, right: true };
+
+    world.step(clock.now(), WorldCommand::Join { player_id: 1, team: None, party: Vec::new() });
+    world.step(clock.now(), WorldCommand::Join { player_id: 2, team: None, party: Vec::new() });
+    clock.advance(Duration::from_millis(200));
+    world.step(clock.now(), WorldCommand::Move { player_id: 2, position: ClientPosition { x: 60.0, y: 0.0 }, controls });
+
+    assert!(world.step(clock.now(), WorldCommand::Fire { player_id: 1, angle: std::f32::consts::PI }).is_empty());
+}
+
+#[test]
+fn carrying_the_enemy_flag_home_captures_it() {
+    let map = GameMap {
+        spawn_points: vec![
+            SpawnPoint { x: 0.0, y: 0.0, team: Some(0) },
+            SpawnPoint { x: 200.0, y: 0.0, team: Some(1) },
+        ],
+        ..GameMap::default()
+    };
+    let settings = WorldSettings {
+        map: Arc::new(map),
+        movement: MovementConfig::default(),
+        spawn_strategy: SpawnStrategy::Team,
+        teams: TeamConfig::default(),
+        match_config: MatchConfig {
+            mode: GameModeKind::CaptureTheFlag,
+            warmup_secs: 1,
+            capture_the_flag: CaptureTheFlagConfig { capture_limit: 1, ..CaptureTheFlagConfig::default() },
+            ..MatchConfig::default()
+        },
+    };
+    let mut world = GameWorld::new(1, 7, &settings);
+    let clock = VirtualClock::default();
+    let right = ClientControls { up: false, down: false, left: false, right: true };
+    let left = ClientControls { up: false, down: false, left: true, right: false };
+
+    world.step(clock.now(), WorldCommand::Join { player_id: 1, team: Some(0), party: Vec::new() });
+    world.step(clock.now(), WorldCommand::Join { player_id: 2, team: Some(1), party: Vec::new() });
+    clock.advance(Duration::from_secs(1));
+    world.step(clock.now(), WorldCommand::Tick);
+    assert_eq!(world.match_phase(), MatchPhase::Live);
+
+    // player 2 stays on its own flag, player 1 runs over, takes it and brings it back
+    clock.advance(Duration::from_millis(500));
+    let events = world.step(clock.now(), WorldCommand::Move { player_id: 1, position: ClientPosition { x: 200.0, y: 0.0 }, controls: right });
+    assert!(events.iter().any(|event| matches!(event, WorldEvent::FlagChanged { team: 1, event: FlagEvent::Taken, player_id: Some(1), .. })));
+
+    clock.advance(Duration::from_millis(500));
+    let events = world.step(clock.now(), WorldCommand::Move { player_id: 1, position: ClientPosition { x: 0.0, y: 0.0 }, controls: left });
+    assert!(events.iter().any(|event| matches!(event, WorldEvent::FlagChanged { team: 1, event: FlagEvent::Captured, player_id: Some(1), .. })));
+
+    assert_eq!(world.match_phase(), MatchPhase::Ended);
+    let summary = events.iter().find_map(|event| match event {
+        WorldEvent::MatchEnded { summary } => Some(summary),
+        _ => None,
+    }).expect("match summary");
+    assert_eq!(summary.winning_team, Some(0));
+    assert_eq!(summary.scores[0].points, CaptureTheFlagConfig::default().capture_points);
+}
@@ -0,0 +1,74 @@
+use demo_game_server::game_server::peer::ClientPosition;
+use demo_game_server::game_server::simulation::seeded_rng;
+use demo_game_server::game_server::spawn::{Occupant, SpawnPoint, SpawnSelector, SpawnStrategy};
+
+fn point(x: f32, team: Option<u8>) -> SpawnPoint {
+    SpawnPoint { x, y: 0.0, team }
+}
+
+fn occupant(x: f32, team: Option<u8>) -> Occupant {
+    Occupant { position: ClientPosition { x, y: 0.0 }, team }
+}
+
+/// the x of each of `count` picks made with a fresh selector and RNG
+fn picks(strategy: SpawnStrategy, points: &[SpawnPoint], team: Option<u8>, occupants: &[Occupant], seed: u64, count: usize) -> Vec<f32> {
+    let mut selector = SpawnSelector::new(strategy);
+    let mut rng = seeded_rng(seed);
+    (0..count)
+        .map(|_| selector.pick(points, team, occupants, &mut rng).unwrap().x)
+        .collect()
+}
+
+#[test]
+fn round_robin_cycles_through_points_in_map_order() {
+    let points = [point(1.0, None), point(2.0, Some(0)), point(3.0, Some(1))];
+
+    assert_eq!(picks(SpawnStrategy::RoundRobin, &points, Some(1), &[], 42, 5), vec![1.0, 2.0, 3.0, 1.0, 2.0]);
+}
+
+#[test]
+fn team_strategy_cycles_through_the_team_points_and_shared_ones() {
+    let points = [point(1.0, Some(0)), point(2.0, None), point(3.0, Some(1)), point(4.0, Some(0))];
+    let mut selector = SpawnSelector::new(SpawnStrategy::Team);
+    let mut rng = seeded_rng(42);
+    let mut pick = |team| selector.pick(&points, team, &[], &mut rng).unwrap().x;
+
+    assert_eq!(pick(Some(0)), 1.0);
+    assert_eq!(pick(Some(1)), 2.0);
+    assert_eq!(pick(Some(0)), 2.0);
+    assert_eq!(pick(Some(1)), 3.0);
+    assert_eq!(pick(Some(0)), 4.0);
+
+    // a team without points of its own uses every point
+    let untagged = [point(1.0, Some(0)), point(2.0, Some(0))];
+    assert_eq!(picks(SpawnStrategy::Team, &untagged, Some(1), &[], 42, 3), vec![1.0, 2.0, 1.0]);
+}
+
+#[test]
+fn farthest_from_enemies_ignores_teammates() {
+    let points = [point(0.0, None), point(500.0, None), point(1000.0, None)];
+    let occupants = [occupant(900.0, Some(1)), occupant(100.0, Some(0))];
+
+    assert_eq!(picks(SpawnStrategy::FarthestFromEnemies, &points, Some(0), &occupants, 42, 2), vec![0.0, 0.0]);
+    assert_eq!(picks(SpawnStrategy::FarthestFromEnemies, &points, Some(1), &occupants, 42, 1), vec![1000.0]);
+    // without teams everyone else is an enemy
+    assert_eq!(picks(SpawnStrategy::FarthestFromEnemies, &points, None, &occupants, 42, 1), vec![500.0]);
+}
+
+#[test]
+fn farthest_from_enemies_picks_from_the_seeded_rng_in_an_empty_room() {
+    let points = (0..8).map(|x| point(x as f32, None)).collect::<Vec<SpawnPoint>>();
+
+    let first = picks(SpawnStrategy::FarthestFromEnemies, &points, None, &[], 42, 32);
+    assert_eq!(first, picks(SpawnStrategy::FarthestFromEnemies, &points, None, &[], 42, 32));
+    assert_ne!(first, picks(SpawnStrategy::FarthestFromEnemies, &points, None, &[], 43, 32));
+    assert!(first.iter().any(|x| *x != first[0]));
+}
+
+#[test]
+fn no_spawn_points_means_no_position() {
+    let mut rng = seeded_rng(42);
+    for strategy in [SpawnStrategy::RoundRobin, SpawnStrategy::FarthestFromEnemies, SpawnStrategy::Team] {
+        assert!(SpawnSelector::new(strategy).pick(&[], Some(0), &[], &mut rng).is_none());
+    }
+}
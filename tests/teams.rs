@@ -0,0 +1,71 @@
+mod common;
+
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::GameClient;
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::team::TeamConfig;
+use common::{join, spawn_server, TIMEOUT};
+
+#[actix_web::test]
+async fn chosen_team_is_kept_until_it_would_unbalance() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&format!("{}?team=1", url)).await;
+    let (_second, _) = join(&format!("{}?team=1", url)).await;
+
+    // the first player got team 1, so the second one is balanced into team 0
+    match first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerJoined { .. })).await.unwrap() {
+        PeerPlayerData::RemotePeerJoined { team, .. } => assert_eq!(team, 0),
+        _ => unreachable!(),
+    }
+    let mut spectator = GameClient::connect(&format!("{}?spectate=true", url)).await.unwrap();
+    match spectator.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap() {
+        PeerPlayerData::WorldUpdate { world_data, .. } => {
+            let mut teams = world_data.iter().map(|info| info.team).collect::<Vec<u8>>();
+            teams.sort();
+            assert_eq!(teams, vec![0, 1]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[actix_web::test]
+async fn team_chat_stays_within_the_team() {
+    let url = spawn_server(ServerConfig::default());
+
+    let (mut first, _) = join(&url).await;
+    let (mut second, _) = join(&url).await;
+
+    first.send_chat(ChatChannel::Team, 0, "team only").await.unwrap();
+    // messages from one connection are handled in order, so everything the team message caused reaches the other
+    // team's socket before this one does
+    first.send_chat(ChatChannel::Room, 0, "everyone").await.unwrap();
+
+    loop {
+        match second.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap() {
+            PeerPlayerData::ChatMessage { channel, text, .. } => {
+                assert_ne!(channel, ChatChannel::Team, "team message {:?} reached the other team", text);
+                if text == "everyone" {
+                    break;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    match first.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap() {
+        PeerPlayerData::ChatMessage { channel, text, .. } => {
+            assert_eq!(channel, ChatChannel::Team);
+            assert_eq!(text, "team only");
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn friendly_fire_rule() {
+    let teams = TeamConfig::default();
+    assert!(teams.can_damage(0, 1));
+    assert!(!teams.can_damage(1, 1));
+    assert!(TeamConfig { friendly_fire: true, ..TeamConfig::default() }.can_damage(1, 1));
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+use demo_game_server::config::ServerConfig;
+use demo_game_server::game_client::ClientError;
+use demo_game_server::game_server::chat::ChatChannel;
+use demo_game_server::game_server::message_types::PeerPlayerData;
+use demo_game_server::game_server::peer::{ClientControls, ClientPosition};
+use demo_game_server::game_server::reliability::{Channel, Packet, PacketKind, PacketLost, ReliableEndpoint, COOKIE_LEN};
+use demo_game_server::game_server::udp_server::UdpConfig;
+use demo_game_server::udp_client::UdpGameClient;
+use common::{join, joined_id, spawn_server, TIMEOUT};
+
+const RESEND_AFTER: Duration = Duration::from_millis(100);
+
+/// the server's websocket url and UDP address
+fn spawn_udp_server() -> (String, SocketAddr) {
+    spawn_udp_server_with(UdpConfig::default())
+}
+
+fn spawn_udp_server_with(config: UdpConfig) -> (String, SocketAddr) {
+    // the server binds the port itself, so grab a free one and let go of it
+    let udp_addr = UdpSocket::bind(("127.0.0.1", 0)).unwrap().local_addr().unwrap();
+    let url = spawn_server(ServerConfig {
+        udp: UdpConfig { bind: Some(udp_addr.to_string()), ..config },
+        ..ServerConfig::default()
+    });
+    (url, udp_addr)
+}
+
+/// sends a `Connect` from `socket` and returns the request's length and the answer
+async fn connect_raw(socket: &tokio::net::UdpSocket, cookie: &[u8], query: &str) -> (usize, Vec<u8>) {
+    let connect = Packet::control(PacketKind::Connect, &[cookie, query.as_bytes()].concat()).write();
+    let mut buf = [0; 1500];
+    socket.send(&connect).await.unwrap();
+    let len = tokio::time::timeout(TIMEOUT, socket.recv(&mut buf)).await.expect("no answer to connect").unwrap();
+    (connect.len(), buf[..len].to_vec())
+}
+
+async fn raw_socket(server_addr: SocketAddr) -> tokio::net::UdpSocket {
+    let socket = tokio::net::UdpSocket::bind(("127.0.0.1", 0)).await.unwrap();
+    socket.connect(server_addr).await.unwrap();
+    socket
+}
+
+fn deliver(endpoint: &mut ReliableEndpoint, bytes: &[u8]) -> Option<Vec<u8>> {
+    endpoint.receive(&Packet::read(bytes).unwrap()).map(|payload| payload.to_vec())
+}
+
+#[test]
+fn reliable_packets_are_resent_until_acked() {
+    let start = Instant::now();
+    let mut client = ReliableEndpoint::new(RESEND_AFTER, 3);
+    let mut server = ReliableEndpoint::new(RESEND_AFTER, 3);
+
+    // the first, reliable packet is lost, the unreliable one after it arrives
+    let _lost = client.send(Channel::Reliable, b"chat".to_vec(), start);
+    let moved = client.send(Channel::Unreliable, b"moved".to_vec(), start);
+    assert_eq!(deliver(&mut server, &moved).as_deref(), Some(&b"moved"[..]));
+
+    // the ack only covers the packet that arrived
+    let ack = server.ack().unwrap();
+    assert_eq!(deliver(&mut client, &ack), None);
+    assert_eq!(client.unacked_len(), 1);
+
+    assert!(client.resend(start + RESEND_AFTER / 2).unwrap().is_empty());
+    let resent = client.resend(start + RESEND_AFTER).unwrap();
+    assert_eq!(resent.len(), 1);
+    assert_eq!(deliver(&mut server, &resent[0]).as_deref(), Some(&b"chat"[..]));
+    // a second copy of it isn't delivered again
+    assert_eq!(deliver(&mut server, &resent[0]), None);
+
+    let reply = server.send(Channel::Reliable, b"world".to_vec(), start);
+    assert_eq!(deliver(&mut client, &reply).as_deref(), Some(&b"world"[..]));
+    assert_eq!(client.unacked_len(), 0);
+}
+
+#[test]
+fn stale_unreliable_packets_are_dropped_and_lost_ones_give_up() {
+    let start = Instant::now();
+    let mut client = ReliableEndpoint::new(RESEND_AFTER, 1);
+    let mut server = ReliableEndpoint::new(RESEND_AFTER, 1);
+
+    let older = client.send(Channel::Unreliable, b"x=1".to_vec(), start);
+    let newer = client.send(Channel::Unreliable, b"x=2".to_vec(), start);
+    assert!(deliver(&mut server, &newer).is_some());
+    assert_eq!(deliver(&mut server, &older), None);
+
+    let lost = client.send(Channel::Reliable, b"join".to_vec(), start);
+    let sequence = Packet::read(&lost).unwrap().sequence;
+    assert_eq!(client.resend(start + RESEND_AFTER).unwrap().len(), 1);
+    assert_eq!(client.resend(start + RESEND_AFTER * 2), Err(PacketLost { sequence }));
+}
+
+#[actix_web::test]
+async fn udp_and_websocket_players_share_a_room() {
+    let (url, udp_addr) = spawn_udp_server();
+
+    let (mut ws_player, _) = join(&url).await;
+    let mut udp_player = UdpGameClient::connect(udp_addr, "").await.unwrap();
+    let world = udp_player.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::WorldUpdate { .. })).await.unwrap();
+    let PeerPlayerData::WorldUpdate { world_data, .. } = world else { unreachable!() };
+    assert_eq!(world_data.len(), 1);
+    let udp_id = joined_id(&mut ws_player).await;
+
+    udp_player.send_chat(ChatChannel::Room, 0, "over udp").await.unwrap();
+    match ws_player.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::ChatMessage { .. })).await.unwrap() {
+        PeerPlayerData::ChatMessage { sender_id, text, .. } => {
+            assert_eq!(sender_id, udp_id);
+            assert_eq!(text, "over udp");
+        }
+        _ => unreachable!(),
+    }
+
+    let target = ClientPosition { x: 5.0, y: 0.0 };
+    ws_player.send_player_moved(ClientControls { up: false, down: false, left: false, right: true }, target).await.unwrap();
+    match udp_player.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerPositionUpdate { .. })).await.unwrap() {
+        PeerPlayerData::RemotePeerPositionUpdate { player_position, .. } => assert_eq!(player_position, target),
+        _ => unreachable!(),
+    }
+
+    udp_player.close().await.unwrap();
+    let left = ws_player.wait_for(TIMEOUT, |data| matches!(data, PeerPlayerData::RemotePeerLeft { .. })).await.unwrap();
+    assert!(matches!(left, PeerPlayerData::RemotePeerLeft { player_id } if player_id == udp_id));
+}
+
+#[actix_web::test]
+async fn unknown_room_is_rejected() {
+    let (_, udp_addr) = spawn_udp_server();
+
+    match UdpGameClient::connect(udp_addr, "room=NOPE").await {
+        Err(ClientError::Rejected(reason)) => assert!(!reason.is_empty()),
+        other => panic!("expected a rejection, got {:?}", other.err()),
+    }
+}
+
+#[actix_web::test]
+async fn connect_is_only_handled_after_a_cookie_round_trip() {
+    let (_, udp_addr) = spawn_udp_server();
+    let socket = raw_socket(udp_addr).await;
+
+    // an unknown room would be rejected, but the query isn't looked at before the address is proven
+    let (request_len, answer) = connect_raw(&socket, &[0; COOKIE_LEN], "room=NOPE").await;
+    let challenge = Packet::read(&answer).unwrap();
+    assert_eq!(challenge.kind, PacketKind::Challenge);
+    assert_eq!(challenge.payload.len(), COOKIE_LEN);
+    assert!(answer.len() <= request_len);
+    let cookie = challenge.payload.to_vec();
+
+    // the cookie is bound to the address it was sent to
+    let other = raw_socket(udp_addr).await;
+    let (_, answer) = connect_raw(&other, &cookie, "room=NOPE").await;
+    assert_eq!(Packet::read(&answer).unwrap().kind, PacketKind::Challenge);
+
+    let (_, answer) = connect_raw(&socket, &cookie, "room=NOPE").await;
+    let rejected = Packet::read(&answer).unwrap();
+    assert_eq!(rejected.kind, PacketKind::Rejected);
+    assert!(!rejected.payload.is_empty());
+}
+
+#[actix_web::test]
+async fn sessions_are_capped() {
+    let (_, udp_addr) = spawn_udp_server_with(UdpConfig { max_sessions: 1, ..UdpConfig::default() });
+
+    let _first = UdpGameClient::connect(udp_addr, "").await.unwrap();
+    match UdpGameClient::connect(udp_addr, "").await {
+        Err(ClientError::Rejected(reason)) => assert_eq!(reason, "server_full"),
+        other => panic!("expected a rejection, got {:?}", other.err()),
+    }
+}